@@ -1,6 +1,6 @@
-use serde_json;
-
 use error::*;
+use lenient;
+use params::Params;
 use Proxer;
 use models::*;
 
@@ -9,6 +9,7 @@ use models::*;
 #[derive(Deserialize, Debug, Clone, PartialEq)]
 pub struct Stream {
     /// Die ID des Streams
+    #[serde(with = "lenient")]
     pub id: u64,
     /// Auf welchem Hoster der Stream liegt
     #[serde(rename = "type")]
@@ -18,14 +19,18 @@ pub struct Stream {
     /// Das Anzeigebild des Hosters
     pub img: String,
     /// Die ID des Uploaders
+    #[serde(with = "lenient")]
     pub uploader: u64,
     /// Der Name des Uploaders
     pub username: String,
     /// Der Verlinkzeitpunkt (Unix-Timestamp als Integer)
+    #[serde(with = "lenient")]
     pub timestamp: i64,
-    /// Die ID der zugewiesenen Subgruppe, null wenn nicht vorhanden.
-    pub tid: u64,
-    /// Der Name der zugewiesenen Subgruppe, null wenn nicht vorhanden.
+    /// Die ID der zugewiesenen Subgruppe, `None` wenn nicht vorhanden.
+    #[serde(default, with = "lenient")]
+    pub tid: Option<u64>,
+    /// Der Name der zugewiesenen Subgruppe, leer wenn nicht vorhanden.
+    #[serde(with = "lenient")]
     pub tname: String,
     /// Der Typ des Hosters (iframe,embed,js,code,link)
     pub htype: String,
@@ -39,6 +44,7 @@ pub struct Stream {
 #[derive(Deserialize, Debug, Clone, PartialEq)]
 pub struct ProxerStream {
     /// Die ID des Streams
+    #[serde(with = "lenient")]
     pub id: u64,
     /// Auf welchem Hoster der Stream liegt
     #[serde(rename = "type")]
@@ -48,30 +54,76 @@ pub struct ProxerStream {
     /// Das Anzeigebild des Hosters
     pub img: String,
     /// Die ID des Uploaders
+    #[serde(with = "lenient")]
     pub uploader: u64,
     /// Der Name des Uploaders
     pub username: String,
     /// Der Verlinkzeitpunkt (Unix-Timestamp als Integer)
+    #[serde(with = "lenient")]
     pub timestamp: i64,
-    /// Die ID der zugewiesenen Subgruppe, null wenn nicht vorhanden.
-    pub tid: String,
-    /// Der Name der zugewiesenen Subgruppe, null wenn nicht vorhanden.
+    /// Die ID der zugewiesenen Subgruppe, `None` wenn nicht vorhanden.
+    #[serde(default, with = "lenient")]
+    pub tid: Option<u64>,
+    /// Der Name der zugewiesenen Subgruppe, leer wenn nicht vorhanden.
+    #[serde(with = "lenient")]
     pub tname: String,
     /// Der Typ des Hosters (iframe,embed,js,code,link)
     pub htype: String,
 }
 
+endpoint! {
+    /// Liefert die Streams einer Folge ohne den Proxerstream. Siehe `Anime::get_streams`.
+    pub struct GetStreams -> Vec<Stream> ["anime", "streams"] {
+        /// Die id des Entrys.
+        pub id: u64,
+        /// Die Episodennummer der zu ladenden Folge.
+        pub episode: u64,
+        /// Die zu ladende Sprache.
+        pub language: Language,
+    }
+    |this| Params::new()
+        .add("id", &this.id)
+        .add("episode", &this.episode)
+        .add("language", &this.language)
+}
+
+endpoint! {
+    /// Liefert die Streams einer Folge inklusive des Proxerstreams. Siehe `Anime::get_proxerstreams`.
+    pub struct GetProxerStreams -> Vec<ProxerStream> ["anime", "proxerstreams"] {
+        /// Die id des Entrys.
+        pub id: u64,
+        /// Die Episodennummer der zu ladenden Folge.
+        pub episode: u64,
+        /// Die zu ladende Sprache.
+        pub language: Language,
+    }
+    |this| Params::new()
+        .add("id", &this.id)
+        .add("episode", &this.episode)
+        .add("language", &this.language)
+}
+
+endpoint! {
+    /// Liefert den Link zu einem einzelnen Stream. Siehe `Anime::get_link`.
+    pub struct GetLink -> String ["anime", "link"] {
+        /// Die id des Streams.
+        pub id: u64,
+    }
+    |this| Params::new().add("id", &this.id)
+}
+
 /// Diese Klasse dient dazu, Streams für Animes zu erhalten, und andere rein Anime-bezogene Aktionen durchzuführen.
 /// Der Zugriff auf diese Klasse ist stark beschränkt.
-#[derive(Debug)]
-pub struct Anime<'a> {
-    proxer: &'a Proxer,
+#[derive(Debug, Clone)]
+pub struct Anime {
+    proxer: Proxer,
 }
 
-impl<'a> Anime<'a> {
-    #[doc(hidden)]
-    pub fn new(proxer: &'a Proxer) -> Anime<'a> {
-        Anime { proxer: proxer }
+impl Anime {
+    pub(crate) fn new(proxer: &Proxer) -> Anime {
+        Anime {
+            proxer: proxer.clone(),
+        }
     }
 
     /// Diese Funktion liefert die Streams einer Folge eines festgelegten Animes, ohne den Proxerstream!.
@@ -83,14 +135,7 @@ impl<'a> Anime<'a> {
     /// * `episode` - Die Episodennummer der zu ladenden Folge
     /// * `language` - Die zu ladende Sprache (gersub,gerdub,engsub,engdub)
     pub fn get_streams(&self, id: u64, episode: u64, language: Language) -> Result<Vec<Stream>> {
-        let url = url!("anime", "streams");
-        let body = param_build!("id" => Some(id),
-            "episode" => Some(episode),
-            "language" => Some(language));
-        let response = self.proxer.connect(&url, &body)?;
-        let data: Response<Vec<Stream>> = serde_json::from_reader(response)?;
-        check_error!(data.error, data.code.unwrap_or_default(), data.message);
-        check_data!(data.data)
+        self.proxer.execute(&GetStreams { id, episode, language })
     }
 
     /// Diese Funktion liefert die Streams einer Folge eines festgelegten Animes, inklusive des Proxerstreams!.
@@ -110,14 +155,7 @@ impl<'a> Anime<'a> {
         episode: u64,
         language: Language,
     ) -> Result<Vec<ProxerStream>> {
-        let url = url!("anime", "proxerstreams");
-        let body = param_build!("id" => Some(id),
-            "episode" => Some(episode),
-            "language" => Some(language));
-        let response = self.proxer.connect(&url, &body)?;
-        let data: Response<Vec<ProxerStream>> = serde_json::from_reader(response)?;
-        check_error!(data.error, data.code.unwrap_or_default(), data.message);
-        check_data!(data.data)
+        self.proxer.execute(&GetProxerStreams { id, episode, language })
     }
 
     /// Diese Funktion dient dazu, den Link zu einem einzelnen Stream zu erhalten.
@@ -129,11 +167,6 @@ impl<'a> Anime<'a> {
     ///
     /// * `id` - Die id des Entrys
     pub fn get_link(&self, id: u64) -> Result<String> {
-        let url = url!("anime", "link");
-        let body = param_build!("id" => Some(id));
-        let response = self.proxer.connect(&url, &body)?;
-        let data: Response<String> = serde_json::from_reader(response)?;
-        check_error!(data.error, data.code.unwrap_or_default(), data.message);
-        check_data!(data.data)
+        self.proxer.execute(&GetLink { id })
     }
 }
@@ -0,0 +1,754 @@
+//! Asynchrone Variante von `Proxer` (Feature `async`).
+//!
+//! `AsyncProxer` bietet dieselben Schnittstellen wie die blockierenden Klassen,
+//! liefert aber `ProxerFuture`s zurück. Die Anfragen werden mit dem blockierenden Client
+//! in einem eigenen Thread-Pool ausgeführt, die Futures sind daher an keine bestimmte Runtime gebunden.
+//!
+//! Jede laufende Anfrage belegt einen Thread des Pools. Der Pool startet höchstens
+//! `AsyncProxer::max_threads` Threads (Default: 4), weitere Anfragen warten, bis ein Thread frei wird.
+//! Threads, die 30 Sekunden keine Arbeit hatten, werden beendet.
+
+use std::collections::VecDeque;
+use std::fmt;
+use std::future::Future;
+use std::panic::{self, AssertUnwindSafe};
+use std::pin::Pin;
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+use std::task::{Context, Poll, Waker};
+use std::thread;
+use std::time::Duration;
+
+use endpoint::Endpoint;
+use error::*;
+use models::*;
+use Proxer;
+use NewsNotification;
+use anime::{self, Anime};
+use info::{self, Info};
+use list::{self, List};
+use manga::{self, Manga};
+use media::{self, Media};
+use messenger::{self, Messenger};
+use notification::{self, Notification};
+use ucp::{self, Ucp};
+use user::{self, User};
+
+struct Shared<T> {
+    result: Option<Result<T>>,
+    waker: Option<Waker>,
+    finished: bool,
+}
+
+/// Das Ergebnis einer asynchronen Anfrage.
+pub struct ProxerFuture<T> {
+    shared: Arc<Mutex<Shared<T>>>,
+}
+
+impl<T> fmt::Debug for ProxerFuture<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ProxerFuture").finish()
+    }
+}
+
+impl<T> Future for ProxerFuture<T> {
+    type Output = Result<T>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Result<T>> {
+        let mut shared = self.shared.lock().unwrap_or_else(|e| e.into_inner());
+        match shared.result.take() {
+            Some(result) => Poll::Ready(result),
+            None if shared.finished => Poll::Ready(Err(Error::Other(
+                "The request thread terminated without a result.".to_owned(),
+            ))),
+            None => {
+                shared.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+/// Markiert die Anfrage auch dann als beendet, wenn der Thread ohne Ergebnis abbricht.
+struct Completion<T> {
+    shared: Arc<Mutex<Shared<T>>>,
+}
+
+impl<T> Completion<T> {
+    fn complete(&self, result: Result<T>) {
+        self.shared.lock().unwrap_or_else(|e| e.into_inner()).result = Some(result);
+    }
+}
+
+impl<T> Drop for Completion<T> {
+    fn drop(&mut self) {
+        let mut shared = self.shared.lock().unwrap_or_else(|e| e.into_inner());
+        shared.finished = true;
+        if let Some(waker) = shared.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+/// Die höchste Anzahl an Threads, wenn `AsyncProxer::max_threads` nicht gesetzt wird.
+const DEFAULT_THREADS: usize = 4;
+
+/// So lange wartet ein Thread des Pools auf Arbeit, bevor er beendet wird.
+const IDLE_TIMEOUT: Duration = Duration::from_secs(30);
+
+type Job = Box<dyn FnOnce() + Send>;
+
+/// Ein Thread-Pool mit höchstens `max_threads` Threads, die bei Bedarf gestartet werden.
+struct Pool {
+    max_threads: usize,
+    state: Mutex<PoolState>,
+    available: Condvar,
+}
+
+#[derive(Default)]
+struct PoolState {
+    jobs: VecDeque<Job>,
+    threads: usize,
+    idle: usize,
+}
+
+impl Pool {
+    fn new(max_threads: usize) -> Pool {
+        Pool {
+            max_threads: max_threads.max(1),
+            state: Mutex::new(PoolState::default()),
+            available: Condvar::new(),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, PoolState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Reiht `job` ein und startet einen weiteren Thread, falls mehr Anfragen warten als Threads frei sind
+    /// und das Limit es erlaubt.
+    fn execute(pool: &Arc<Pool>, job: Job) {
+        let mut state = pool.lock();
+        state.jobs.push_back(job);
+        if state.jobs.len() <= state.idle || state.threads >= pool.max_threads {
+            pool.available.notify_one();
+            return;
+        }
+
+        state.threads += 1;
+        drop(state);
+        let worker = pool.clone();
+        let spawned = thread::Builder::new()
+            .name("proxer-request".to_owned())
+            .spawn(move || worker.work());
+        if let Err(err) = spawned {
+            warn!("Could not start request thread: {}", err);
+            let mut state = pool.lock();
+            state.threads -= 1;
+            if state.threads == 0 {
+                // Ohne Thread würden die Anfragen nie beendet, ihre Futures liefern stattdessen einen Fehler.
+                let jobs: Vec<Job> = state.jobs.drain(..).collect();
+                drop(state);
+                drop(jobs);
+            }
+        }
+    }
+
+    /// Arbeitet die Warteschlange ab, bis `IDLE_TIMEOUT` lang keine Arbeit kam.
+    fn work(&self) {
+        loop {
+            let job = {
+                let mut state = self.lock();
+                loop {
+                    if let Some(job) = state.jobs.pop_front() {
+                        break job;
+                    }
+                    state.idle += 1;
+                    let (next, timeout) = self
+                        .available
+                        .wait_timeout(state, IDLE_TIMEOUT)
+                        .unwrap_or_else(|e| e.into_inner());
+                    state = next;
+                    state.idle -= 1;
+                    if timeout.timed_out() && state.jobs.is_empty() {
+                        state.threads -= 1;
+                        return;
+                    }
+                }
+            };
+            // Bricht eine Anfrage ab, meldet `Completion` den Fehler, der Thread arbeitet weiter.
+            let _ = panic::catch_unwind(AssertUnwindSafe(job));
+        }
+    }
+}
+
+/// Der blockierende Client und der Thread-Pool, auf dem seine Anfragen ausgeführt werden.
+#[derive(Clone)]
+struct Executor {
+    proxer: Proxer,
+    pool: Arc<Pool>,
+}
+
+impl fmt::Debug for Executor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Executor")
+            .field("proxer", &self.proxer)
+            .field("max_threads", &self.pool.max_threads)
+            .finish()
+    }
+}
+
+impl Executor {
+    fn new(proxer: Proxer, max_threads: usize) -> Executor {
+        Executor {
+            proxer,
+            pool: Arc::new(Pool::new(max_threads)),
+        }
+    }
+
+    fn spawn<T, F>(&self, call: F) -> ProxerFuture<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&Proxer) -> Result<T> + Send + 'static,
+    {
+        let shared = Arc::new(Mutex::new(Shared {
+            result: None,
+            waker: None,
+            finished: false,
+        }));
+        let completion = Completion {
+            shared: shared.clone(),
+        };
+        let proxer = self.proxer.clone();
+        Pool::execute(&self.pool, Box::new(move || completion.complete(call(&proxer))));
+        ProxerFuture { shared }
+    }
+}
+
+macro_rules! async_class
+{
+    (
+        $(#[$attr:meta])*
+        $name:ident => $class:ident
+        {
+            $( fn $method:ident ( $( $arg:ident : $ty:ty ),* ) -> $output:ty; )*
+        }
+    ) =>
+    (
+        $(#[$attr])*
+        #[derive(Debug, Clone)]
+        pub struct $name {
+            executor: Executor,
+        }
+
+        impl $name {
+            $(
+                #[doc = concat!("Asynchrone Variante von `", stringify!($class), "::", stringify!($method), "`.")]
+                #[allow(clippy::too_many_arguments)]
+                pub fn $method(&self, $( $arg: $ty ),*) -> ProxerFuture<$output> {
+                    self.executor.spawn(move |proxer| $class::new(proxer).$method($( $arg ),*))
+                }
+            )*
+        }
+    );
+}
+
+/// Asynchrone Variante von `Proxer`.
+///
+/// Der Client kann günstig geklont und zwischen Threads geteilt werden, alle Klone teilen sich den Thread-Pool.
+#[derive(Debug, Clone)]
+pub struct AsyncProxer {
+    executor: Executor,
+}
+
+impl AsyncProxer {
+    /// Erstellt eine asynchrone Proxer-Sitzung mit dem angegebenen API-Key.
+    pub fn new(api_key: &str) -> Result<Self> {
+        Ok(AsyncProxer::from(Proxer::new(api_key)?))
+    }
+
+    /// Führt höchstens `max_threads` Anfragen gleichzeitig aus. Default: 4.
+    ///
+    /// Erstellt einen neuen Thread-Pool, bereits erstellte Klone und Klassen verwenden weiterhin den bisherigen.
+    pub fn max_threads(self, max_threads: usize) -> Self {
+        AsyncProxer {
+            executor: Executor::new(self.executor.proxer, max_threads),
+        }
+    }
+
+    /// Asynchrone Variante von `Proxer::get_news`.
+    pub fn get_news(&self) -> ProxerFuture<Vec<NewsNotification>> {
+        self.executor.spawn(|proxer| proxer.get_news())
+    }
+
+    /// Asynchrone Variante von `Proxer::execute`.
+    pub fn execute<E>(&self, endpoint: E) -> ProxerFuture<E::Output>
+    where
+        E: Endpoint + Send + 'static,
+        E::Output: Send + 'static,
+    {
+        self.executor.spawn(move |proxer| proxer.execute(&endpoint))
+    }
+
+    /// Loggt einen User ein. Siehe `User::login`.
+    pub fn login(&self, username: &str, password: &str, secret_key: Option<&str>) -> ProxerFuture<AsyncUser> {
+        let username = username.to_owned();
+        let password = password.to_owned();
+        let secret_key = secret_key.map(str::to_owned);
+        let executor = self.executor.clone();
+        self.executor.spawn(move |proxer| {
+            let login = User::login(proxer, &username, &password, secret_key.as_deref())?;
+            Ok(AsyncUser {
+                uid: login.uid,
+                avatar: login.avatar,
+                token: login.token,
+                executor,
+            })
+        })
+    }
+
+    /// Setzt eine gespeicherte Sitzung fort. Siehe `User::resume`.
+    pub fn resume(&self, token: &str) -> ProxerFuture<AsyncUser> {
+        let token = token.to_owned();
+        let executor = self.executor.clone();
+        self.executor.spawn(move |proxer| {
+            let user = User::resume(proxer, &token)?;
+            Ok(AsyncUser {
+                uid: user.uid,
+                avatar: user.avatar.clone(),
+                token: user.token.clone(),
+                executor,
+            })
+        })
+    }
+
+    /// Asynchrone Variante von `Proxer::anime`.
+    pub fn anime(&self) -> AsyncAnime {
+        AsyncAnime {
+            executor: self.executor.clone(),
+        }
+    }
+
+    /// Asynchrone Variante von `Proxer::info`.
+    pub fn info(&self) -> AsyncInfo {
+        AsyncInfo {
+            executor: self.executor.clone(),
+        }
+    }
+
+    /// Asynchrone Variante von `Proxer::list`.
+    pub fn list(&self) -> AsyncList {
+        AsyncList {
+            executor: self.executor.clone(),
+        }
+    }
+
+    /// Asynchrone Variante von `Proxer::manga`.
+    pub fn manga(&self) -> AsyncManga {
+        AsyncManga {
+            executor: self.executor.clone(),
+        }
+    }
+
+    /// Asynchrone Variante von `Proxer::media`.
+    pub fn media(&self) -> AsyncMedia {
+        AsyncMedia {
+            executor: self.executor.clone(),
+        }
+    }
+
+    /// Asynchrone Variante von `Proxer::messenger`.
+    pub fn messenger(&self) -> AsyncMessenger {
+        AsyncMessenger {
+            executor: self.executor.clone(),
+        }
+    }
+
+    /// Asynchrone Variante von `Proxer::notification`.
+    pub fn notification(&self) -> AsyncNotification {
+        AsyncNotification {
+            executor: self.executor.clone(),
+        }
+    }
+
+    /// Asynchrone Variante von `Proxer::ucp`.
+    pub fn ucp(&self) -> AsyncUcp {
+        AsyncUcp {
+            executor: self.executor.clone(),
+        }
+    }
+}
+
+impl From<Proxer> for AsyncProxer {
+    fn from(proxer: Proxer) -> AsyncProxer {
+        AsyncProxer {
+            executor: Executor::new(proxer, DEFAULT_THREADS),
+        }
+    }
+}
+
+async_class! {
+    /// Asynchrone Variante von `Anime`.
+    AsyncAnime => Anime {
+        fn get_streams(id: u64, episode: u64, language: Language) -> Vec<anime::Stream>;
+        fn get_proxerstreams(id: u64, episode: u64, language: Language) -> Vec<anime::ProxerStream>;
+        fn get_link(id: u64) -> String;
+    }
+}
+
+async_class! {
+    /// Asynchrone Variante von `Info`.
+    AsyncInfo => Info {
+        fn get_fullentry(id: u64) -> info::FullEntry;
+        fn get_entry(id: u64) -> info::Entry;
+        fn get_name(id: u64) -> Vec<info::Name>;
+        fn get_gate(id: u64) -> bool;
+        fn get_language(id: u64) -> Vec<String>;
+        fn get_season(id: u64) -> Vec<info::Season>;
+        fn get_groups(id: u64) -> Vec<info::Group>;
+        fn get_publisher(id: u64) -> Vec<info::Publisher>;
+        fn get_listinfo(id: u64, page: Option<u64>, limit: Option<u64>) -> info::ListInfo;
+        fn get_comments(id: u64, page: Option<u64>, limit: Option<u64>, sort: Option<Sort>) -> Vec<info::Group>;
+        fn get_relations(id: u64) -> Vec<info::Relation>;
+        fn get_entrytag(id: u64) -> Vec<info::EntryTag>;
+        fn get_translatorgroup(id: u64) -> info::TranslatorGroup;
+        fn get_industry(id: u64) -> info::Industry;
+        fn set_userinfo(id: u64, watch_type: WatchType) -> ();
+    }
+}
+
+async_class! {
+    /// Asynchrone Variante von `List`.
+    AsyncList => List {
+        fn entry_search(
+            name: Option<String>,
+            language: Option<String>,
+            medium_type: Option<Medium>,
+            genre: Option<String>,
+            nogenre: Option<String>,
+            fsk: Option<String>,
+            sort: Option<SearchSort>,
+            length: Option<u64>,
+            lengthlimit: Option<LengthLimit>,
+            tags: Option<String>,
+            notags: Option<String>,
+            tagratefilter: Option<String>,
+            tagspoilerfilter: Option<String>,
+            page: Option<u64>,
+            limit: Option<u64>
+        ) -> Vec<list::EntrySearch>;
+        fn get_entrylist(
+            kat: Option<Kategorie>,
+            medium: Option<Medium>,
+            is_h: Option<bool>,
+            start: Option<String>,
+            page: Option<u64>,
+            limit: Option<u64>
+        ) -> Vec<list::EntryList>;
+        fn get_tag_ids(search: String) -> list::TagIDs;
+        fn get_tags(
+            search: Option<String>,
+            tag_type: Option<String>,
+            sort: Option<String>,
+            sort_type: Option<String>,
+            sub_type: Option<SubType>
+        ) -> Vec<list::Tag>;
+        fn get_translatorgroups(
+            start: Option<String>,
+            contains: Option<String>,
+            page: Option<u64>,
+            limit: Option<u64>
+        ) -> Vec<list::TranslatorGroup>;
+        fn get_industrys(
+            start: Option<String>,
+            contains: Option<String>,
+            country: Option<String>,
+            firma_type: Option<Firma>,
+            page: Option<u64>,
+            limit: Option<u64>
+        ) -> Vec<list::Industry>;
+        fn get_translatorgroups_projects(
+            id: u64,
+            status_type: Option<TranslationStatus>,
+            is_h: Option<i8>,
+            page: Option<u64>,
+            limit: Option<u64>
+        ) -> Vec<list::TranslatorGroupProject>;
+        fn get_industry_projects(
+            id: u64,
+            firma_type: Option<Firma>,
+            is_h: Option<i8>,
+            page: Option<u64>,
+            limit: Option<u64>
+        ) -> Vec<list::IndustrysProject>;
+    }
+}
+
+/// Asynchrone Variante von `Manga`.
+#[derive(Debug, Clone)]
+pub struct AsyncManga {
+    executor: Executor,
+}
+
+impl AsyncManga {
+    /// Asynchrone Variante von `Manga::get_chapter`.
+    pub fn get_chapter(&self, id: u64, episode: u64, language: &str) -> ProxerFuture<Vec<manga::Chapter>> {
+        let language = language.to_owned();
+        self.executor.spawn(move |proxer| Manga::new(proxer).get_chapter(id, episode, &language))
+    }
+}
+
+async_class! {
+    /// Asynchrone Variante von `Media`.
+    AsyncMedia => Media {
+        fn get_randomheader(style: Option<String>) -> Vec<media::RandomHeader>;
+        fn get_headerlist() -> Vec<media::HeaderList>;
+    }
+}
+
+async_class! {
+    /// Asynchrone Variante von `Messenger`.
+    AsyncMessenger => Messenger {
+        fn get_constants() -> Vec<messenger::Constants>;
+        fn get_conferences(conference_type: Option<ConferenceOption>, page: Option<u64>) -> Vec<messenger::Conference>;
+        fn get_conference_info(conference_id: u64) -> messenger::ConferenceInfo;
+        fn get_user_info(user_id: u64) -> messenger::UserInfo;
+        fn get_messages(conference_id: Option<u64>, message: Option<u64>, read: Option<bool>) -> Vec<messenger::Messages>;
+        fn new_conference(text: String, username: String) -> u64;
+        fn new_conferencegroup(users: Vec<String>, tropic: String, text: Option<String>) -> u64;
+        fn report(text: String, conference_id: u64) -> ();
+        fn set_message(conference_id: u64, text: String) -> String;
+        fn set_read(conference_id: u64) -> ();
+        fn set_unread(conference_id: u64) -> ();
+        fn set_block(conference_id: u64) -> ();
+        fn set_unblock(conference_id: u64) -> ();
+        fn set_favour(conference_id: u64) -> ();
+        fn set_unfavour(conference_id: u64) -> ();
+    }
+}
+
+async_class! {
+    /// Asynchrone Variante von `Notification`.
+    AsyncNotification => Notification {
+        fn get_count() -> String;
+        fn get_news_per_api(page: Option<u64>, limit: Option<u64>) -> Vec<notification::News>;
+        fn delete_notification(nid: Option<u64>) -> ();
+    }
+}
+
+async_class! {
+    /// Asynchrone Variante von `Ucp`.
+    AsyncUcp => Ucp {
+        fn get_list(
+            kat: Option<Kategorie>,
+            page: Option<u64>,
+            limit: Option<u64>,
+            search: Option<String>,
+            search_start: Option<String>,
+            sort: Option<Sort>
+        ) -> Vec<ucp::List>;
+        fn get_listsum(kat: Option<Kategorie>) -> String;
+        fn get_topten() -> String;
+        fn get_history(limit: Option<u64>, page: Option<u64>) -> Vec<ucp::History>;
+        fn get_votes() -> Vec<ucp::Vote>;
+        fn get_reminder(kat: Option<Kategorie>, page: Option<u64>, limit: Option<u64>) -> Vec<ucp::Reminder>;
+        fn delete_reminder(id: u64) -> ();
+        fn delte_favorite(id: u64) -> ();
+        fn delte_vote(id: u64) -> ();
+        fn set_commentstate(id: u64, value: u64) -> ();
+        fn set_reminder(id: u64, value: u64) -> ();
+    }
+}
+
+/// Asynchrone Variante von `User`. Das Token wird in der `Debug`-Ausgabe nicht angezeigt.
+#[derive(Clone)]
+pub struct AsyncUser {
+    /// Die ID des eingeloggten Users.
+    pub uid: u64,
+    /// Der Avatar des eingeloggten Users.
+    pub avatar: String,
+    /// Ein Login-Token.
+    pub token: String,
+
+    executor: Executor,
+}
+
+impl fmt::Debug for AsyncUser {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AsyncUser")
+            .field("uid", &self.uid)
+            .field("avatar", &self.avatar)
+            .field("token", &"***")
+            .field("executor", &self.executor)
+            .finish()
+    }
+}
+
+impl AsyncUser {
+    fn to_login(&self) -> user::Login {
+        user::Login {
+            uid: self.uid,
+            avatar: self.avatar.clone(),
+            token: self.token.clone(),
+        }
+    }
+
+    fn spawn<T, F>(&self, call: F) -> ProxerFuture<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&User) -> Result<T> + Send + 'static,
+    {
+        let login = self.to_login();
+        self.executor.spawn(move |proxer| call(&User::from_login(proxer, login)))
+    }
+
+    /// Die Daten der aktuellen Sitzung. Siehe `User::session`.
+    pub fn session(&self) -> user::Session {
+        user::Session::from(self.to_login())
+    }
+
+    /// Asynchrone Variante von `User::logout`.
+    pub fn logout(self) -> ProxerFuture<()> {
+        let login = self.to_login();
+        self.executor.spawn(move |proxer| User::from_login(proxer, login).logout())
+    }
+
+    /// Asynchrone Variante von `User::get_userinfo`.
+    pub fn get_userinfo(&self) -> ProxerFuture<user::UserInfo> {
+        self.spawn(|user| user.get_userinfo())
+    }
+
+    /// Asynchrone Variante von `User::get_topten`.
+    pub fn get_topten(&self, uid: u64, kat: Option<Kategorie>) -> ProxerFuture<Vec<user::TopTen>> {
+        self.spawn(move |user| user.get_topten(uid, kat))
+    }
+
+    /// Asynchrone Variante von `User::get_list`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn get_list(
+        &self,
+        uid: u64,
+        kat: Option<Kategorie>,
+        page: Option<u64>,
+        limit: Option<u64>,
+        search: Option<String>,
+        search_start: Option<String>,
+        sort: Option<Sort>,
+    ) -> ProxerFuture<Vec<user::List>> {
+        self.spawn(move |user| user.get_list(uid, kat, page, limit, search, search_start, sort))
+    }
+
+    /// Asynchrone Variante von `User::get_latestcomments`.
+    pub fn get_latestcomments(
+        &self,
+        kat: Option<Kategorie>,
+        page: Option<u64>,
+        limit: Option<u64>,
+        length: Option<u64>,
+    ) -> ProxerFuture<Vec<user::LatestComment>> {
+        self.spawn(move |user| user.get_latestcomments(kat, page, limit, length))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use std::sync::{mpsc, Barrier};
+    use std::task::Wake;
+    use std::time::Instant;
+
+    use super::*;
+    use info::GetEntry;
+    use testing::{self, ScriptedTransport};
+
+    struct Unpark(thread::Thread);
+
+    impl Wake for Unpark {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let waker = Waker::from(Arc::new(Unpark(thread::current())));
+        let mut context = Context::from_waker(&waker);
+        let mut future = Box::pin(future);
+        loop {
+            match future.as_mut().poll(&mut context) {
+                Poll::Ready(output) => return output,
+                Poll::Pending => thread::park(),
+            }
+        }
+    }
+
+    /// Ein Transport, der jede Anfrage nach `delay` mit dem Entry der angefragten ID beantwortet
+    /// und sich die Threads merkt, auf denen er aufgerufen wurde.
+    fn entries(delay: Duration, threads: &Arc<Mutex<HashSet<thread::ThreadId>>>) -> ScriptedTransport {
+        let threads = threads.clone();
+        ScriptedTransport::new().delay(delay).handler(move |request| {
+            threads.lock().unwrap().insert(thread::current().id());
+            let id = testing::param(request, "id").unwrap().parse().unwrap();
+            testing::ok(&testing::entry(id))
+        })
+    }
+
+    #[test]
+    fn resolves_requests() {
+        let threads = Arc::new(Mutex::new(HashSet::new()));
+        let proxer = AsyncProxer::from(entries(Duration::from_millis(0), &threads).proxer());
+
+        assert_eq!(block_on(proxer.info().get_entry(53)).unwrap().id, 53);
+        assert_eq!(block_on(proxer.execute(GetEntry { id: 1217 })).unwrap().id, 1217);
+    }
+
+    #[test]
+    fn limits_threads() {
+        let threads = Arc::new(Mutex::new(HashSet::new()));
+        let transport = entries(Duration::from_millis(30), &threads);
+        let proxer = AsyncProxer::from(transport.proxer()).max_threads(2);
+
+        let start = Instant::now();
+        let futures: Vec<_> = (1..7).map(|id| proxer.info().get_entry(id)).collect();
+        let ids: Vec<u64> = futures.into_iter().map(|future| block_on(future).unwrap().id).collect();
+
+        assert_eq!(ids, (1..7).collect::<Vec<_>>());
+        assert_eq!(transport.count(), 6);
+        assert!(threads.lock().unwrap().len() <= 2, "{} threads", threads.lock().unwrap().len());
+        assert!(start.elapsed() >= Duration::from_millis(80), "{:?}", start.elapsed());
+    }
+
+    #[test]
+    fn starts_threads_for_bursts() {
+        let pool = Arc::new(Pool::new(4));
+        // Ein freier Thread, der nie aufwacht: Die Anfragen müssen trotzdem neue Threads bekommen.
+        pool.lock().idle = 1;
+        let barrier = Arc::new(Barrier::new(3));
+        let (done, finished) = mpsc::channel();
+        // Die ersten drei Anfragen warten aufeinander, laufen also nur auf drei eigenen Threads durch.
+        for job in 0..4 {
+            let (barrier, done) = (barrier.clone(), done.clone());
+            Pool::execute(&pool, Box::new(move || {
+                if job < 3 {
+                    barrier.wait();
+                }
+                done.send(()).unwrap();
+            }));
+        }
+
+        for _ in 0..4 {
+            finished.recv_timeout(Duration::from_secs(5)).expect("jobs were queued behind the idle thread");
+        }
+        assert_eq!(pool.lock().threads, 3);
+    }
+
+    #[test]
+    fn survives_panicking_requests() {
+        let transport = ScriptedTransport::new().handler(|request| match testing::param(request, "id") {
+            Some(ref id) if id == "0" => panic!("transport failed"),
+            id => testing::ok(&testing::entry(id.unwrap().parse().unwrap())),
+        });
+        let proxer = AsyncProxer::from(transport.proxer()).max_threads(1);
+
+        assert!(block_on(proxer.info().get_entry(0)).is_err());
+        assert_eq!(block_on(proxer.info().get_entry(53)).unwrap().id, 53);
+    }
+}
@@ -0,0 +1,368 @@
+//! Mehrere unabhängige Anfragen parallel ausführen.
+//!
+//! Ein `Batch` führt dieselbe API-Funktion für viele Eingaben mit höchstens `concurrency`
+//! gleichzeitigen Anfragen aus. Jede Anfrage läuft wie gewohnt über `Proxer::execute`,
+//! Zwischenspeicher, Rate-Limit und Retry-Policy greifen also weiterhin: Ist das Budget einer
+//! API-Klasse aufgebraucht, warten die Anfragen (`RateLimitMode::Block`) bzw. schlagen einzeln
+//! mit `Error::BudgetExceeded` fehl (`RateLimitMode::Error`).
+//!
+//! Unabhängig von `Batch` werden gleiche lesende Anfragen, die gleichzeitig unterwegs sind,
+//! nur einmal gesendet: Fragen zwei Threads zur selben Zeit denselben Entry ab, warten beide
+//! auf dieselbe Antwort.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use proxer::Proxer;
+//! use proxer::info::GetEntry;
+//!
+//! let proxer = Proxer::new("api-key").unwrap();
+//! let ids = vec![53, 1217, 7834];
+//! let entries = proxer.batch(ids.iter().map(|&id| GetEntry { id })).concurrency(4).run();
+//! for (id, entry) in ids.iter().zip(entries) {
+//!     match entry {
+//!         Ok(entry) => println!("{}: {}", id, entry.name),
+//!         Err(err) => println!("{}: {}", id, err),
+//!     }
+//! }
+//! ```
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread;
+
+use endpoint::Endpoint;
+use error::*;
+use Proxer;
+
+/// Die Anzahl gleichzeitiger Anfragen, wenn `Batch::concurrency` nicht gesetzt wird.
+const DEFAULT_CONCURRENCY: usize = 4;
+
+/// Führt eine API-Funktion für mehrere Eingaben parallel aus. Siehe `Proxer::batch`.
+pub struct Batch<E> {
+    proxer: Proxer,
+    endpoints: Vec<E>,
+    concurrency: usize,
+}
+
+impl<E> Batch<E>
+where
+    E: Endpoint + Sync,
+    E::Output: Send,
+{
+    /// Erstellt einen `Batch` aus den Anfragen `endpoints`.
+    pub fn new<I>(proxer: &Proxer, endpoints: I) -> Batch<E>
+    where
+        I: IntoIterator<Item = E>,
+    {
+        Batch {
+            proxer: proxer.clone(),
+            endpoints: endpoints.into_iter().collect(),
+            concurrency: DEFAULT_CONCURRENCY,
+        }
+    }
+
+    /// Sendet höchstens `concurrency` Anfragen gleichzeitig. Default: 4.
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Führt alle Anfragen aus und liefert ihre Ergebnisse in der Reihenfolge der Eingabe.
+    ///
+    /// Schlägt eine Anfrage fehl, enthält nur ihr Eintrag den Fehler, die übrigen Anfragen laufen weiter.
+    /// Der aufrufende Thread arbeitet selbst mit, bei `concurrency(1)` wird also kein Thread gestartet.
+    pub fn run(self) -> Vec<Result<E::Output>> {
+        let results: Vec<Mutex<Option<Result<E::Output>>>> = self.endpoints.iter().map(|_| Mutex::new(None)).collect();
+        let next = AtomicUsize::new(0);
+        let work = || loop {
+            let index = next.fetch_add(1, Ordering::SeqCst);
+            let endpoint = match self.endpoints.get(index) {
+                Some(endpoint) => endpoint,
+                None => break,
+            };
+            let result = self.proxer.execute(endpoint);
+            *results[index].lock().unwrap_or_else(|e| e.into_inner()) = Some(result);
+        };
+
+        let workers = self.concurrency.min(self.endpoints.len());
+        debug!("Running {} requests with {} workers", self.endpoints.len(), workers);
+        thread::scope(|scope| {
+            for _ in 1..workers {
+                let spawned = thread::Builder::new()
+                    .name("proxer-batch".to_owned())
+                    .spawn_scoped(scope, work);
+                if let Err(err) = spawned {
+                    warn!("Could not start batch worker: {}", err);
+                    break;
+                }
+            }
+            work();
+        });
+
+        results
+            .into_iter()
+            .map(|result| {
+                result
+                    .into_inner()
+                    .unwrap_or_else(|e| e.into_inner())
+                    .unwrap_or_else(|| Err(Error::Other("The batch request was not executed.".to_owned())))
+            })
+            .collect()
+    }
+}
+
+impl<E: fmt::Debug> fmt::Debug for Batch<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Batch")
+            .field("endpoints", &self.endpoints)
+            .field("concurrency", &self.concurrency)
+            .finish()
+    }
+}
+
+/// API-Klasse, API-Funktion, kodierte Parameter und Login-Token einer Anfrage.
+type RequestKey = (String, String, String, Option<String>);
+
+/// Eine Anfrage, die gerade gesendet wird. `None` bis sie beendet ist, danach die Antwort
+/// bzw. `Some(None)`, wenn sie fehlgeschlagen ist.
+#[derive(Default)]
+struct Pending {
+    response: Mutex<Option<Option<Vec<u8>>>>,
+    done: Condvar,
+}
+
+/// Fasst gleiche lesende Anfragen zusammen, die gleichzeitig gesendet werden.
+#[derive(Default)]
+pub(crate) struct InFlight {
+    requests: Mutex<HashMap<RequestKey, Arc<Pending>>>,
+}
+
+impl InFlight {
+    /// Sendet die Anfrage mit `send`, oder wartet auf die Antwort einer gleichen Anfrage, die bereits unterwegs ist.
+    ///
+    /// Schlägt die andere Anfrage fehl, wird die Anfrage selbst gesendet, da sich ein `Error` nicht teilen lässt.
+    pub(crate) fn run<F>(&self, key: RequestKey, send: F) -> Result<Vec<u8>>
+    where
+        F: FnOnce() -> Result<Vec<u8>>,
+    {
+        let (pending, leader) = {
+            let mut requests = self.requests.lock().unwrap_or_else(|e| e.into_inner());
+            match requests.get(&key) {
+                Some(pending) => (pending.clone(), false),
+                None => {
+                    let pending = Arc::new(Pending::default());
+                    requests.insert(key.clone(), pending.clone());
+                    (pending, true)
+                }
+            }
+        };
+
+        if !leader {
+            let mut response = pending.response.lock().unwrap_or_else(|e| e.into_inner());
+            while response.is_none() {
+                response = pending.done.wait(response).unwrap_or_else(|e| e.into_inner());
+            }
+            if let Some(Some(ref response)) = *response {
+                debug!("Sharing response of {}/{} with a request in flight", key.0, key.1);
+                return Ok(response.clone());
+            }
+            drop(response);
+            return send();
+        }
+
+        let mut finish = Finish {
+            in_flight: self,
+            key,
+            pending,
+            response: None,
+        };
+        let result = send();
+        finish.response = result.as_ref().ok().cloned();
+        result
+    }
+}
+
+impl fmt::Debug for InFlight {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let requests = self.requests.lock().unwrap_or_else(|e| e.into_inner());
+        f.debug_struct("InFlight").field("requests", &requests.len()).finish()
+    }
+}
+
+/// Beendet eine Anfrage und weckt die wartenden Threads, auch wenn `send` abbricht.
+struct Finish<'a> {
+    in_flight: &'a InFlight,
+    key: RequestKey,
+    pending: Arc<Pending>,
+    response: Option<Vec<u8>>,
+}
+
+impl<'a> Drop for Finish<'a> {
+    fn drop(&mut self) {
+        self.in_flight.requests.lock().unwrap_or_else(|e| e.into_inner()).remove(&self.key);
+        *self.pending.response.lock().unwrap_or_else(|e| e.into_inner()) = Some(self.response.take());
+        self.pending.done.notify_all();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::panic::{self, AssertUnwindSafe};
+    use std::sync::Barrier;
+    use std::time::Duration;
+
+    use super::*;
+    use info::GetEntry;
+    use testing::{self, ScriptedTransport};
+
+    fn key(id: u64) -> RequestKey {
+        ("info".to_owned(), "entry".to_owned(), format!("id={}", id), None)
+    }
+
+    /// Startet zwei Anfragen mit gleichem Schlüssel, wobei die zweite erst beginnt, wenn die erste unterwegs ist.
+    fn run_two<F, G>(in_flight: &InFlight, leader: F, follower: G) -> (Result<Vec<u8>>, Result<Vec<u8>>)
+    where
+        F: FnOnce() -> Result<Vec<u8>> + Send,
+        G: FnOnce() -> Result<Vec<u8>> + Send,
+    {
+        let started = Barrier::new(2);
+        thread::scope(|scope| {
+            let first = scope.spawn(|| {
+                in_flight.run(key(1), || {
+                    started.wait();
+                    thread::sleep(Duration::from_millis(50));
+                    leader()
+                })
+            });
+            started.wait();
+            let second = in_flight.run(key(1), follower);
+            (first.join().unwrap(), second)
+        })
+    }
+
+    #[test]
+    fn same_key_is_sent_once() {
+        let in_flight = InFlight::default();
+        let (first, second) = run_two(
+            &in_flight,
+            || Ok(b"response".to_vec()),
+            || panic!("The follower must not send."),
+        );
+
+        assert_eq!(first.unwrap(), b"response");
+        assert_eq!(second.unwrap(), b"response");
+        assert!(in_flight.requests.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn follower_resends_when_leader_fails() {
+        let in_flight = InFlight::default();
+        let (first, second) = run_two(
+            &in_flight,
+            || Err(Error::Other("failed".to_owned())),
+            || Ok(b"resent".to_vec()),
+        );
+
+        assert!(first.is_err());
+        assert_eq!(second.unwrap(), b"resent");
+    }
+
+    #[test]
+    fn different_keys_are_sent_separately() {
+        let in_flight = InFlight::default();
+        let first = in_flight.run(key(1), || Ok(b"1".to_vec())).unwrap();
+        let second = in_flight.run(key(2), || Ok(b"2".to_vec())).unwrap();
+        let third = in_flight.run(key(1), || Ok(b"3".to_vec())).unwrap();
+
+        assert_eq!((first, second, third), (b"1".to_vec(), b"2".to_vec(), b"3".to_vec()));
+    }
+
+    #[test]
+    fn panicking_send_removes_key() {
+        let in_flight = InFlight::default();
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            in_flight.run(key(1), || panic!("send failed"))
+        }));
+
+        assert!(result.is_err());
+        assert!(in_flight.requests.lock().unwrap().is_empty());
+        assert_eq!(in_flight.run(key(1), || Ok(b"next".to_vec())).unwrap(), b"next");
+    }
+
+    #[test]
+    fn panicking_leader_wakes_follower() {
+        let in_flight = InFlight::default();
+        let started = Barrier::new(2);
+        let second = thread::scope(|scope| {
+            let first = scope.spawn(|| {
+                in_flight.run(key(1), || {
+                    started.wait();
+                    thread::sleep(Duration::from_millis(50));
+                    panic!("send failed")
+                })
+            });
+            started.wait();
+            let second = in_flight.run(key(1), || Ok(b"resent".to_vec()));
+            assert!(first.join().is_err());
+            second
+        });
+
+        assert_eq!(second.unwrap(), b"resent");
+    }
+
+    #[test]
+    fn proxer_shares_identical_requests() {
+        let transport = ScriptedTransport::new()
+            .delay(Duration::from_millis(50))
+            .json(&testing::ok(&testing::entry(53)));
+        let proxer = transport.proxer();
+        let entries: Vec<_> = thread::scope(|scope| {
+            let workers: Vec<_> = (0..4)
+                .map(|_| scope.spawn(|| proxer.execute(&GetEntry { id: 53 })))
+                .collect();
+            workers.into_iter().map(|worker| worker.join().unwrap()).collect()
+        });
+
+        assert!(entries.iter().all(|entry| entry.as_ref().unwrap().id == 53));
+        assert!(transport.count() < 4, "{} requests sent", transport.count());
+    }
+
+    #[test]
+    fn keeps_order_and_reports_errors() {
+        let transport = ScriptedTransport::new()
+            .json(&testing::ok(&testing::entry(1)))
+            .json(&testing::api_error(3007))
+            .json(&testing::ok(&testing::entry(3)));
+        let proxer = transport.proxer();
+        let results = proxer.batch((1..4).map(|id| GetEntry { id })).concurrency(1).run();
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].as_ref().unwrap().id, 1);
+        match results[1] {
+            Err(Error::ProxerError(ProxerErrorCode::InfoInvalidId, _)) => {}
+            ref other => panic!("unexpected result: {:?}", other),
+        }
+        assert_eq!(results[2].as_ref().unwrap().id, 3);
+        let sent: Vec<_> = transport.requests().iter().map(|request| testing::param(request, "id")).collect();
+        assert_eq!(sent, vec![Some("1".to_owned()), Some("2".to_owned()), Some("3".to_owned())]);
+    }
+
+    #[test]
+    fn keeps_order_with_parallel_workers() {
+        let transport = ScriptedTransport::new()
+            .delay(Duration::from_millis(5))
+            .handler(|request| {
+                let id = testing::param(request, "id").unwrap().parse().unwrap();
+                testing::ok(&testing::entry(id))
+            });
+        let proxer = transport.proxer();
+        let results = proxer.batch((1..21).map(|id| GetEntry { id })).concurrency(4).run();
+
+        let ids: Vec<u64> = results.into_iter().map(|entry| entry.unwrap().id).collect();
+        assert_eq!(ids, (1..21).collect::<Vec<_>>());
+        assert_eq!(transport.count(), 20);
+    }
+}
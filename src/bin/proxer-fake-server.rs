@@ -0,0 +1,32 @@
+//! Startet einen nachgebauten Proxer-Server mit Beispieldaten.
+//!
+//! Aufruf: `proxer-fake-server [ADRESSE] [API-KEY]`, Default: `127.0.0.1:8080` und `fake-api-key`.
+//! Die ausgegebene Basis-URL kann an `ProxerBuilder::base_url` übergeben werden.
+
+extern crate proxer;
+
+use std::env;
+use std::process;
+
+use proxer::fake_server::{FakeApi, FakeServer};
+
+fn main() {
+    let mut args = env::args().skip(1);
+    let address = args.next().unwrap_or_else(|| "127.0.0.1:8080".to_owned());
+    let api_key = args.next().unwrap_or_else(|| "fake-api-key".to_owned());
+
+    let server = match FakeServer::start(FakeApi::with_sample_data(&api_key), address.as_str()) {
+        Ok(server) => server,
+        Err(err) => {
+            eprintln!("{}", err);
+            process::exit(1);
+        }
+    };
+    println!("Basis-URL: {}", server.base_url());
+    println!("API-Key:   {}", api_key);
+    println!("Login:     genesis / passwort, souryo / geheim");
+
+    loop {
+        std::thread::park();
+    }
+}
@@ -0,0 +1,771 @@
+//! Zwischenspeicher für API-Antworten.
+//!
+//! Viele Daten der API (z.B. `info/fullentry` oder `list/tags`) ändern sich selten, ihre Abfrage
+//! belastet den Server jedoch stark. Ein mit `ProxerBuilder::cache` gesetzter `ResponseCache`
+//! speichert erfolgreiche Antworten nach API-Klasse, Funktion und Parametern für eine Endpunkt-abhängige Zeit.
+//!
+//! Mit `ResponseCache::directory` werden die Antworten zusätzlich auf der Festplatte abgelegt,
+//! sodass sie auch nach einem Neustart der Anwendung verfügbar sind.
+
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Mutex, MutexGuard};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use serde_json;
+
+use models::EmptyResponse;
+
+const MINUTE: u64 = 60;
+const HOUR: u64 = 60 * MINUTE;
+
+const DEFAULT_DISK_SIZE: u64 = 50 * 1024 * 1024;
+
+/// Die Standard-Gültigkeitsdauer der Antworten einer API-Funktion.
+/// `None` bedeutet, dass die Antworten nicht zwischengespeichert werden.
+pub fn default_ttl(api_class: &str, api_function: &str) -> Option<Duration> {
+    let seconds = match (api_class, api_function) {
+        ("info", "fullentry") | ("info", "entry") | ("info", "names") => 6 * HOUR,
+        ("info", "comments") | ("info", "listinfo") | ("info", "gate") => 10 * MINUTE,
+        ("info", "setuserinfo") => return None,
+        ("info", _) => HOUR,
+        ("list", "tags") | ("list", "tagids") => 24 * HOUR,
+        ("list", _) => 30 * MINUTE,
+        ("media", "headerlist") => 24 * HOUR,
+        ("media", _) => return None,
+        ("manga", _) | ("anime", "link") => HOUR,
+        ("anime", _) => 10 * MINUTE,
+        ("user", "login") | ("user", "logout") => return None,
+        ("user", _) => 5 * MINUTE,
+        _ => return None,
+    };
+    Some(Duration::from_secs(seconds))
+}
+
+/// Die API-Klassen, deren Antworten nach der verändernden Funktion veraltet sein können.
+fn affected_classes(api_class: &str) -> &'static [&'static str] {
+    match api_class {
+        "info" | "ucp" => &["info", "ucp", "user"],
+        "messenger" => &["messenger"],
+        "notifications" => &["notifications"],
+        "user" => &["user", "ucp", "messenger", "notifications"],
+        _ => &[],
+    }
+}
+
+/// Zähler des `ResponseCache`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    /// Anfragen, die aus dem Zwischenspeicher beantwortet wurden.
+    pub hits: usize,
+    /// Zwischenspeicherbare Anfragen, die an den Server gesendet wurden.
+    pub misses: usize,
+    /// Die Anzahl der aktuell gespeicherten Antworten.
+    pub entries: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct CacheKey {
+    api_class: String,
+    api_function: String,
+    params: String,
+    login_token: Option<String>,
+}
+
+#[derive(Debug)]
+struct CacheEntry {
+    body: Vec<u8>,
+    expires: Instant,
+    /// Wann die Antwort zuletzt verwendet wurde, für die LRU-Verdrängung.
+    used: u64,
+}
+
+/// Die Metadaten einer auf der Festplatte gespeicherten Antwort.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct DiskMeta {
+    api_class: String,
+    api_function: String,
+    params: String,
+    /// Unix-Timestamp, ab dem die Antwort veraltet ist.
+    expires: u64,
+    /// Zeitpunkt des letzten Zugriffs in Nanosekunden seit 1970, für die LRU-Verdrängung.
+    /// Innerhalb eines Prozesses streng steigend, sodass jeder Zugriff eindeutig einsortiert wird.
+    accessed: u64,
+    /// Die Größe des Bodys in Bytes.
+    size: u64,
+}
+
+/// Legt Antworten als `<hash>.body` und `<hash>.meta` in einem Verzeichnis ab.
+#[derive(Debug)]
+struct DiskStore {
+    directory: PathBuf,
+    max_size: u64,
+    lock: Mutex<()>,
+    /// Der zuletzt vergebene Wert für `DiskMeta::accessed`.
+    last_access: AtomicU64,
+}
+
+/// Speichert erfolgreiche API-Antworten im Arbeitsspeicher.
+///
+/// Antworten werden zusätzlich nach dem Login-Token getrennt, damit sich die Daten
+/// verschiedener User nicht vermischen. Nach verändernden Anfragen (z.B. `Info::set_userinfo`)
+/// werden die Antworten der betroffenen API-Klassen verworfen.
+#[derive(Debug)]
+pub struct ResponseCache {
+    ttls: HashMap<(String, String), Option<Duration>>,
+    max_entries: usize,
+    max_disk_size: u64,
+    disk: Option<DiskStore>,
+    offline: bool,
+    entries: Mutex<HashMap<CacheKey, CacheEntry>>,
+    uses: AtomicU64,
+    hits: AtomicUsize,
+    misses: AtomicUsize,
+}
+
+impl Default for ResponseCache {
+    fn default() -> ResponseCache {
+        ResponseCache::new()
+    }
+}
+
+impl ResponseCache {
+    /// Erstellt einen leeren Zwischenspeicher mit den Gültigkeitsdauern aus `default_ttl`
+    /// und höchstens 1000 Einträgen.
+    pub fn new() -> ResponseCache {
+        ResponseCache {
+            ttls: HashMap::new(),
+            max_entries: 1000,
+            max_disk_size: DEFAULT_DISK_SIZE,
+            disk: None,
+            offline: false,
+            entries: Mutex::new(HashMap::new()),
+            uses: AtomicU64::new(0),
+            hits: AtomicUsize::new(0),
+            misses: AtomicUsize::new(0),
+        }
+    }
+
+    /// Setzt die Gültigkeitsdauer für eine API-Funktion. `None` deaktiviert den Zwischenspeicher für sie.
+    pub fn ttl(mut self, api_class: &str, api_function: &str, ttl: Option<Duration>) -> Self {
+        self.ttls.insert((api_class.to_owned(), api_function.to_owned()), ttl);
+        self
+    }
+
+    /// Die höchste Anzahl gespeicherter Antworten. Default: 1000.
+    /// Wird sie erreicht, wird die am längsten nicht verwendete Antwort verworfen.
+    pub fn max_entries(mut self, max_entries: usize) -> Self {
+        self.max_entries = max_entries;
+        self
+    }
+
+    /// Speichert die Antworten zusätzlich im Verzeichnis `directory`, das bei Bedarf angelegt wird.
+    /// Die Daten bleiben so auch nach einem Neustart der Anwendung erhalten.
+    pub fn directory<P: AsRef<Path>>(mut self, directory: P) -> Self {
+        self.disk = Some(DiskStore {
+            directory: directory.as_ref().to_path_buf(),
+            max_size: self.max_disk_size,
+            lock: Mutex::new(()),
+            last_access: AtomicU64::new(0),
+        });
+        self
+    }
+
+    /// Die höchste Größe aller auf der Festplatte gespeicherten Antworten in Bytes. Default: 50 MiB.
+    /// Wird sie überschritten, werden die am längsten nicht verwendeten Antworten gelöscht.
+    /// Hat nur mit `directory` eine Wirkung.
+    pub fn max_disk_size(mut self, max_size: u64) -> Self {
+        self.max_disk_size = max_size;
+        if let Some(ref mut disk) = self.disk {
+            disk.max_size = max_size;
+        }
+        self
+    }
+
+    /// Im Offline-Modus werden zwischengespeicherte Anfragen nicht an den Server gesendet. Gespeicherte
+    /// Antworten werden auch nach Ablauf ihrer Gültigkeit verwendet, fehlt die Antwort, schlägt die Anfrage
+    /// mit `Error::NotCached` fehl. Verändernde Anfragen und API-Funktionen ohne Gültigkeitsdauer
+    /// (siehe `ttl_for`) werden weiterhin gesendet.
+    pub fn offline(mut self, offline: bool) -> Self {
+        self.offline = offline;
+        self
+    }
+
+    /// Ob der Offline-Modus aktiv ist.
+    pub fn is_offline(&self) -> bool {
+        self.offline
+    }
+
+    /// Die Gültigkeitsdauer der Antworten einer API-Funktion.
+    pub fn ttl_for(&self, api_class: &str, api_function: &str) -> Option<Duration> {
+        match self.ttls.get(&(api_class.to_owned(), api_function.to_owned())) {
+            Some(ttl) => *ttl,
+            None => default_ttl(api_class, api_function),
+        }
+    }
+
+    /// Die aktuellen Zähler.
+    pub fn stats(&self) -> CacheStats {
+        CacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            entries: self.lock().len(),
+        }
+    }
+
+    /// Verwirft alle Antworten der API-Funktion.
+    pub fn invalidate(&self, api_class: &str, api_function: &str) {
+        self.lock()
+            .retain(|key, _| key.api_class != api_class || key.api_function != api_function);
+        if let Some(ref disk) = self.disk {
+            disk.remove_where(|meta| meta.api_class == api_class && meta.api_function == api_function);
+        }
+    }
+
+    /// Verwirft alle Antworten der API-Klasse.
+    pub fn invalidate_class(&self, api_class: &str) {
+        self.lock().retain(|key, _| key.api_class != api_class);
+        if let Some(ref disk) = self.disk {
+            disk.remove_where(|meta| meta.api_class == api_class);
+        }
+    }
+
+    /// Verwirft alle Antworten.
+    pub fn clear(&self) {
+        self.lock().clear();
+        if let Some(ref disk) = self.disk {
+            disk.remove_where(|_| true);
+        }
+    }
+
+    /// Liefert die gespeicherte Antwort, falls vorhanden und noch gültig.
+    pub(crate) fn get(
+        &self,
+        api_class: &str,
+        api_function: &str,
+        params: &str,
+        login_token: Option<&str>,
+    ) -> Option<Vec<u8>> {
+        if self.ttl_for(api_class, api_function).is_none() && !self.offline {
+            return None;
+        }
+
+        let key = CacheKey::new(api_class, api_function, params, login_token);
+        let body = self.get_memory(&key, self.offline).or_else(|| {
+            let disk = self.disk.as_ref()?;
+            let (body, remaining) = disk.get(&key, self.offline)?;
+            if let Some(remaining) = remaining {
+                self.insert_memory(key.clone(), &body, remaining);
+            }
+            Some(body)
+        });
+        match body {
+            Some(_) => {
+                debug!("Cache hit for {}/{}", api_class, api_function);
+                self.hits.fetch_add(1, Ordering::Relaxed)
+            }
+            None => self.misses.fetch_add(1, Ordering::Relaxed),
+        };
+        body
+    }
+
+    /// Speichert eine Antwort, sofern die API-Funktion zwischengespeichert wird und die Antwort erfolgreich war.
+    pub(crate) fn insert(
+        &self,
+        api_class: &str,
+        api_function: &str,
+        params: &str,
+        login_token: Option<&str>,
+        body: &[u8],
+    ) {
+        let ttl = match self.ttl_for(api_class, api_function) {
+            Some(ttl) => ttl,
+            None => return,
+        };
+        match serde_json::from_slice::<EmptyResponse>(body) {
+            Ok(ref data) if data.error == 0 => (),
+            _ => return,
+        }
+
+        let key = CacheKey::new(api_class, api_function, params, login_token);
+        if let Some(ref disk) = self.disk {
+            if let Err(err) = disk.insert(&key, body, ttl) {
+                warn!("Could not write cached response to {:?}: {:?}", disk.directory, err);
+            }
+        }
+        self.insert_memory(key, body, ttl);
+    }
+
+    /// Liefert die Antwort aus dem Arbeitsspeicher. Abgelaufene Antworten werden nur mit `stale` geliefert.
+    fn get_memory(&self, key: &CacheKey, stale: bool) -> Option<Vec<u8>> {
+        let mut entries = self.lock();
+        match entries.get_mut(key) {
+            Some(entry) if stale || entry.expires > Instant::now() => {
+                entry.used = self.uses.fetch_add(1, Ordering::Relaxed);
+                Some(entry.body.clone())
+            }
+            Some(_) => {
+                entries.remove(key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    fn insert_memory(&self, key: CacheKey, body: &[u8], ttl: Duration) {
+        let now = Instant::now();
+        let mut entries = self.lock();
+        if entries.len() >= self.max_entries && !entries.contains_key(&key) {
+            entries.retain(|_, entry| entry.expires > now);
+        }
+        if entries.len() >= self.max_entries && !entries.contains_key(&key) {
+            let least_used = entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.used)
+                .map(|(key, _)| key.clone());
+            if let Some(least_used) = least_used {
+                entries.remove(&least_used);
+            }
+        }
+        if self.max_entries > 0 {
+            entries.insert(
+                key,
+                CacheEntry {
+                    body: body.to_vec(),
+                    expires: now + ttl,
+                    used: self.uses.fetch_add(1, Ordering::Relaxed),
+                },
+            );
+        }
+    }
+
+    /// Verwirft alle Antworten, die durch die verändernde API-Funktion veraltet sein können.
+    pub(crate) fn invalidate_after(&self, api_class: &str, api_function: &str) {
+        let classes = affected_classes(api_class);
+        if classes.is_empty() {
+            return;
+        }
+        debug!("Invalidating cached {:?} after {}/{}", classes, api_class, api_function);
+        self.lock().retain(|key, _| !classes.contains(&key.api_class.as_str()));
+        if let Some(ref disk) = self.disk {
+            disk.remove_where(|meta| classes.contains(&meta.api_class.as_str()));
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<CacheKey, CacheEntry>> {
+        self.entries.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl CacheKey {
+    fn new(api_class: &str, api_function: &str, params: &str, login_token: Option<&str>) -> CacheKey {
+        CacheKey {
+            api_class: api_class.to_owned(),
+            api_function: api_function.to_owned(),
+            params: params.to_owned(),
+            login_token: login_token.map(str::to_owned),
+        }
+    }
+
+    /// Ein stabiler Hash (FNV-1a) des Schlüssels, der als Dateiname verwendet wird.
+    /// Das Login-Token fließt nur in den Hash ein und wird nicht auf der Festplatte gespeichert.
+    fn file_name(&self) -> String {
+        let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+        let parts = [
+            self.api_class.as_str(),
+            self.api_function.as_str(),
+            self.params.as_str(),
+            self.login_token.as_ref().map_or("", String::as_str),
+        ];
+        for part in &parts {
+            for byte in part.bytes().chain(Some(0)) {
+                hash ^= u64::from(byte);
+                hash = hash.wrapping_mul(0x0100_0000_01b3);
+            }
+        }
+        format!("{:016x}", hash)
+    }
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|time| time.as_secs())
+        .unwrap_or_default()
+}
+
+impl DiskStore {
+    /// Liefert den Body und die verbleibende Gültigkeit. Abgelaufene Antworten werden nur mit `stale` geliefert.
+    fn get(&self, key: &CacheKey, stale: bool) -> Option<(Vec<u8>, Option<Duration>)> {
+        let _guard = self.lock.lock().unwrap_or_else(|e| e.into_inner());
+        let name = key.file_name();
+        let mut meta = self.read_meta(&name).ok()?;
+        if meta.api_class != key.api_class || meta.api_function != key.api_function || meta.params != key.params {
+            return None;
+        }
+
+        let now = unix_now();
+        let remaining = meta.expires.checked_sub(now).filter(|seconds| *seconds > 0);
+        if remaining.is_none() && !stale {
+            self.remove(&name);
+            return None;
+        }
+        let mut body = Vec::new();
+        if File::open(self.path(&name, "body")).and_then(|mut file| file.read_to_end(&mut body)).is_err() {
+            self.remove(&name);
+            return None;
+        }
+
+        meta.accessed = self.access_stamp();
+        if let Err(err) = self.write_meta(&name, &meta) {
+            warn!("Could not update cache metadata in {:?}: {:?}", self.directory, err);
+        }
+        Some((body, remaining.map(Duration::from_secs)))
+    }
+
+    fn insert(&self, key: &CacheKey, body: &[u8], ttl: Duration) -> io::Result<()> {
+        let _guard = self.lock.lock().unwrap_or_else(|e| e.into_inner());
+        fs::create_dir_all(&self.directory)?;
+        let name = key.file_name();
+        let now = unix_now();
+        File::create(self.path(&name, "body"))?.write_all(body)?;
+        self.write_meta(
+            &name,
+            &DiskMeta {
+                api_class: key.api_class.clone(),
+                api_function: key.api_function.clone(),
+                params: key.params.clone(),
+                expires: now + ttl.as_secs(),
+                accessed: self.access_stamp(),
+                size: body.len() as u64,
+            },
+        )?;
+        self.evict(&name);
+        Ok(())
+    }
+
+    /// Ein Zeitstempel für `DiskMeta::accessed`, der größer ist als alle bisher vergebenen.
+    /// Wird nur unter `lock` aufgerufen.
+    fn access_stamp(&self) -> u64 {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|time| time.as_nanos() as u64)
+            .unwrap_or_default();
+        let stamp = now.max(self.last_access.load(Ordering::Relaxed) + 1);
+        self.last_access.store(stamp, Ordering::Relaxed);
+        stamp
+    }
+
+    /// Löscht die am längsten nicht verwendeten Antworten, bis die Größe wieder unter `max_size` liegt.
+    /// Die gerade geschriebene Antwort `keep` wird dabei nie gelöscht.
+    fn evict(&self, keep: &str) {
+        let mut metas = self.metas();
+        let mut size: u64 = metas.iter().map(|(_, meta)| meta.size).sum();
+        if size <= self.max_size {
+            return;
+        }
+        metas.sort_by_key(|(_, meta)| meta.accessed);
+        for (name, meta) in metas {
+            if size <= self.max_size {
+                break;
+            }
+            if name == keep {
+                continue;
+            }
+            debug!("Evicting cached {}/{} from disk", meta.api_class, meta.api_function);
+            self.remove(&name);
+            size -= meta.size;
+        }
+    }
+
+    fn remove_where<F: Fn(&DiskMeta) -> bool>(&self, predicate: F) {
+        let _guard = self.lock.lock().unwrap_or_else(|e| e.into_inner());
+        for (name, meta) in self.metas() {
+            if predicate(&meta) {
+                self.remove(&name);
+            }
+        }
+    }
+
+    /// Alle lesbaren Metadaten im Verzeichnis mit dem zugehörigen Dateinamen.
+    fn metas(&self) -> Vec<(String, DiskMeta)> {
+        let entries = match fs::read_dir(&self.directory) {
+            Ok(entries) => entries,
+            Err(_) => return Vec::new(),
+        };
+        entries
+            .filter_map(|entry| entry.ok())
+            .filter_map(|entry| {
+                let path = entry.path();
+                if path.extension()? != "meta" {
+                    return None;
+                }
+                let name = path.file_stem()?.to_str()?.to_owned();
+                let meta = self.read_meta(&name).ok()?;
+                Some((name, meta))
+            })
+            .collect()
+    }
+
+    fn read_meta(&self, name: &str) -> io::Result<DiskMeta> {
+        let file = File::open(self.path(name, "meta"))?;
+        serde_json::from_reader(file).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+    }
+
+    fn write_meta(&self, name: &str, meta: &DiskMeta) -> io::Result<()> {
+        let file = File::create(self.path(name, "meta"))?;
+        serde_json::to_writer(file, meta).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+    }
+
+    fn remove(&self, name: &str) {
+        let _ = fs::remove_file(self.path(name, "meta"));
+        let _ = fs::remove_file(self.path(name, "body"));
+    }
+
+    fn path(&self, name: &str, extension: &str) -> PathBuf {
+        self.directory.join(format!("{}.{}", name, extension))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::process;
+    use std::thread;
+
+    use super::*;
+    use error::Error;
+    use info::GetEntry;
+    use models::WatchType;
+    use testing::{self, ScriptedTransport};
+
+    /// Ein leeres Verzeichnis für einen Test, das beim Drop gelöscht wird.
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new(name: &str) -> TempDir {
+            let path = env::temp_dir().join(format!("proxer-cache-{}-{}", name, process::id()));
+            let _ = fs::remove_dir_all(&path);
+            TempDir(path)
+        }
+
+        fn size(&self) -> u64 {
+            fs::read_dir(&self.0)
+                .unwrap()
+                .filter_map(|entry| entry.ok())
+                .filter(|entry| entry.path().extension().is_some_and(|extension| extension == "body"))
+                .map(|entry| entry.metadata().unwrap().len())
+                .sum()
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    fn body(id: u64) -> Vec<u8> {
+        testing::ok(&testing::entry(id)).into_bytes()
+    }
+
+    fn insert(cache: &ResponseCache, id: u64) {
+        cache.insert("info", "entry", &format!("id={}", id), None, &body(id));
+    }
+
+    fn get(cache: &ResponseCache, id: u64) -> Option<Vec<u8>> {
+        cache.get("info", "entry", &format!("id={}", id), None)
+    }
+
+    #[test]
+    fn expires_after_ttl() {
+        let cache = ResponseCache::new()
+            .ttl("info", "entry", Some(Duration::from_millis(50)))
+            .ttl("info", "names", Some(Duration::from_secs(0)));
+        insert(&cache, 1);
+        cache.insert("info", "names", "id=1", None, &body(1));
+
+        assert_eq!(get(&cache, 1), Some(body(1)));
+        assert_eq!(cache.get("info", "names", "id=1", None), None);
+        thread::sleep(Duration::from_millis(80));
+        assert_eq!(get(&cache, 1), None);
+        assert_eq!(
+            cache.stats(),
+            CacheStats {
+                hits: 1,
+                misses: 2,
+                entries: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn skips_errors_and_uncached_functions() {
+        let cache = ResponseCache::new().ttl("info", "entry", None);
+        insert(&cache, 1);
+        cache.insert("info", "names", "id=1", None, testing::api_error(3007).as_bytes());
+        cache.insert("info", "names", "id=2", Some("token"), &body(2));
+
+        assert_eq!(get(&cache, 1), None);
+        assert_eq!(cache.get("info", "names", "id=1", None), None);
+        assert_eq!(cache.get("info", "names", "id=2", None), None);
+        assert_eq!(cache.get("info", "names", "id=2", Some("token")), Some(body(2)));
+    }
+
+    #[test]
+    fn evicts_least_recently_used() {
+        let cache = ResponseCache::new().max_entries(2);
+        insert(&cache, 1);
+        insert(&cache, 2);
+        assert!(get(&cache, 1).is_some());
+        insert(&cache, 3);
+
+        assert!(get(&cache, 1).is_some());
+        assert_eq!(get(&cache, 2), None);
+        assert!(get(&cache, 3).is_some());
+        assert_eq!(cache.stats().entries, 2);
+    }
+
+    #[test]
+    fn evicts_least_recently_used_regardless_of_ttl() {
+        let cache = ResponseCache::new()
+            .max_entries(2)
+            .ttl("info", "names", Some(Duration::from_secs(24 * HOUR)));
+        cache.insert("info", "names", "id=1", None, &body(1));
+        insert(&cache, 2);
+        insert(&cache, 3);
+
+        assert_eq!(cache.get("info", "names", "id=1", None), None);
+        assert!(get(&cache, 2).is_some());
+    }
+
+    #[test]
+    fn keeps_responses_on_disk() {
+        let dir = TempDir::new("disk");
+        insert(&ResponseCache::new().directory(&dir.0), 1);
+
+        let cache = ResponseCache::new().directory(&dir.0);
+        assert_eq!(get(&cache, 1), Some(body(1)));
+        cache.invalidate_after("ucp", "setcommentstate");
+        assert_eq!(get(&ResponseCache::new().directory(&dir.0), 1), None);
+    }
+
+    #[test]
+    fn max_disk_size_does_not_depend_on_order() {
+        let limit = body(1).len() as u64 * 2;
+        let first = TempDir::new("size-first");
+        let last = TempDir::new("size-last");
+        let caches = [
+            ResponseCache::new().max_disk_size(limit).directory(&first.0),
+            ResponseCache::new().directory(&last.0).max_disk_size(limit),
+        ];
+        for cache in &caches {
+            for id in 1..6 {
+                insert(cache, id);
+            }
+        }
+
+        assert!(first.size() <= limit, "{} > {}", first.size(), limit);
+        assert!(last.size() <= limit, "{} > {}", last.size(), limit);
+        assert!(first.size() > 0);
+    }
+
+    #[test]
+    fn evicts_least_recently_used_from_disk() {
+        let dir = TempDir::new("disk-lru");
+        // Ohne Einträge im Arbeitsspeicher wird jede Antwort von der Festplatte gelesen.
+        let cache = ResponseCache::new()
+            .max_entries(0)
+            .max_disk_size(body(1).len() as u64 * 2)
+            .directory(&dir.0);
+        insert(&cache, 1);
+        insert(&cache, 2);
+        assert!(get(&cache, 1).is_some());
+        insert(&cache, 3);
+
+        assert_eq!(get(&cache, 1), Some(body(1)));
+        assert_eq!(get(&cache, 2), None);
+        assert_eq!(get(&cache, 3), Some(body(3)));
+
+        insert(&cache, 4);
+        assert_eq!(get(&cache, 1), None);
+        assert_eq!(get(&cache, 3), Some(body(3)));
+        assert_eq!(get(&cache, 4), Some(body(4)));
+    }
+
+    #[test]
+    fn keeps_the_written_response_on_disk() {
+        let dir = TempDir::new("disk-keep");
+        let cache = ResponseCache::new().max_entries(0).max_disk_size(1).directory(&dir.0);
+        insert(&cache, 1);
+        insert(&cache, 2);
+
+        assert_eq!(get(&cache, 1), None);
+        assert_eq!(get(&cache, 2), Some(body(2)));
+    }
+
+    #[test]
+    fn offline_uses_stale_responses() {
+        let dir = TempDir::new("offline");
+        let online = ResponseCache::new()
+            .ttl("info", "entry", Some(Duration::from_secs(0)))
+            .directory(&dir.0);
+        insert(&online, 1);
+        assert_eq!(get(&online, 1), None);
+        insert(&online, 1);
+
+        let offline = ResponseCache::new().directory(&dir.0).offline(true);
+        assert_eq!(get(&offline, 1), Some(body(1)));
+        assert_eq!(get(&offline, 2), None);
+    }
+
+    #[test]
+    fn offline_fails_without_sending() {
+        let transport = ScriptedTransport::new().json(&testing::ok(&testing::entry(1)));
+        let proxer = transport
+            .builder()
+            .cache(ResponseCache::new().offline(true))
+            .build()
+            .unwrap();
+
+        match proxer.execute(&GetEntry { id: 1 }) {
+            Err(Error::NotCached {
+                ref api_class,
+                ref api_function,
+            }) => assert_eq!((api_class.as_str(), api_function.as_str()), ("info", "entry")),
+            other => panic!("unexpected result: {:?}", other),
+        }
+        assert_eq!(transport.count(), 0);
+    }
+
+    #[test]
+    fn offline_sends_uncached_requests() {
+        let transport = ScriptedTransport::new()
+            .json(&testing::ok(&testing::entry(1)))
+            .json(&testing::ok("null"));
+        let proxer = transport
+            .builder()
+            .cache(ResponseCache::new().ttl("info", "entry", None).offline(true))
+            .build()
+            .unwrap();
+
+        assert_eq!(proxer.execute(&GetEntry { id: 1 }).unwrap().id, 1);
+        proxer.set_login_token(Some("token".to_owned()));
+        proxer.info().set_userinfo(1, WatchType::Note).unwrap();
+        assert_eq!(transport.count(), 2);
+    }
+
+    #[test]
+    fn answers_repeated_requests_from_memory() {
+        let transport = ScriptedTransport::new().json(&testing::ok(&testing::entry(1)));
+        let proxer = transport.builder().cache(ResponseCache::new()).build().unwrap();
+
+        assert_eq!(proxer.execute(&GetEntry { id: 1 }).unwrap().id, 1);
+        assert_eq!(proxer.execute(&GetEntry { id: 1 }).unwrap().id, 1);
+        assert_eq!(transport.count(), 1);
+        assert_eq!(proxer.cache().unwrap().stats().hits, 1);
+    }
+}
@@ -0,0 +1,391 @@
+//! Aufzeichnen und Abspielen von Antworten für Tests ohne Netzwerk.
+//!
+//! Ein `Recorder` sendet alle Anfragen über einen anderen `Transport` und schreibt jede Antwort
+//! zusammen mit API-Klasse, API-Funktion und den geschwärzten Parametern in eine Cassette (JSON-Datei).
+//! Ein `Replayer` liefert später die passenden Antworten aus dieser Datei, ohne eine Verbindung aufzubauen.
+//! Passwörter und Nachrichtentexte werden nicht gespeichert (siehe `Params::redacted`),
+//! der API-Key und das Login-Token sind Header und werden ebenfalls nicht gespeichert.
+//! Das Token in der Antwort von `user/login` wird durch "***" ersetzt, Cookies und Authentifizierungs-Header
+//! der Antwort (z.B. `Set-Cookie`) werden verworfen.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use proxer::Proxer;
+//! use proxer::cassette::{Recorder, Replayer};
+//! use proxer::transport::HyperTransport;
+//!
+//! // Einmalig mit Netzwerk aufzeichnen ...
+//! let recorder = Recorder::new("tests/cassettes/info.json", HyperTransport::new().unwrap());
+//! let proxer = Proxer::builder("api-key").transport(recorder).build().unwrap();
+//! proxer.info().get_entry(53).unwrap();
+//!
+//! // ... und danach ohne Netzwerk abspielen.
+//! let replayer = Replayer::load("tests/cassettes/info.json").unwrap();
+//! let proxer = Proxer::builder("api-key").transport(replayer).build().unwrap();
+//! assert_eq!(proxer.info().get_entry(53).unwrap().id, 53);
+//! ```
+
+use std::fs::{self, File};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use serde_json::{self, Value};
+
+use error::*;
+use params::Params;
+use transport::{Request, Response, Transport};
+use {API_KEY_HEADER, LOGIN_TOKEN_HEADER};
+
+/// Header der Antwort, die nicht aufgezeichnet werden, da sie Sitzungsdaten enthalten können.
+static SECRET_HEADERS: [&str; 6] = [
+    "set-cookie",
+    "cookie",
+    "authorization",
+    "proxy-authorization",
+    API_KEY_HEADER,
+    LOGIN_TOKEN_HEADER,
+];
+
+/// Eine aufgezeichnete Anfrage mit ihrer Antwort.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Interaction {
+    /// Die API-Klasse, z.B. "info".
+    pub api_class: String,
+    /// Die API-Funktion, z.B. "entry".
+    pub api_function: String,
+    /// Die kodierten, geschwärzten Parameter der Anfrage.
+    pub params: String,
+    /// Der HTTP-Statuscode der Antwort.
+    pub status: u16,
+    /// Die Header der Antwort.
+    pub headers: Vec<(String, String)>,
+    /// Der Body der Antwort.
+    pub body: String,
+}
+
+impl Interaction {
+    fn matches(&self, api_class: &str, api_function: &str, params: &str) -> bool {
+        self.api_class == api_class && self.api_function == api_function && self.params == params
+    }
+
+    fn to_response(&self) -> Response {
+        Response {
+            status: self.status,
+            headers: self.headers.clone(),
+            body: self.body.clone().into_bytes(),
+        }
+    }
+}
+
+/// Der Inhalt einer Cassette-Datei.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct Cassette {
+    /// Die Aufzeichnungen in der Reihenfolge, in der sie gesendet wurden.
+    pub interactions: Vec<Interaction>,
+}
+
+impl Cassette {
+    /// Liest eine Cassette aus der Datei `path`.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Cassette> {
+        Ok(serde_json::from_reader(File::open(path)?)?)
+    }
+
+    /// Schreibt die Cassette in die Datei `path`. Fehlende Verzeichnisse werden angelegt.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        serde_json::to_writer_pretty(File::create(path)?, self)
+            .map_err(|err| Error::from(io::Error::new(io::ErrorKind::InvalidData, err)))
+    }
+}
+
+/// API-Klasse, API-Funktion und geschwärzte Parameter einer Anfrage.
+fn describe(request: &Request) -> (String, String, String) {
+    let path = request.url.split(['?', '#']).next().unwrap_or_default();
+    let mut segments = path.trim_end_matches('/').rsplit('/');
+    let api_function = segments.next().unwrap_or_default().to_owned();
+    let api_class = segments.next().unwrap_or_default().to_owned();
+    let params = Params::parse(&request.body).redacted().encode();
+    (api_class, api_function, params)
+}
+
+/// Der Body einer Antwort, beim Login ohne das Token.
+fn redact_body(api_class: &str, api_function: &str, body: &[u8]) -> String {
+    if (api_class, api_function) == ("user", "login") {
+        if let Ok(mut value) = serde_json::from_slice::<Value>(body) {
+            if let Some(token) = value.pointer_mut("/data/token") {
+                *token = Value::from("***");
+            }
+            return value.to_string();
+        }
+    }
+    String::from_utf8_lossy(body).into_owned()
+}
+
+/// Die Header einer Antwort ohne Cookies und Authentifizierungsdaten.
+fn redact_headers(headers: &[(String, String)]) -> Vec<(String, String)> {
+    headers
+        .iter()
+        .filter(|(name, _)| !SECRET_HEADERS.iter().any(|secret| name.eq_ignore_ascii_case(secret)))
+        .cloned()
+        .collect()
+}
+
+/// Ein `Transport`, der alle Antworten eines anderen `Transport`s in eine Cassette schreibt.
+///
+/// Die Datei wird nach jeder Antwort neu geschrieben und enthält danach alle Aufzeichnungen dieses `Recorder`s.
+#[derive(Debug)]
+pub struct Recorder<T> {
+    inner: T,
+    path: PathBuf,
+    cassette: Mutex<Cassette>,
+}
+
+impl<T: Transport> Recorder<T> {
+    /// Zeichnet alle Antworten von `inner` in die Datei `path` auf. Eine vorhandene Datei wird überschrieben.
+    pub fn new<P: AsRef<Path>>(path: P, inner: T) -> Recorder<T> {
+        Recorder {
+            inner,
+            path: path.as_ref().to_owned(),
+            cassette: Mutex::new(Cassette::default()),
+        }
+    }
+
+    /// Die bisherigen Aufzeichnungen.
+    pub fn cassette(&self) -> Cassette {
+        self.cassette.lock().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+}
+
+impl<T: Transport> Transport for Recorder<T> {
+    fn send(&self, request: &Request) -> Result<Response> {
+        let response = self.inner.send(request)?;
+        let (api_class, api_function, params) = describe(request);
+        debug!("Recording {}/{} with {}", api_class, api_function, params);
+
+        let mut cassette = self.cassette.lock().unwrap_or_else(|e| e.into_inner());
+        cassette.interactions.push(Interaction {
+            body: redact_body(&api_class, &api_function, &response.body),
+            api_class,
+            api_function,
+            params,
+            status: response.status,
+            headers: redact_headers(&response.headers),
+        });
+        cassette.save(&self.path)?;
+        Ok(response)
+    }
+}
+
+/// Ein `Transport`, der Antworten aus einer Cassette liefert, ohne eine Verbindung aufzubauen.
+///
+/// Eine Anfrage passt zu einer Aufzeichnung, wenn API-Klasse, API-Funktion und die geschwärzten Parameter
+/// übereinstimmen. Gibt es mehrere passende Aufzeichnungen, werden sie in der aufgezeichneten Reihenfolge
+/// geliefert, danach wird die letzte wiederholt. Anfragen ohne passende Aufzeichnung schlagen mit
+/// `Error::NotRecorded` fehl.
+#[derive(Debug)]
+pub struct Replayer {
+    interactions: Vec<Interaction>,
+    used: Mutex<Vec<bool>>,
+}
+
+impl Replayer {
+    /// Spielt die Aufzeichnungen von `cassette` ab.
+    pub fn new(cassette: Cassette) -> Replayer {
+        Replayer {
+            used: Mutex::new(vec![false; cassette.interactions.len()]),
+            interactions: cassette.interactions,
+        }
+    }
+
+    /// Spielt die Cassette aus der Datei `path` ab.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Replayer> {
+        Cassette::load(path).map(Replayer::new)
+    }
+
+    /// Die Aufzeichnungen, die noch nicht abgespielt wurden.
+    pub fn unused(&self) -> Vec<&Interaction> {
+        let used = self.used.lock().unwrap_or_else(|e| e.into_inner());
+        self.interactions
+            .iter()
+            .zip(used.iter())
+            .filter(|&(_, &used)| !used)
+            .map(|(interaction, _)| interaction)
+            .collect()
+    }
+}
+
+impl Transport for Replayer {
+    fn send(&self, request: &Request) -> Result<Response> {
+        let (api_class, api_function, params) = describe(request);
+        let mut used = self.used.lock().unwrap_or_else(|e| e.into_inner());
+        let matching: Vec<usize> = self
+            .interactions
+            .iter()
+            .enumerate()
+            .filter(|&(_, interaction)| interaction.matches(&api_class, &api_function, &params))
+            .map(|(index, _)| index)
+            .collect();
+
+        let index = match matching.iter().find(|&&index| !used[index]).or_else(|| matching.last()) {
+            Some(&index) => index,
+            None => {
+                error!("No recorded response for {}/{} with {}", api_class, api_function, params);
+                return Err(Error::NotRecorded {
+                    api_class,
+                    api_function,
+                    params,
+                });
+            }
+        };
+        used[index] = true;
+        debug!("Replaying {}/{} with {}", api_class, api_function, params);
+        Ok(self.interactions[index].to_response())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::process;
+    #[cfg(feature = "fake-server")]
+    use std::sync::Arc;
+
+    use super::*;
+    #[cfg(feature = "fake-server")]
+    use fake_server::FakeApi;
+    use testing::{self, ScriptedTransport};
+    #[cfg(feature = "fake-server")]
+    use Proxer;
+
+    /// Der Pfad einer Cassette für einen Test, die beim Drop gelöscht wird.
+    struct TempFile(PathBuf);
+
+    impl TempFile {
+        fn new(name: &str) -> TempFile {
+            TempFile(env::temp_dir().join(format!("proxer-cassette-{}-{}.json", name, process::id())))
+        }
+    }
+
+    impl Drop for TempFile {
+        fn drop(&mut self) {
+            let _ = fs::remove_file(&self.0);
+        }
+    }
+
+    #[test]
+    fn does_not_record_cookies() {
+        let file = TempFile::new("cookies");
+        let inner = ScriptedTransport::new().response(Ok(Response {
+            status: 200,
+            headers: vec![
+                ("Content-Type".to_owned(), "application/json".to_owned()),
+                ("Set-Cookie".to_owned(), "joomla_user_state=logged_in; path=/".to_owned()),
+                ("set-cookie".to_owned(), "e0da4f913f5f05ed7a3f6dc5f0488c7b=secret".to_owned()),
+                ("Authorization".to_owned(), "Bearer secret".to_owned()),
+            ],
+            body: testing::ok(&testing::entry(53)).into_bytes(),
+        }));
+        let proxer = inner.builder().transport(Recorder::new(&file.0, inner.clone())).build().unwrap();
+        proxer.info().get_entry(53).unwrap();
+
+        let saved = fs::read_to_string(&file.0).unwrap();
+        assert!(!saved.contains("secret") && !saved.contains("joomla"), "{}", saved);
+        assert_eq!(
+            Cassette::load(&file.0).unwrap().interactions[0].headers,
+            vec![("Content-Type".to_owned(), "application/json".to_owned())]
+        );
+    }
+
+    /// Damit der `Replayer` nach dem Abspielen noch geprüft werden kann.
+    #[cfg(feature = "fake-server")]
+    impl Transport for Arc<Replayer> {
+        fn send(&self, request: &Request) -> Result<Response> {
+            (**self).send(request)
+        }
+    }
+
+    #[cfg(feature = "fake-server")]
+    fn proxer<T: Transport + 'static>(transport: T) -> Proxer {
+        Proxer::builder("test-key").disclaimer(|_| ()).transport(transport).build().unwrap()
+    }
+
+    #[cfg(feature = "fake-server")]
+    #[test]
+    fn records_and_replays() {
+        let file = TempFile::new("round-trip");
+        let recorder = Recorder::new(&file.0, FakeApi::with_sample_data("test-key"));
+        let recording = proxer(recorder);
+        let user = recording.login("genesis", "passwort", None).unwrap();
+        let token = user.token.clone();
+        assert_eq!(recording.info().get_entry(53).unwrap().id, 53);
+        assert!(recording.info().get_entry(1).is_err());
+        user.logout().unwrap();
+
+        let saved = fs::read_to_string(&file.0).unwrap();
+        assert!(!saved.contains("passwort"), "{}", saved);
+        assert!(!saved.contains(&token), "{}", saved);
+        let cassette = Cassette::load(&file.0).unwrap();
+        let functions: Vec<_> = cassette
+            .interactions
+            .iter()
+            .map(|interaction| format!("{}/{}", interaction.api_class, interaction.api_function))
+            .collect();
+        assert_eq!(functions, ["user/login", "info/entry", "info/entry", "user/logout"]);
+
+        let replayer = Arc::new(Replayer::new(cassette));
+        let replaying = proxer(replayer.clone());
+        let user = replaying.login("genesis", "passwort", None).unwrap();
+        assert_eq!((user.uid, user.token.as_str()), (1, "***"));
+        assert_eq!(replaying.info().get_entry(53).unwrap().id, 53);
+        assert_eq!(replaying.info().get_entry(53).unwrap().id, 53);
+        match replaying.info().get_entry(1) {
+            Err(Error::ProxerError(ProxerErrorCode::InfoInvalidId, _)) => {}
+            other => panic!("unexpected result: {:?}", other),
+        }
+        match replaying.info().get_entry(7834) {
+            Err(Error::NotRecorded {
+                ref api_class,
+                ref api_function,
+                ref params,
+            }) => {
+                assert_eq!((api_class.as_str(), api_function.as_str(), params.as_str()), ("info", "entry", "id=7834"))
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+        let unused: Vec<_> = replayer.unused().iter().map(|interaction| interaction.api_function.clone()).collect();
+        assert_eq!(unused, ["logout"]);
+        user.logout().unwrap();
+        assert!(replayer.unused().is_empty());
+    }
+
+    #[test]
+    fn replays_in_recorded_order_and_repeats_the_last() {
+        let interaction = |body: &str| Interaction {
+            api_class: "notifications".to_owned(),
+            api_function: "count".to_owned(),
+            params: String::new(),
+            status: 200,
+            headers: Vec::new(),
+            body: testing::ok(body),
+        };
+        let replayer = Replayer::new(Cassette {
+            interactions: vec![interaction(r#""1""#), interaction(r#""2""#)],
+        });
+        let request = Request {
+            url: "https://proxer.me/api/v1/notifications/count".to_owned(),
+            headers: Vec::new(),
+            body: String::new(),
+        };
+        let bodies: Vec<_> = (0..3)
+            .map(|_| String::from_utf8(replayer.send(&request).unwrap().body).unwrap())
+            .collect();
+
+        assert_eq!(bodies, [testing::ok(r#""1""#), testing::ok(r#""2""#), testing::ok(r#""2""#)]);
+        assert!(replayer.unused().is_empty());
+    }
+}
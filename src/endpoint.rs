@@ -0,0 +1,106 @@
+//! Typisierte Beschreibung der API-Funktionen.
+//!
+//! Jede Funktion der Proxer-API ist als Struct modelliert, das `Endpoint` implementiert
+//! (z.B. `info::GetFullEntry`). Über `Proxer::execute` wird sie gesendet und die Antwort
+//! in den passenden `Output` umgewandelt. Die Methoden von `Info`, `List` usw. sind Kurzformen dafür.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use proxer::Proxer;
+//! use proxer::info::GetEntry;
+//!
+//! let proxer = Proxer::new("api-key").unwrap();
+//! let entry = proxer.execute(&GetEntry { id: 53 }).unwrap();
+//! println!("{}", entry.name);
+//! ```
+
+use serde::de::DeserializeOwned;
+use serde_json::{self, Value};
+
+use error::*;
+use models::{EmptyResponse, Response};
+use params::Params;
+
+/// Eine Funktion der Proxer-API.
+pub trait Endpoint {
+    /// Der Typ der Daten, die bei Erfolg zurückgegeben werden.
+    type Output: DeserializeOwned;
+
+    /// Die API-Klasse, z.B. "info".
+    fn api_class(&self) -> &'static str;
+
+    /// Die API-Funktion, z.B. "fullentry".
+    fn api_function(&self) -> &'static str;
+
+    /// Die Parameter der Anfrage.
+    fn params(&self) -> Params;
+
+    /// Ob die Funktion Daten auf dem Server verändert.
+    /// Solche Anfragen werden weder zwischengespeichert noch ohne Weiteres wiederholt.
+    fn mutates(&self) -> bool {
+        false
+    }
+
+    /// Ob für die Funktion ein eingeloggter User benötigt wird.
+    fn needs_login(&self) -> bool {
+        false
+    }
+
+    /// Wandelt die Antwort des Servers in den `Output` um.
+    fn parse(&self, response: &[u8]) -> Result<Self::Output> {
+        parse_response(response)
+    }
+}
+
+/// Liest eine Antwort der Form `{"error": .., "message": .., "code": .., "data": ..}`.
+///
+/// Fehlt `data`, gelingt dies nur für Typen, die aus `null` gelesen werden können (z.B. `()`).
+pub fn parse_response<T: DeserializeOwned>(response: &[u8]) -> Result<T> {
+    let data: Response<T> = serde_json::from_slice(response)?;
+    check_error!(data.error, data.code.unwrap_or_default(), data.message);
+    match data.data {
+        Some(data) => Ok(data),
+        None => serde_json::from_value(Value::Null).or_else(|_| check_data!(None)),
+    }
+}
+
+/// Liest eine Antwort wie `parse_response`, wertet `data` aber nicht aus.
+///
+/// Für Funktionen ohne Rückgabewert, bei denen der Server in `data` z.B. eine ID oder `{}` schickt.
+pub fn parse_empty_response(response: &[u8]) -> Result<()> {
+    let data: EmptyResponse = serde_json::from_slice(response)?;
+    check_error!(data.error, data.code.unwrap_or_default(), data.message);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use messenger::SetRead;
+    use user::LogoutUser;
+
+    #[test]
+    fn empty_output_ignores_data() {
+        let responses: [&[u8]; 4] = [
+            br#"{"error":0,"message":"ok","data":"1"}"#,
+            br#"{"error":0,"message":"ok","data":{}}"#,
+            br#"{"error":0,"message":"ok","data":42}"#,
+            br#"{"error":0,"message":"ok"}"#,
+        ];
+        for response in &responses {
+            assert_eq!(LogoutUser {}.parse(response).unwrap(), ());
+            assert_eq!(SetRead { conference_id: 1 }.parse(response).unwrap(), ());
+        }
+    }
+
+    #[test]
+    fn empty_output_reports_errors() {
+        let response = br#"{"error":1,"message":"Messages: Ungueltige Konferenz","code":3024,"data":"1"}"#;
+        let endpoint = SetRead { conference_id: 1 };
+        match endpoint.parse(response) {
+            Err(Error::ProxerError(ProxerErrorCode::MessengerInvalidConference, _)) => {}
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+}
@@ -1,15 +1,77 @@
+use std::error::Error as StdError;
+use std::fmt;
 use std::io::Error as IoError;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
 use hyper::error::Error as HyperError;
 use serde_json::error as serde;
+use hyper_native_tls::native_tls::Error as TlsError;
 
 pub type Result<T> = ::std::result::Result<T, Error>;
 
+/// Die Sprache, in der Fehlermeldungen über `Display` ausgegeben werden.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum MessageLanguage {
+    #[default]
+    German,
+    English,
+}
+
+static MESSAGE_LANGUAGE: AtomicUsize = AtomicUsize::new(0);
+
+/// Setzt die Sprache der Fehlermeldungen für den gesamten Prozess. Default: `German`.
+pub fn set_message_language(language: MessageLanguage) {
+    let value = match language {
+        MessageLanguage::German => 0,
+        MessageLanguage::English => 1,
+    };
+    MESSAGE_LANGUAGE.store(value, Ordering::Relaxed);
+}
+
+/// Die aktuell eingestellte Sprache der Fehlermeldungen.
+pub fn message_language() -> MessageLanguage {
+    match MESSAGE_LANGUAGE.load(Ordering::Relaxed) {
+        1 => MessageLanguage::English,
+        _ => MessageLanguage::German,
+    }
+}
+
 #[derive(Debug)]
 pub enum Error {
     Hyper(HyperError),
     SerdeError(serde::Error),
     Io(IoError),
-    ProxerError(u16, String),
+    Tls(TlsError),
+    ProxerError(ProxerErrorCode, String),
+    /// Das Login-Token der Sitzung ist nicht mehr gültig, der User muss sich erneut einloggen.
+    SessionExpired,
+    /// Für den Account ist die Zwei-Faktor-Authentifizierung aktiviert,
+    /// der Login muss mit dem Einmal-Code wiederholt werden.
+    TwoFactorRequired,
+    /// Für die Anfrage muss ein User eingeloggt sein.
+    LoginRequired,
+    /// Das Login-Token wurde abgelehnt und der automatische erneute Login ist fehlgeschlagen.
+    ReloginFailed(Box<Error>),
+    /// Statt einer API-Antwort wurde eine Firewall- bzw. Cloudflare-Challenge-Seite geliefert
+    /// (entspricht dem Fehler 2000). `snippet` enthält den gekürzten Anfang der Seite.
+    Firewall { status: u16, snippet: String },
+    /// Statt einer API-Antwort wurde eine Wartungsseite geliefert.
+    Maintenance { status: u16, snippet: String },
+    /// Der Server hat die Anfrage wegen zu vieler Anfragen abgelehnt (HTTP 429).
+    /// `retry_after` ist die vom Server vorgegebene Wartezeit in Sekunden.
+    RateLimited { retry_after: Option<u64>, snippet: String },
+    /// Der Server hat mit einem HTTP-Statuscode 5xx ohne API-Antwort geantwortet.
+    ServerError { status: u16, snippet: String },
+    /// Die Antwort ist kein JSON und konnte keinem anderen Fehler zugeordnet werden.
+    InvalidResponse { status: u16, content_type: Option<String>, snippet: String },
+    /// Das Anfrage-Budget der API-Klasse ist aufgebraucht (siehe `ratelimit::RateLimiter`).
+    /// Frühestens nach `retry_after` ist wieder eine Anfrage möglich.
+    BudgetExceeded { api_class: String, retry_after: Duration },
+    /// Im Offline-Modus des `cache::ResponseCache` ist keine Antwort für die Anfrage gespeichert.
+    NotCached { api_class: String, api_function: String },
+    /// Eine `cassette::Replayer` enthält keine Aufzeichnung für die Anfrage.
+    /// `params` sind die kodierten, geschwärzten Parameter der Anfrage.
+    NotRecorded { api_class: String, api_function: String, params: String },
     Other(String),
 }
 
@@ -34,13 +96,21 @@ impl From<IoError> for Error {
     }
 }
 
+impl From<TlsError> for Error {
+    fn from(err: TlsError) -> Error {
+        error!("TlsError with: {:?}", err);
+        Error::Tls(err)
+    }
+}
+
 impl From<(u16, String)> for Error {
     fn from((code, message): (u16, String)) -> Error {
+        let code = ProxerErrorCode::from(code);
         error!(
             "ProxerError with: message:{:?}, code:{}({:?})",
             message,
-            code,
-            DecodeErrorCode::decode(code)
+            code.code(),
+            code.description()
         );
         Error::ProxerError(code, message)
     }
@@ -53,63 +123,598 @@ impl From<String> for Error {
     }
 }
 
-pub struct DecodeErrorCode;
-
-impl DecodeErrorCode {
-    pub fn decode(code: u16) -> String {
-        match code
-        {
-            1000 =>	format!("API-Version existiert nicht."),
-            1001 =>	format!("API-Version wurde entfernt."),
-            1002 =>	format!("API-Klasse existiert nicht."),
-            1003 =>	format!("API-Funktion existiert nicht."),
-            1004 =>	format!("Der API-Schlüssel besitzt nicht ausreichend Rechte um diese Aktion durchzuführen."),
-            1005 =>	format!("Es wurde ein ungültiges Login-Token verwendet."),
-            1006 =>	format!("Die aufgerufene Funktion wurde gesperrt."),
-
-            2000 =>	format!("IP von Firewall geblockt."),
-            2001 =>	format!("News: Fehler bei der Abfrage der News."),
-
-            3000 =>	format!("Login: Fehlende Login-Daten."),
-            3001 =>	format!("Login: Ungültige Login-Daten."),
-            3002 =>	format!("Notifications: User nicht eingeloggt."),
-            3003 =>	format!("Userinfo: Userid existiert nicht."),
-            3004 =>	format!("Ucp: User nicht eingeloggt."),
-            3005 =>	format!("Ucp: Kategorie existiert nicht."),
-            3006 =>	format!("Ucp: Ungültige ID."),
-            3007 =>	format!("Info: Ungültige ID."),
-            3008 =>	format!("Info: setUserInfo: Ungültiger Typ."),
-            3009 =>	format!("Info: setUserInfo: User nicht eingeloggt."),
-            3010 =>	format!("Info: setUserInfo: Werk bereits in Liste enthalten."),
-            3011 =>	format!("Info: setUserInfo: Anzahl zulässiger Favoriten überschritten."),
-            3012 =>	format!("Login: Der User ist bereits eingeloggt."),
-            3013 =>	format!("Login: Ein anderer User ist bereits eingeloggt."),
-            3014 =>	format!("User: Der Zugriff auf die gesuchte Information wurde verweigert (möglicherweise sollte ein User eingeloggt werden)."),
-            3015 =>	format!("List: Kategorie existiert nicht."),
-            3016 =>	format!("List: Medium existiert nicht."),
-            3017 =>	format!("Media: Stil existiert nicht."),
-            3018 =>	format!("Media: Eintrag existiert nicht."),
-            3019 =>	format!("Manga: Kapitel existiert nicht (nicht hochgeladen)."),
-            3020 =>	format!("Anime: Episode existiert nicht (keine Streams)."),
-            3021 =>	format!("Anime: Stream existiert nicht."),
-            3022 =>	format!("Ucp: Episode existiert nicht."),
-            3023 =>	format!("Messages: Der User ist nicht eingeloggt."),
-            3024 =>	format!("Messages: Ungültige Konferenz (fehlende Berechtigung oder fehlerhafte Konferenz-ID)."),
-            3025 =>	format!("Messages: Ungültige/Fehlende Eingabe bei Meldegrund."),
-            3026 =>	format!("Messages: Ungültige/Fehlende Nachricht."),
-            3027 =>	format!("Messages: Ungültiger Benutzer."),
-            3028 =>	format!("Messages: Die maximale Anzahl an Usern wurde erreicht."),
-            3029 =>	format!("Messages: Ungültiges/Fehlendes Thema."),
-            3030 =>	format!("Messages: Es muss mindestens ein Benutzer in einer Konferenz hinzugefügt werden."),
-            3031 =>	format!("Chat: Ungültiger Raum."),
-            3032 =>	format!("Chat: Keine Berechtigungen."),
-            3033 =>	format!("Chat: Ungültige Nachricht."),
-            3034 =>	format!("Chat: Nicht eingeloggt."),
-            3035 =>	format!("List: Ungültige Sprache."),
-            3036 =>	format!("List: Ungültiger Typ."),
-            3037 =>	format!("List: Ungültige ID."),
-
-            _ => format!("Unknown Code")
+impl Error {
+    /// Schreibt die Fehlermeldung in der Sprache `language`.
+    fn fmt_in(&self, f: &mut fmt::Formatter, language: MessageLanguage) -> fmt::Result {
+        let german = language == MessageLanguage::German;
+        match *self {
+            Error::Hyper(ref err) if german => write!(f, "HTTP-Fehler: {}", err),
+            Error::Hyper(ref err) => write!(f, "HTTP error: {}", err),
+            Error::SerdeError(ref err) if german => write!(f, "Antwort konnte nicht gelesen werden: {}", err),
+            Error::SerdeError(ref err) => write!(f, "Could not parse the response: {}", err),
+            Error::Io(ref err) if german => write!(f, "E/A-Fehler: {}", err),
+            Error::Io(ref err) => write!(f, "I/O error: {}", err),
+            Error::Tls(ref err) if german => write!(f, "TLS-Fehler: {}", err),
+            Error::Tls(ref err) => write!(f, "TLS error: {}", err),
+            Error::ProxerError(code, ref message) => {
+                if german {
+                    write!(f, "Proxer-API-Fehler {}: {}", code.code(), code.description_in(language))?;
+                } else {
+                    write!(f, "Proxer API error {}: {}", code.code(), code.description_in(language))?;
+                }
+                if !message.is_empty() {
+                    write!(f, " ({})", message)?;
+                }
+                Ok(())
+            }
+            Error::SessionExpired if german => {
+                write!(f, "Die Sitzung ist abgelaufen, der User muss sich erneut einloggen.")
+            }
+            Error::SessionExpired => write!(f, "The session has expired, the user has to log in again."),
+            Error::TwoFactorRequired if german => {
+                write!(f, "Der Login erfordert den Einmal-Code der Zwei-Faktor-Authentifizierung.")
+            }
+            Error::TwoFactorRequired => write!(f, "The login requires the two-factor authentication code."),
+            Error::LoginRequired if german => write!(f, "Für diese Anfrage muss ein User eingeloggt sein."),
+            Error::LoginRequired => write!(f, "This request requires a logged in user."),
+            Error::ReloginFailed(ref err) if german => {
+                write!(f, "Der automatische erneute Login ist fehlgeschlagen: ")?;
+                err.fmt_in(f, language)
+            }
+            Error::ReloginFailed(ref err) => {
+                write!(f, "The automatic re-login failed: ")?;
+                err.fmt_in(f, language)
+            }
+            Error::Firewall { status, ref snippet } if german => {
+                write!(f, "Die Anfrage wurde von der Firewall blockiert (HTTP {}): {}", status, snippet)
+            }
+            Error::Firewall { status, ref snippet } => {
+                write!(f, "The request was blocked by the firewall (HTTP {}): {}", status, snippet)
+            }
+            Error::Maintenance { status, ref snippet } if german => {
+                write!(f, "Proxer befindet sich im Wartungsmodus (HTTP {}): {}", status, snippet)
+            }
+            Error::Maintenance { status, ref snippet } => {
+                write!(f, "Proxer is in maintenance mode (HTTP {}): {}", status, snippet)
+            }
+            Error::RateLimited { retry_after: Some(seconds), .. } if german => {
+                write!(f, "Zu viele Anfragen, erneuter Versuch in {} Sekunden möglich.", seconds)
+            }
+            Error::RateLimited { retry_after: Some(seconds), .. } => {
+                write!(f, "Too many requests, retry in {} seconds.", seconds)
+            }
+            Error::RateLimited { retry_after: None, .. } if german => write!(f, "Zu viele Anfragen."),
+            Error::RateLimited { retry_after: None, .. } => write!(f, "Too many requests."),
+            Error::ServerError { status, ref snippet } if german => {
+                write!(f, "Serverfehler (HTTP {}): {}", status, snippet)
+            }
+            Error::ServerError { status, ref snippet } => write!(f, "Server error (HTTP {}): {}", status, snippet),
+            Error::InvalidResponse { status, ref snippet, .. } if german => {
+                write!(f, "Unerwartete Antwort (HTTP {}): {}", status, snippet)
+            }
+            Error::InvalidResponse { status, ref snippet, .. } => {
+                write!(f, "Unexpected response (HTTP {}): {}", status, snippet)
+            }
+            Error::BudgetExceeded { ref api_class, retry_after } if german => write!(
+                f,
+                "Das Anfrage-Budget für {:?} ist aufgebraucht, nächste Anfrage in {:?} möglich.",
+                api_class, retry_after
+            ),
+            Error::BudgetExceeded { ref api_class, retry_after } => write!(
+                f,
+                "The request budget for {:?} is exhausted, next request possible in {:?}.",
+                api_class, retry_after
+            ),
+            Error::NotCached { ref api_class, ref api_function } if german => write!(
+                f,
+                "Offline-Modus: Für {}/{} ist keine Antwort gespeichert.",
+                api_class, api_function
+            ),
+            Error::NotCached { ref api_class, ref api_function } => write!(
+                f,
+                "Offline mode: No response is stored for {}/{}.",
+                api_class, api_function
+            ),
+            Error::NotRecorded { ref api_class, ref api_function, ref params } if german => write!(
+                f,
+                "Für {}/{} mit den Parametern {:?} ist keine Antwort aufgezeichnet.",
+                api_class, api_function, params
+            ),
+            Error::NotRecorded { ref api_class, ref api_function, ref params } => write!(
+                f,
+                "No response is recorded for {}/{} with parameters {:?}.",
+                api_class, api_function, params
+            ),
+            Error::Other(ref message) => f.write_str(message),
+        }
+    }
+
+    /// Der Fehlercode der Proxer-API, falls es sich um einen solchen Fehler handelt.
+    pub fn proxer_code(&self) -> Option<ProxerErrorCode> {
+        match *self {
+            Error::ProxerError(code, _) => Some(code),
+            Error::SessionExpired => Some(ProxerErrorCode::InvalidLoginToken),
+            Error::TwoFactorRequired => Some(ProxerErrorCode::LoginTwoFactorRequired),
+            Error::Firewall { .. } => Some(ProxerErrorCode::IpBlocked),
+            Error::Maintenance { .. } => Some(ProxerErrorCode::ServerMaintenance),
+            _ => None,
+        }
+    }
+}
+
+/// Die Meldung wird in der mit `set_message_language` eingestellten Sprache geschrieben.
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_in(f, message_language())
+    }
+}
+
+impl StdError for Error {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match *self {
+            Error::Hyper(ref err) => Some(err),
+            Error::SerdeError(ref err) => Some(err),
+            Error::Io(ref err) => Some(err),
+            Error::Tls(ref err) => Some(err),
+            Error::ReloginFailed(ref err) => Some(&**err),
+            _ => None,
+        }
+    }
+}
+
+macro_rules! error_codes
+{
+    ( $( $code:expr => $name:ident { de: $de:expr, en: $en:expr, }, )* ) =>
+    (
+        /// Die Fehlercodes der Proxer-API, gruppiert nach API-Klasse.
+        ///
+        /// Unbekannte Codes werden als `Unknown` mit dem Originalwert abgebildet.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum ProxerErrorCode {
+            $(
+                #[doc = $de]
+                $name,
+            )*
+            /// Ein Fehlercode, der (noch) nicht bekannt ist.
+            Unknown(u16),
+        }
+
+        impl ProxerErrorCode {
+            /// Der numerische Fehlercode.
+            pub fn code(&self) -> u16 {
+                match *self {
+                    $( ProxerErrorCode::$name => $code, )*
+                    ProxerErrorCode::Unknown(code) => code,
+                }
+            }
+
+            /// Die Beschreibung des Fehlers aus dem Proxer-API wiki in der eingestellten Sprache.
+            pub fn description(&self) -> &'static str {
+                self.description_in(message_language())
+            }
+
+            /// Die Beschreibung des Fehlers in der Sprache `language`.
+            pub fn description_in(&self, language: MessageLanguage) -> &'static str {
+                match (*self, language) {
+                    $(
+                        (ProxerErrorCode::$name, MessageLanguage::German) => $de,
+                        (ProxerErrorCode::$name, MessageLanguage::English) => $en,
+                    )*
+                    (ProxerErrorCode::Unknown(_), MessageLanguage::German) => "Unbekannter Fehlercode.",
+                    (ProxerErrorCode::Unknown(_), MessageLanguage::English) => "Unknown error code.",
+                }
+            }
+        }
+
+        impl From<u16> for ProxerErrorCode {
+            fn from(code: u16) -> ProxerErrorCode {
+                match code {
+                    $( $code => ProxerErrorCode::$name, )*
+                    code => ProxerErrorCode::Unknown(code),
+                }
+            }
+        }
+    );
+}
+
+error_codes! {
+    // Allgemein
+    1000 => ApiVersionNotFound {
+        de: "API-Version existiert nicht.",
+        en: "API version does not exist.",
+    },
+    1001 => ApiVersionRemoved {
+        de: "API-Version wurde entfernt.",
+        en: "API version has been removed.",
+    },
+    1002 => ApiClassNotFound {
+        de: "API-Klasse existiert nicht.",
+        en: "API class does not exist.",
+    },
+    1003 => ApiFunctionNotFound {
+        de: "API-Funktion existiert nicht.",
+        en: "API function does not exist.",
+    },
+    1004 => InsufficientPermissions {
+        de: "Der API-Schlüssel besitzt nicht ausreichend Rechte um diese Aktion durchzuführen.",
+        en: "The API key lacks the permissions to perform this action.",
+    },
+    1005 => InvalidLoginToken {
+        de: "Es wurde ein ungültiges Login-Token verwendet.",
+        en: "An invalid login token was used.",
+    },
+    1006 => FunctionBlocked {
+        de: "Die aufgerufene Funktion wurde gesperrt.",
+        en: "The called function has been blocked.",
+    },
+    1007 => ServerMaintenance {
+        de: "Der Server befindet sich im Wartungsmodus.",
+        en: "The server is in maintenance mode.",
+    },
+    1008 => ApiMaintenance {
+        de: "Die API befindet sich im Wartungsmodus.",
+        en: "The API is in maintenance mode.",
+    },
+
+    2000 => IpBlocked {
+        de: "IP von Firewall geblockt.",
+        en: "IP blocked by the firewall.",
+    },
+    2001 => NewsError {
+        de: "News: Fehler bei der Abfrage der News.",
+        en: "News: Error while fetching the news.",
+    },
+
+    // Login
+    3000 => LoginMissingCredentials {
+        de: "Login: Fehlende Login-Daten.",
+        en: "Login: Missing login data.",
+    },
+    3001 => LoginInvalidCredentials {
+        de: "Login: Ungültige Login-Daten.",
+        en: "Login: Invalid login data.",
+    },
+    3012 => LoginAlreadyLoggedIn {
+        de: "Login: Der User ist bereits eingeloggt.",
+        en: "Login: The user is already logged in.",
+    },
+    3013 => LoginOtherUserLoggedIn {
+        de: "Login: Ein anderer User ist bereits eingeloggt.",
+        en: "Login: Another user is already logged in.",
+    },
+    3038 => LoginTwoFactorRequired {
+        de: "Login: Zwei-Faktor-Authentifizierung ist aktiviert, es wurde jedoch kein Secret-Key übergeben.",
+        en: "Login: Two-factor authentication is enabled, but no secret key was given.",
+    },
+
+    // Notifications
+    3002 => NotificationsNotLoggedIn {
+        de: "Notifications: User nicht eingeloggt.",
+        en: "Notifications: User not logged in.",
+    },
+
+    // User
+    3003 => UserNotFound {
+        de: "Userinfo: Userid existiert nicht.",
+        en: "Userinfo: User id does not exist.",
+    },
+    3014 => UserAccessDenied {
+        de: "User: Der Zugriff auf die gesuchte Information wurde verweigert (möglicherweise sollte ein User eingeloggt werden).",
+        en: "User: Access to the requested information was denied (a user may have to be logged in).",
+    },
+
+    // Ucp
+    3004 => UcpNotLoggedIn {
+        de: "Ucp: User nicht eingeloggt.",
+        en: "Ucp: User not logged in.",
+    },
+    3005 => UcpInvalidCategory {
+        de: "Ucp: Kategorie existiert nicht.",
+        en: "Ucp: Category does not exist.",
+    },
+    3006 => UcpInvalidId {
+        de: "Ucp: Ungültige ID.",
+        en: "Ucp: Invalid id.",
+    },
+    3022 => UcpEpisodeNotFound {
+        de: "Ucp: Episode existiert nicht.",
+        en: "Ucp: Episode does not exist.",
+    },
+
+    // Info
+    3007 => InfoInvalidId {
+        de: "Info: Ungültige ID.",
+        en: "Info: Invalid id.",
+    },
+    3008 => InfoInvalidType {
+        de: "Info: setUserInfo: Ungültiger Typ.",
+        en: "Info: setUserInfo: Invalid type.",
+    },
+    3009 => InfoNotLoggedIn {
+        de: "Info: setUserInfo: User nicht eingeloggt.",
+        en: "Info: setUserInfo: User not logged in.",
+    },
+    3010 => InfoEntryAlreadyInList {
+        de: "Info: setUserInfo: Werk bereits in Liste enthalten.",
+        en: "Info: setUserInfo: Entry is already in the list.",
+    },
+    3011 => InfoFavoritesExceeded {
+        de: "Info: setUserInfo: Anzahl zulässiger Favoriten überschritten.",
+        en: "Info: setUserInfo: Number of allowed favorites exceeded.",
+    },
+
+    // List
+    3015 => ListInvalidCategory {
+        de: "List: Kategorie existiert nicht.",
+        en: "List: Category does not exist.",
+    },
+    3016 => ListInvalidMedium {
+        de: "List: Medium existiert nicht.",
+        en: "List: Medium does not exist.",
+    },
+    3035 => ListInvalidLanguage {
+        de: "List: Ungültige Sprache.",
+        en: "List: Invalid language.",
+    },
+    3036 => ListInvalidType {
+        de: "List: Ungültiger Typ.",
+        en: "List: Invalid type.",
+    },
+    3037 => ListInvalidId {
+        de: "List: Ungültige ID.",
+        en: "List: Invalid id.",
+    },
+
+    // Media
+    3017 => MediaInvalidStyle {
+        de: "Media: Stil existiert nicht.",
+        en: "Media: Style does not exist.",
+    },
+    3018 => MediaEntryNotFound {
+        de: "Media: Eintrag existiert nicht.",
+        en: "Media: Entry does not exist.",
+    },
+
+    // Manga
+    3019 => MangaChapterNotFound {
+        de: "Manga: Kapitel existiert nicht (nicht hochgeladen).",
+        en: "Manga: Chapter does not exist (not uploaded).",
+    },
+
+    // Anime
+    3020 => AnimeEpisodeNotFound {
+        de: "Anime: Episode existiert nicht (keine Streams).",
+        en: "Anime: Episode does not exist (no streams).",
+    },
+    3021 => AnimeStreamNotFound {
+        de: "Anime: Stream existiert nicht.",
+        en: "Anime: Stream does not exist.",
+    },
+
+    // Messenger
+    3023 => MessengerNotLoggedIn {
+        de: "Messages: Der User ist nicht eingeloggt.",
+        en: "Messages: The user is not logged in.",
+    },
+    3024 => MessengerInvalidConference {
+        de: "Messages: Ungültige Konferenz (fehlende Berechtigung oder fehlerhafte Konferenz-ID).",
+        en: "Messages: Invalid conference (missing permission or wrong conference id).",
+    },
+    3025 => MessengerInvalidReportReason {
+        de: "Messages: Ungültige/Fehlende Eingabe bei Meldegrund.",
+        en: "Messages: Invalid/missing report reason.",
+    },
+    3026 => MessengerInvalidMessage {
+        de: "Messages: Ungültige/Fehlende Nachricht.",
+        en: "Messages: Invalid/missing message.",
+    },
+    3027 => MessengerInvalidUser {
+        de: "Messages: Ungültiger Benutzer.",
+        en: "Messages: Invalid user.",
+    },
+    3028 => MessengerUserLimitReached {
+        de: "Messages: Die maximale Anzahl an Usern wurde erreicht.",
+        en: "Messages: The maximum number of users has been reached.",
+    },
+    3029 => MessengerInvalidTopic {
+        de: "Messages: Ungültiges/Fehlendes Thema.",
+        en: "Messages: Invalid/missing topic.",
+    },
+    3030 => MessengerMissingUser {
+        de: "Messages: Es muss mindestens ein Benutzer in einer Konferenz hinzugefügt werden.",
+        en: "Messages: At least one user has to be added to a conference.",
+    },
+
+    // Chat
+    3031 => ChatInvalidRoom {
+        de: "Chat: Ungültiger Raum.",
+        en: "Chat: Invalid room.",
+    },
+    3032 => ChatNoPermission {
+        de: "Chat: Keine Berechtigungen.",
+        en: "Chat: No permissions.",
+    },
+    3033 => ChatInvalidMessage {
+        de: "Chat: Ungültige Nachricht.",
+        en: "Chat: Invalid message.",
+    },
+    3034 => ChatNotLoggedIn {
+        de: "Chat: Nicht eingeloggt.",
+        en: "Chat: Not logged in.",
+    },
+}
+
+impl fmt::Display for ProxerErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.code(), self.description())
+    }
+}
+
+impl ProxerErrorCode {
+    /// Der User ist nicht eingeloggt oder das Login-Token ist ungültig.
+    pub fn is_auth_error(&self) -> bool {
+        matches!(
+            *self,
+            ProxerErrorCode::InvalidLoginToken
+            | ProxerErrorCode::NotificationsNotLoggedIn
+            | ProxerErrorCode::UcpNotLoggedIn
+            | ProxerErrorCode::InfoNotLoggedIn
+            | ProxerErrorCode::MessengerNotLoggedIn
+            | ProxerErrorCode::ChatNotLoggedIn
+        )
+    }
+
+    /// Die angefragte ID, Episode, Konferenz oder Funktion existiert nicht.
+    pub fn is_not_found(&self) -> bool {
+        matches!(
+            *self,
+            ProxerErrorCode::ApiVersionNotFound
+            | ProxerErrorCode::ApiClassNotFound
+            | ProxerErrorCode::ApiFunctionNotFound
+            | ProxerErrorCode::UserNotFound
+            | ProxerErrorCode::UcpInvalidId
+            | ProxerErrorCode::UcpEpisodeNotFound
+            | ProxerErrorCode::InfoInvalidId
+            | ProxerErrorCode::ListInvalidId
+            | ProxerErrorCode::MediaEntryNotFound
+            | ProxerErrorCode::MangaChapterNotFound
+            | ProxerErrorCode::AnimeEpisodeNotFound
+            | ProxerErrorCode::AnimeStreamNotFound
+            | ProxerErrorCode::MessengerInvalidConference
+            | ProxerErrorCode::ChatInvalidRoom
+        )
+    }
+
+    /// Dem API-Schlüssel oder dem User fehlen die Rechte für diese Aktion.
+    pub fn is_permission_error(&self) -> bool {
+        matches!(
+            *self,
+            ProxerErrorCode::InsufficientPermissions
+            | ProxerErrorCode::FunctionBlocked
+            | ProxerErrorCode::UserAccessDenied
+            | ProxerErrorCode::ChatNoPermission
+        )
+    }
+
+    /// Der Fehler ist voraussichtlich vorübergehend, die Anfrage kann später wiederholt werden.
+    ///
+    /// Eine IP-Sperre (`IpBlocked`) zählt nicht dazu: Sie endet nicht innerhalb weniger Sekunden,
+    /// weitere Anfragen würden sie nur verlängern.
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            *self,
+            ProxerErrorCode::ServerMaintenance
+            | ProxerErrorCode::ApiMaintenance
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Alle bekannten Fehlercodes mit ihren Eigenschaften:
+    /// `a` = `is_auth_error`, `n` = `is_not_found`, `p` = `is_permission_error`, `r` = `is_retryable`.
+    fn table() -> Vec<(u16, ProxerErrorCode, &'static str)> {
+        vec![
+            (1000, ProxerErrorCode::ApiVersionNotFound, "n"),
+            (1001, ProxerErrorCode::ApiVersionRemoved, ""),
+            (1002, ProxerErrorCode::ApiClassNotFound, "n"),
+            (1003, ProxerErrorCode::ApiFunctionNotFound, "n"),
+            (1004, ProxerErrorCode::InsufficientPermissions, "p"),
+            (1005, ProxerErrorCode::InvalidLoginToken, "a"),
+            (1006, ProxerErrorCode::FunctionBlocked, "p"),
+            (1007, ProxerErrorCode::ServerMaintenance, "r"),
+            (1008, ProxerErrorCode::ApiMaintenance, "r"),
+            (2000, ProxerErrorCode::IpBlocked, ""),
+            (2001, ProxerErrorCode::NewsError, ""),
+            (3000, ProxerErrorCode::LoginMissingCredentials, ""),
+            (3001, ProxerErrorCode::LoginInvalidCredentials, ""),
+            (3012, ProxerErrorCode::LoginAlreadyLoggedIn, ""),
+            (3013, ProxerErrorCode::LoginOtherUserLoggedIn, ""),
+            (3038, ProxerErrorCode::LoginTwoFactorRequired, ""),
+            (3002, ProxerErrorCode::NotificationsNotLoggedIn, "a"),
+            (3003, ProxerErrorCode::UserNotFound, "n"),
+            (3014, ProxerErrorCode::UserAccessDenied, "p"),
+            (3004, ProxerErrorCode::UcpNotLoggedIn, "a"),
+            (3005, ProxerErrorCode::UcpInvalidCategory, ""),
+            (3006, ProxerErrorCode::UcpInvalidId, "n"),
+            (3022, ProxerErrorCode::UcpEpisodeNotFound, "n"),
+            (3007, ProxerErrorCode::InfoInvalidId, "n"),
+            (3008, ProxerErrorCode::InfoInvalidType, ""),
+            (3009, ProxerErrorCode::InfoNotLoggedIn, "a"),
+            (3010, ProxerErrorCode::InfoEntryAlreadyInList, ""),
+            (3011, ProxerErrorCode::InfoFavoritesExceeded, ""),
+            (3015, ProxerErrorCode::ListInvalidCategory, ""),
+            (3016, ProxerErrorCode::ListInvalidMedium, ""),
+            (3035, ProxerErrorCode::ListInvalidLanguage, ""),
+            (3036, ProxerErrorCode::ListInvalidType, ""),
+            (3037, ProxerErrorCode::ListInvalidId, "n"),
+            (3017, ProxerErrorCode::MediaInvalidStyle, ""),
+            (3018, ProxerErrorCode::MediaEntryNotFound, "n"),
+            (3019, ProxerErrorCode::MangaChapterNotFound, "n"),
+            (3020, ProxerErrorCode::AnimeEpisodeNotFound, "n"),
+            (3021, ProxerErrorCode::AnimeStreamNotFound, "n"),
+            (3023, ProxerErrorCode::MessengerNotLoggedIn, "a"),
+            (3024, ProxerErrorCode::MessengerInvalidConference, "n"),
+            (3025, ProxerErrorCode::MessengerInvalidReportReason, ""),
+            (3026, ProxerErrorCode::MessengerInvalidMessage, ""),
+            (3027, ProxerErrorCode::MessengerInvalidUser, ""),
+            (3028, ProxerErrorCode::MessengerUserLimitReached, ""),
+            (3029, ProxerErrorCode::MessengerInvalidTopic, ""),
+            (3030, ProxerErrorCode::MessengerMissingUser, ""),
+            (3031, ProxerErrorCode::ChatInvalidRoom, "n"),
+            (3032, ProxerErrorCode::ChatNoPermission, "p"),
+            (3033, ProxerErrorCode::ChatInvalidMessage, ""),
+            (3034, ProxerErrorCode::ChatNotLoggedIn, "a"),
+        ]
+    }
+
+    #[test]
+    fn maps_codes_to_variants() {
+        for (code, variant, _) in table() {
+            assert_eq!(ProxerErrorCode::from(code), variant);
+            assert_eq!(variant.code(), code);
+            assert!(!variant.description_in(MessageLanguage::German).is_empty());
+            assert!(!variant.description_in(MessageLanguage::English).is_empty());
+        }
+        assert_eq!(ProxerErrorCode::from(4242), ProxerErrorCode::Unknown(4242));
+        assert_eq!(ProxerErrorCode::Unknown(4242).code(), 4242);
+    }
+
+    #[test]
+    fn predicates() {
+        for (code, variant, flags) in table() {
+            let actual: String = [
+                ('a', variant.is_auth_error()),
+                ('n', variant.is_not_found()),
+                ('p', variant.is_permission_error()),
+                ('r', variant.is_retryable()),
+            ]
+            .iter()
+            .filter(|&&(_, set)| set)
+            .map(|&(flag, _)| flag)
+            .collect();
+            assert_eq!(actual, flags, "{} ({:?})", code, variant);
+        }
+        let unknown = ProxerErrorCode::Unknown(4242);
+        assert!(!unknown.is_auth_error() && !unknown.is_not_found() && !unknown.is_retryable());
+    }
+
+    #[test]
+    fn ip_block_is_not_retryable() {
+        assert!(!ProxerErrorCode::IpBlocked.is_retryable());
+        assert!(!ProxerErrorCode::NewsError.is_retryable());
+        assert!(ProxerErrorCode::ServerMaintenance.is_retryable());
+    }
+
+    #[test]
+    fn converts_api_errors() {
+        match Error::from((3007, "Info: Ungueltige ID.".to_owned())) {
+            Error::ProxerError(ProxerErrorCode::InfoInvalidId, ref message) => {
+                assert_eq!(message, "Info: Ungueltige ID.")
+            }
+            other => panic!("unexpected error: {:?}", other),
         }
     }
 }
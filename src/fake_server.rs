@@ -0,0 +1,877 @@
+//! Ein nachgebauter Proxer-Server für Integrationstests (Feature `fake-server`).
+//!
+//! `FakeApi` hält ein kleines Datenmodell im Speicher (Entrys, User, UCP-Listen, Konferenzen,
+//! Nachrichten und News) und beantwortet Anfragen wie `https://proxer.me/api/v1/<class>/<function>`,
+//! einschließlich der passenden Fehlercodes (z.B. 3007 für unbekannte IDs oder 3023 ohne Login)
+//! und der Prüfung von API-Key und Login-Token.
+//!
+//! Die `FakeApi` kann direkt als `Transport` verwendet oder mit `FakeServer::start` auf einem
+//! lokalen Port bereitgestellt werden. Das Programm `proxer-fake-server` startet einen solchen Server
+//! mit den Beispieldaten aus `FakeApi::with_sample_data`.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use proxer::Proxer;
+//! use proxer::fake_server::{FakeApi, FakeServer};
+//!
+//! let server = FakeServer::start(FakeApi::with_sample_data("api-key"), "127.0.0.1:0").unwrap();
+//! let proxer = Proxer::builder("api-key").base_url(&server.base_url()).build().unwrap();
+//!
+//! let user = proxer.login("genesis", "passwort", None).unwrap();
+//! let ucp = proxer.ucp();
+//! let entry = ucp.get_list(None, None, None, None, None, None).unwrap().remove(0);
+//! ucp.set_commentstate(entry.cid, 0).unwrap();
+//! user.logout().unwrap();
+//! ```
+
+use std::collections::HashMap;
+use std::fmt;
+use std::io::Read;
+use std::net::{SocketAddr, ToSocketAddrs};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use hyper::header::ContentType;
+use hyper::server::{Listening, Request as HttpRequest, Response as HttpResponse, Server};
+use hyper::uri::RequestUri;
+use serde_json::{self, Value};
+
+use error::*;
+use params::Params;
+use transport::{Request, Response, Transport};
+use {API_KEY_HEADER, LOGIN_TOKEN_HEADER};
+
+/// Die Antwort einer API-Funktion: Daten oder ein Fehlercode.
+type Reply = ::std::result::Result<Value, ProxerErrorCode>;
+
+#[derive(Debug, Clone)]
+struct FakeEntry {
+    id: u64,
+    name: String,
+    medium: String,
+    count: u64,
+}
+
+impl FakeEntry {
+    fn kat(&self) -> &'static str {
+        match self.medium.as_str() {
+            "mangaseries" | "oneshot" | "doujin" | "hmanga" => "manga",
+            _ => "anime",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct FakeUser {
+    uid: u64,
+    username: String,
+    password: String,
+}
+
+#[derive(Debug, Clone)]
+struct FakeListEntry {
+    cid: u64,
+    uid: u64,
+    eid: u64,
+    state: u8,
+    episode: u64,
+}
+
+#[derive(Debug, Clone)]
+struct FakeConference {
+    id: u64,
+    topic: String,
+    members: Vec<u64>,
+    group: bool,
+}
+
+#[derive(Debug, Clone)]
+struct FakeMessage {
+    id: u64,
+    conference_id: u64,
+    uid: u64,
+    text: String,
+    timestamp: i64,
+}
+
+#[derive(Debug, Clone)]
+struct FakeNews {
+    nid: u64,
+    subject: String,
+}
+
+#[derive(Debug, Default)]
+struct Data {
+    entries: Vec<FakeEntry>,
+    users: Vec<FakeUser>,
+    lists: Vec<FakeListEntry>,
+    conferences: Vec<FakeConference>,
+    messages: Vec<FakeMessage>,
+    news: Vec<FakeNews>,
+    tokens: HashMap<String, u64>,
+    next_id: u64,
+}
+
+impl Data {
+    fn next_id(&mut self) -> u64 {
+        self.next_id += 1;
+        self.next_id
+    }
+
+    fn user(&self, uid: u64) -> Option<&FakeUser> {
+        self.users.iter().find(|user| user.uid == uid)
+    }
+
+    fn username(&self, uid: u64) -> String {
+        self.user(uid).map(|user| user.username.clone()).unwrap_or_default()
+    }
+}
+
+/// Das Datenmodell und die API-Funktionen des nachgebauten Servers.
+///
+/// Unterstützt werden `user/login`, `user/logout`, `user/userinfo`, `info/entry`,
+/// `ucp/list`, `ucp/listsum`, `ucp/setcommentstate`, `messenger/conferences`,
+/// `messenger/messages`, `messenger/setmessage`, `messenger/newconference`,
+/// `notifications/count`, `notifications/news` und `notifications/delete`.
+pub struct FakeApi {
+    api_key: String,
+    data: Mutex<Data>,
+}
+
+impl fmt::Debug for FakeApi {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let data = self.lock();
+        f.debug_struct("FakeApi")
+            .field("entries", &data.entries.len())
+            .field("users", &data.users.len())
+            .field("conferences", &data.conferences.len())
+            .field("logged_in", &data.tokens.len())
+            .finish()
+    }
+}
+
+impl FakeApi {
+    /// Erstellt einen Server ohne Daten, der nur Anfragen mit dem API-Key `api_key` annimmt.
+    pub fn new(api_key: &str) -> FakeApi {
+        FakeApi {
+            api_key: api_key.to_owned(),
+            data: Mutex::new(Data {
+                next_id: 1000,
+                ..Data::default()
+            }),
+        }
+    }
+
+    /// Erstellt einen Server mit Beispieldaten:
+    ///
+    /// * die User "genesis" (UID 1, Passwort "passwort") und "souryo" (UID 2, Passwort "geheim"),
+    /// * drei Entrys (IDs 53, 1217 und 7834), davon zwei in der Liste von "genesis",
+    /// * eine Konferenz (ID 1) zwischen beiden Usern mit zwei Nachrichten,
+    /// * zwei News.
+    pub fn with_sample_data(api_key: &str) -> FakeApi {
+        FakeApi::new(api_key)
+            .user(1, "genesis", "passwort")
+            .user(2, "souryo", "geheim")
+            .entry(53, "Hunter x Hunter (2011)", "animeseries", 148)
+            .entry(1217, "One Piece", "mangaseries", 1000)
+            .entry(7834, "Kimi no Na wa.", "movie", 1)
+            .list_entry(1, 53, 1, 23)
+            .list_entry(1, 7834, 0, 1)
+            .conference(1, "", &[1, 2])
+            .message(1, 2, "Hallo!")
+            .message(1, 1, "Hi, wie geht's?")
+            .news(1, "Willkommen bei Proxer")
+            .news(2, "Neue API-Version")
+    }
+
+    /// Fügt einen Entry hinzu. `medium` ist z.B. "animeseries", "movie" oder "mangaseries".
+    pub fn entry(self, id: u64, name: &str, medium: &str, count: u64) -> Self {
+        self.lock().entries.push(FakeEntry {
+            id,
+            name: name.to_owned(),
+            medium: medium.to_owned(),
+            count,
+        });
+        self
+    }
+
+    /// Fügt einen User hinzu, der sich mit `username` und `password` einloggen kann.
+    pub fn user(self, uid: u64, username: &str, password: &str) -> Self {
+        self.lock().users.push(FakeUser {
+            uid,
+            username: username.to_owned(),
+            password: password.to_owned(),
+        });
+        self
+    }
+
+    /// Fügt den Entry `eid` der Liste des Users `uid` hinzu.
+    /// `state`: 0 (gesehen), 1 (am Schauen), 2 (wird geschaut), 3 (abgebrochen).
+    pub fn list_entry(self, uid: u64, eid: u64, state: u8, episode: u64) -> Self {
+        {
+            let mut data = self.lock();
+            let cid = data.next_id();
+            data.lists.push(FakeListEntry {
+                cid,
+                uid,
+                eid,
+                state,
+                episode,
+            });
+        }
+        self
+    }
+
+    /// Fügt eine Konferenz zwischen den Usern `members` hinzu. Bei mehr als zwei Usern ist es eine Gruppe.
+    pub fn conference(self, id: u64, topic: &str, members: &[u64]) -> Self {
+        self.lock().conferences.push(FakeConference {
+            id,
+            topic: topic.to_owned(),
+            members: members.to_vec(),
+            group: members.len() > 2,
+        });
+        self
+    }
+
+    /// Fügt der Konferenz `conference_id` eine Nachricht des Users `uid` hinzu.
+    pub fn message(self, conference_id: u64, uid: u64, text: &str) -> Self {
+        {
+            let mut data = self.lock();
+            let id = data.next_id();
+            data.messages.push(FakeMessage {
+                id,
+                conference_id,
+                uid,
+                text: text.to_owned(),
+                timestamp: 1_500_000_000 + id as i64,
+            });
+        }
+        self
+    }
+
+    /// Fügt eine News hinzu.
+    pub fn news(self, nid: u64, subject: &str) -> Self {
+        self.lock().news.push(FakeNews {
+            nid,
+            subject: subject.to_owned(),
+        });
+        self
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Data> {
+        self.data.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Beantwortet eine Anfrage an `path` (z.B. "/api/v1/info/entry") und liefert den JSON-Body der Antwort.
+    pub fn handle(&self, path: &str, api_key: Option<&str>, token: Option<&str>, body: &str) -> String {
+        let path = path.split(['?', '#']).next().unwrap_or_default();
+        let mut segments = path.trim_end_matches('/').rsplit('/');
+        let api_function = segments.next().unwrap_or_default();
+        let api_class = segments.next().unwrap_or_default();
+        let api_version = segments.next().unwrap_or_default();
+        let params: HashMap<String, String> = Params::parse(body)
+            .iter()
+            .map(|(name, value)| (name.to_owned(), value.to_owned()))
+            .collect();
+
+        let reply = self.dispatch(api_version, api_class, api_function, &params, api_key, token);
+        debug!("Fake API {}/{}: {:?}", api_class, api_function, reply.as_ref().err());
+        let json = match reply {
+            Ok(Value::Null) => serde_json::json!({ "error": 0, "message": "Erfolgreich" }),
+            Ok(data) => serde_json::json!({ "error": 0, "message": "Erfolgreich", "data": data }),
+            Err(code) => serde_json::json!({
+                "error": 1,
+                "message": code.description_in(MessageLanguage::German),
+                "code": code.code(),
+            }),
+        };
+        json.to_string()
+    }
+
+    fn dispatch(
+        &self,
+        api_version: &str,
+        api_class: &str,
+        api_function: &str,
+        params: &HashMap<String, String>,
+        api_key: Option<&str>,
+        token: Option<&str>,
+    ) -> Reply {
+        if api_key != Some(self.api_key.as_str()) {
+            return Err(ProxerErrorCode::InsufficientPermissions);
+        }
+        if api_version != "v1" {
+            return Err(ProxerErrorCode::ApiVersionNotFound);
+        }
+
+        let mut data = self.lock();
+        let uid = match token {
+            Some(token) if !token.is_empty() => match data.tokens.get(token) {
+                Some(&uid) => Some(uid),
+                None if (api_class, api_function) != ("user", "login") => {
+                    return Err(ProxerErrorCode::InvalidLoginToken)
+                }
+                None => None,
+            },
+            _ => None,
+        };
+        let call = Call { params, uid };
+
+        match (api_class, api_function) {
+            ("user", "login") => call.login(&mut data),
+            ("user", "logout") => {
+                if let Some(token) = token {
+                    data.tokens.remove(token);
+                }
+                Ok(Value::Null)
+            }
+            ("user", "userinfo") => call.userinfo(&data),
+            ("info", "entry") => call.entry(&data),
+            ("ucp", "list") => call.ucp_list(&data),
+            ("ucp", "listsum") => call.ucp_listsum(&data),
+            ("ucp", "setcommentstate") => call.ucp_setcommentstate(&mut data),
+            ("messenger", "conferences") => call.conferences(&data),
+            ("messenger", "messages") => call.messages(&data),
+            ("messenger", "setmessage") => call.setmessage(&mut data),
+            ("messenger", "newconference") => call.newconference(&mut data),
+            ("notifications", "count") => call.notifications_count(&data),
+            ("notifications", "news") => call.news(&data),
+            ("notifications", "delete") => call.notifications_delete(&mut data),
+            ("user", _) | ("info", _) | ("ucp", _) | ("messenger", _) | ("notifications", _) | ("list", _)
+            | ("anime", _) | ("manga", _) | ("media", _) => Err(ProxerErrorCode::ApiFunctionNotFound),
+            _ => Err(ProxerErrorCode::ApiClassNotFound),
+        }
+    }
+}
+
+impl Transport for FakeApi {
+    fn send(&self, request: &Request) -> Result<Response> {
+        let header = |name: &str| {
+            request
+                .headers
+                .iter()
+                .find(|&(key, _)| key.eq_ignore_ascii_case(name))
+                .map(|(_, value)| value.as_str())
+        };
+        let body = self.handle(&request.url, header(API_KEY_HEADER), header(LOGIN_TOKEN_HEADER), &request.body);
+        Ok(Response {
+            status: 200,
+            headers: vec![("Content-Type".to_owned(), "application/json".to_owned())],
+            body: body.into_bytes(),
+        })
+    }
+}
+
+/// Die Parameter und der eingeloggte User einer Anfrage.
+struct Call<'a> {
+    params: &'a HashMap<String, String>,
+    uid: Option<u64>,
+}
+
+impl<'a> Call<'a> {
+    fn param(&self, name: &str) -> Option<&str> {
+        self.params.get(name).map(String::as_str).filter(|value| !value.is_empty())
+    }
+
+    fn number(&self, name: &str) -> Option<u64> {
+        self.param(name).and_then(|value| value.parse().ok())
+    }
+
+    /// Der eingeloggte User, sonst `code`.
+    fn login_required(&self, code: ProxerErrorCode) -> ::std::result::Result<u64, ProxerErrorCode> {
+        self.uid.ok_or(code)
+    }
+
+    /// Die Einträge der Seite `p` mit `limit` Einträgen (Default `default_limit`).
+    fn page<T>(&self, items: Vec<T>, default_limit: u64) -> Vec<T> {
+        let page = self.number("p").unwrap_or(0) as usize;
+        let limit = self.number("limit").unwrap_or(default_limit) as usize;
+        items.into_iter().skip(page * limit).take(limit).collect()
+    }
+
+    fn login(&self, data: &mut Data) -> Reply {
+        let (username, password) = match (self.param("username"), self.param("password")) {
+            (Some(username), Some(password)) => (username, password),
+            _ => return Err(ProxerErrorCode::LoginMissingCredentials),
+        };
+        let uid = data
+            .users
+            .iter()
+            .find(|user| user.username.eq_ignore_ascii_case(username) && user.password == password)
+            .map(|user| user.uid)
+            .ok_or(ProxerErrorCode::LoginInvalidCredentials)?;
+        let token = format!("fake-token-{}-{}", uid, data.next_id());
+        data.tokens.insert(token.clone(), uid);
+        Ok(serde_json::json!({ "uid": uid, "avatar": "", "token": token }))
+    }
+
+    fn userinfo(&self, data: &Data) -> Reply {
+        let uid = self.number("uid").or(self.uid).ok_or(ProxerErrorCode::UserNotFound)?;
+        let user = data.user(uid).ok_or(ProxerErrorCode::UserNotFound)?;
+        Ok(serde_json::json!({
+            "uid": user.uid,
+            "username": user.username,
+            "avatar": "",
+            "status": "",
+            "status_time": 0,
+            "points_uploads": 0,
+            "points_anime": 0,
+            "points_manga": 0,
+            "points_info": 0,
+            "points_forum": 0,
+            "points_misc": 0,
+        }))
+    }
+
+    fn entry(&self, data: &Data) -> Reply {
+        let id = self.number("id").ok_or(ProxerErrorCode::InfoInvalidId)?;
+        let entry = data.entries.iter().find(|entry| entry.id == id).ok_or(ProxerErrorCode::InfoInvalidId)?;
+        Ok(serde_json::json!({
+            "id": entry.id,
+            "name": entry.name,
+            "genre": "",
+            "fsk": "",
+            "description": "",
+            "medium": entry.medium,
+            "count": entry.count,
+            "state": 1,
+            "rate_sum": 0,
+            "rate_count": 0,
+            "clicks": 0,
+            "kat": entry.kat(),
+            "license": 0,
+        }))
+    }
+
+    fn ucp_list(&self, data: &Data) -> Reply {
+        let uid = self.login_required(ProxerErrorCode::UcpNotLoggedIn)?;
+        let kat = self.param("kat").unwrap_or("anime");
+        if kat != "anime" && kat != "manga" {
+            return Err(ProxerErrorCode::UcpInvalidCategory);
+        }
+        let items = data
+            .lists
+            .iter()
+            .filter(|item| item.uid == uid)
+            .filter_map(|item| data.entries.iter().find(|entry| entry.id == item.eid).map(|entry| (item, entry)))
+            .filter(|&(_, entry)| entry.kat() == kat)
+            .map(|(item, entry)| {
+                serde_json::json!({
+                    "id": entry.id,
+                    "name": entry.name,
+                    "count": entry.count,
+                    "medium": entry.medium,
+                    "estate": "1",
+                    "cid": item.cid,
+                    "comment": "",
+                    "state": item.state.to_string(),
+                    "episode": item.episode,
+                    "data": "",
+                    "rating": 0,
+                })
+            })
+            .collect();
+        Ok(Value::Array(self.page(items, 100)))
+    }
+
+    fn ucp_listsum(&self, data: &Data) -> Reply {
+        let uid = self.login_required(ProxerErrorCode::UcpNotLoggedIn)?;
+        let sum: u64 = data.lists.iter().filter(|item| item.uid == uid).map(|item| item.episode).sum();
+        Ok(Value::from(sum.to_string()))
+    }
+
+    fn ucp_setcommentstate(&self, data: &mut Data) -> Reply {
+        let uid = self.login_required(ProxerErrorCode::UcpNotLoggedIn)?;
+        let id = self.number("id").ok_or(ProxerErrorCode::UcpInvalidId)?;
+        let value = self.number("value").filter(|&value| value <= 3).ok_or(ProxerErrorCode::UcpInvalidId)?;
+        let item = data
+            .lists
+            .iter_mut()
+            .find(|item| item.cid == id && item.uid == uid)
+            .ok_or(ProxerErrorCode::UcpInvalidId)?;
+        item.state = value as u8;
+        Ok(Value::Null)
+    }
+
+    fn conferences(&self, data: &Data) -> Reply {
+        let uid = self.login_required(ProxerErrorCode::MessengerNotLoggedIn)?;
+        let items = data
+            .conferences
+            .iter()
+            .filter(|conference| conference.members.contains(&uid))
+            .map(|conference| {
+                let last = data.messages.iter().rev().find(|message| message.conference_id == conference.id);
+                let topic = if conference.group || !conference.topic.is_empty() {
+                    conference.topic.clone()
+                } else {
+                    let other = conference.members.iter().find(|&&member| member != uid).cloned().unwrap_or(uid);
+                    data.username(other)
+                };
+                serde_json::json!({
+                    "id": conference.id,
+                    "topic": topic,
+                    "topic_custom": "",
+                    "count": conference.members.len(),
+                    "group": conference.group,
+                    "timestamp_end": last.map(|message| message.timestamp).unwrap_or(0).to_string(),
+                    "read": true,
+                    "read_count": 0,
+                    "read_mid": last.map(|message| message.id).unwrap_or(0),
+                    "image": "",
+                })
+            })
+            .collect();
+        Ok(Value::Array(self.page(items, 48)))
+    }
+
+    fn messages(&self, data: &Data) -> Reply {
+        let uid = self.login_required(ProxerErrorCode::MessengerNotLoggedIn)?;
+        let conference_id = self.number("conference_id").unwrap_or(0);
+        let visible: Vec<u64> = data
+            .conferences
+            .iter()
+            .filter(|conference| conference.members.contains(&uid))
+            .map(|conference| conference.id)
+            .collect();
+        if conference_id != 0 && !visible.contains(&conference_id) {
+            return Err(ProxerErrorCode::MessengerInvalidConference);
+        }
+        let items = data
+            .messages
+            .iter()
+            .filter(|message| visible.contains(&message.conference_id))
+            .filter(|message| conference_id == 0 || message.conference_id == conference_id)
+            .map(|message| {
+                serde_json::json!({
+                    "message_id": message.id,
+                    "conference_id": message.conference_id,
+                    "user_id": message.uid,
+                    "username": data.username(message.uid),
+                    "message": message.text,
+                    "action": "",
+                    "timestamp": message.timestamp,
+                    "device": "default",
+                })
+            })
+            .collect();
+        Ok(Value::Array(items))
+    }
+
+    fn setmessage(&self, data: &mut Data) -> Reply {
+        let uid = self.login_required(ProxerErrorCode::MessengerNotLoggedIn)?;
+        let conference_id = self.number("conference_id").unwrap_or(0);
+        if !data
+            .conferences
+            .iter()
+            .any(|conference| conference.id == conference_id && conference.members.contains(&uid))
+        {
+            return Err(ProxerErrorCode::MessengerInvalidConference);
+        }
+        let text = self.param("text").ok_or(ProxerErrorCode::MessengerInvalidMessage)?;
+        Ok(Value::from(append_message(data, conference_id, uid, text).to_string()))
+    }
+
+    fn newconference(&self, data: &mut Data) -> Reply {
+        let uid = self.login_required(ProxerErrorCode::MessengerNotLoggedIn)?;
+        let username = self.param("username").ok_or(ProxerErrorCode::MessengerMissingUser)?;
+        let other = data
+            .users
+            .iter()
+            .find(|user| user.username.eq_ignore_ascii_case(username) && user.uid != uid)
+            .map(|user| user.uid)
+            .ok_or(ProxerErrorCode::MessengerInvalidUser)?;
+        let text = self.param("text").ok_or(ProxerErrorCode::MessengerInvalidMessage)?;
+
+        let existing = data
+            .conferences
+            .iter()
+            .find(|conference| {
+                !conference.group && conference.members.contains(&uid) && conference.members.contains(&other)
+            })
+            .map(|conference| conference.id);
+        let conference_id = match existing {
+            Some(id) => id,
+            None => {
+                let id = data.next_id();
+                data.conferences.push(FakeConference {
+                    id,
+                    topic: String::new(),
+                    members: vec![uid, other],
+                    group: false,
+                });
+                id
+            }
+        };
+        append_message(data, conference_id, uid, text);
+        Ok(Value::from(conference_id))
+    }
+
+    fn notifications_count(&self, data: &Data) -> Reply {
+        self.login_required(ProxerErrorCode::NotificationsNotLoggedIn)?;
+        Ok(Value::from(format!("0,0,{},0", data.news.len())))
+    }
+
+    fn news(&self, data: &Data) -> Reply {
+        let items = data
+            .news
+            .iter()
+            .rev()
+            .map(|news| {
+                serde_json::json!({
+                    "nid": news.nid,
+                    "time": 1_500_000_000 + news.nid as i64,
+                    "mid": news.nid,
+                    "description": "",
+                    "image_id": "",
+                    "image_style": "",
+                    "subject": news.subject,
+                    "hits": 0,
+                    "thread": news.nid,
+                    "uid": 1,
+                    "uname": data.username(1),
+                    "posts": 0,
+                    "catid": 1,
+                    "catname": "News",
+                })
+            })
+            .collect();
+        Ok(Value::Array(self.page(items, 15)))
+    }
+
+    fn notifications_delete(&self, data: &mut Data) -> Reply {
+        self.login_required(ProxerErrorCode::NotificationsNotLoggedIn)?;
+        match self.number("nid") {
+            Some(nid) => data.news.retain(|news| news.nid != nid),
+            None => data.news.clear(),
+        }
+        Ok(Value::Null)
+    }
+}
+
+/// Hängt eine Nachricht an die Konferenz an und liefert ihre ID.
+fn append_message(data: &mut Data, conference_id: u64, uid: u64, text: &str) -> u64 {
+    let id = data.next_id();
+    data.messages.push(FakeMessage {
+        id,
+        conference_id,
+        uid,
+        text: text.to_owned(),
+        timestamp: 1_500_000_000 + id as i64,
+    });
+    id
+}
+
+/// Stellt eine `FakeApi` per HTTP auf einem lokalen Port bereit.
+///
+/// Der Server läuft bis zum Ende des Prozesses weiter, auch wenn der `FakeServer` verworfen wird.
+pub struct FakeServer {
+    api: Arc<FakeApi>,
+    listening: Option<Listening>,
+    address: SocketAddr,
+}
+
+impl fmt::Debug for FakeServer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("FakeServer")
+            .field("api", &self.api)
+            .field("address", &self.address)
+            .finish()
+    }
+}
+
+impl FakeServer {
+    /// Startet den Server auf `address`. Mit Port 0 wird ein freier Port gewählt, siehe `FakeServer::base_url`.
+    pub fn start<A: ToSocketAddrs>(api: FakeApi, address: A) -> Result<FakeServer> {
+        let api = Arc::new(api);
+        let shared = api.clone();
+        let listening = Server::http(address)?.handle(move |mut request: HttpRequest, mut response: HttpResponse| {
+            let path = match request.uri {
+                RequestUri::AbsolutePath(ref path) => path.clone(),
+                ref uri => uri.to_string(),
+            };
+            let header = |name: &str| {
+                request
+                    .headers
+                    .get_raw(name)
+                    .and_then(|values| values.first())
+                    .map(|value| String::from_utf8_lossy(value).into_owned())
+            };
+            let api_key = header(API_KEY_HEADER);
+            let token = header(LOGIN_TOKEN_HEADER);
+            let mut body = String::new();
+            if let Err(err) = request.read_to_string(&mut body) {
+                warn!("Fake API could not read request body: {:?}", err);
+            }
+
+            let json = shared.handle(&path, api_key.as_deref(), token.as_deref(), &body);
+            response.headers_mut().set(ContentType::json());
+            if let Err(err) = response.send(json.as_bytes()) {
+                warn!("Fake API could not send response: {:?}", err);
+            }
+        })?;
+        let address = listening.socket;
+        info!("Fake Proxer API listening on {}", address);
+        Ok(FakeServer {
+            api,
+            listening: Some(listening),
+            address,
+        })
+    }
+
+    /// Die Adresse, auf der der Server erreichbar ist.
+    pub fn address(&self) -> SocketAddr {
+        self.address
+    }
+
+    /// Die Basis-URL für `ProxerBuilder::base_url`, z.B. "http://127.0.0.1:8080/api".
+    pub fn base_url(&self) -> String {
+        format!("http://{}/api", self.address)
+    }
+
+    /// Das Datenmodell des Servers.
+    pub fn api(&self) -> &FakeApi {
+        &self.api
+    }
+}
+
+impl Drop for FakeServer {
+    fn drop(&mut self) {
+        // `Listening` wartet beim Verwerfen auf das Ende des Servers, `close` löst den Server stattdessen ab.
+        if let Some(mut listening) = self.listening.take() {
+            let _ = listening.close();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use models::EmptyResponse;
+    use user::{Credentials, Session, User};
+    use Proxer;
+
+    fn proxer(api: FakeApi) -> Proxer {
+        Proxer::builder("api-key").disclaimer(|_| ()).transport(api).build().unwrap()
+    }
+
+    fn code<T: fmt::Debug>(result: Result<T>) -> ProxerErrorCode {
+        match result {
+            Err(Error::ProxerError(code, _)) => code,
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn login_list_comment_state_logout_over_http() {
+        let server = FakeServer::start(FakeApi::with_sample_data("api-key"), "127.0.0.1:0").unwrap();
+        let proxer = Proxer::builder("api-key")
+            .disclaimer(|_| ())
+            .base_url(&server.base_url())
+            .build()
+            .unwrap();
+
+        let user = proxer.login("genesis", "passwort", None).unwrap();
+        assert_eq!(user.uid, 1);
+        assert!(proxer.is_logged_in());
+
+        let ucp = proxer.ucp();
+        let list = ucp.get_list(None, None, None, None, None, None).unwrap();
+        assert_eq!(list.len(), 2);
+        let entry = list[0].clone();
+        let state = (entry.state + 1) % 4;
+        ucp.set_commentstate(entry.cid, state).unwrap();
+        let list = ucp.get_list(None, None, None, None, None, None).unwrap();
+        assert_eq!(list.iter().find(|item| item.cid == entry.cid).unwrap().state, state);
+
+        user.logout().unwrap();
+        assert!(!proxer.is_logged_in());
+        match ucp.get_list(None, None, None, None, None, None) {
+            Err(Error::LoginRequired) => {}
+            other => panic!("unexpected result: {:?}", other),
+        }
+        assert_eq!(server.api().lock().tokens.len(), 0);
+    }
+
+    /// Sendet eine Anfrage direkt an `api` und liefert den Fehlercode der Antwort.
+    fn send(api: &FakeApi, function: &str, token: Option<&str>, body: &str) -> Option<u16> {
+        let mut headers = vec![(API_KEY_HEADER.to_owned(), "api-key".to_owned())];
+        headers.extend(token.map(|token| (LOGIN_TOKEN_HEADER.to_owned(), token.to_owned())));
+        let request = Request {
+            url: format!("https://proxer.me/api/v1/{}", function),
+            headers,
+            body: body.to_owned(),
+        };
+        let response: EmptyResponse = serde_json::from_slice(&api.send(&request).unwrap().body).unwrap();
+        response.code
+    }
+
+    #[test]
+    fn reports_api_errors_as_transport() {
+        let api = FakeApi::with_sample_data("api-key");
+        assert_eq!(send(&api, "info/entry", None, "id=1"), Some(3007));
+        assert_eq!(send(&api, "info/entry", None, "id=53"), None);
+        assert_eq!(send(&api, "messenger/conferences", None, ""), Some(3023));
+        assert_eq!(send(&api, "ucp/list", None, ""), Some(3004));
+        assert_eq!(send(&api, "info/unknown", None, ""), Some(1003));
+
+        let proxer = proxer(api);
+        assert_eq!(code(proxer.info().get_entry(1)), ProxerErrorCode::InfoInvalidId);
+        assert_eq!(proxer.info().get_entry(53).unwrap().id, 53);
+        assert_eq!(code(proxer.login("genesis", "falsch", None)), ProxerErrorCode::LoginInvalidCredentials);
+
+        proxer.login("souryo", "geheim", None).unwrap();
+        assert_eq!(proxer.messenger().get_conferences(None, None).unwrap().len(), 1);
+        assert_eq!(code(proxer.ucp().set_commentstate(1001, 2)), ProxerErrorCode::UcpInvalidId);
+    }
+
+    #[test]
+    fn checks_api_key_and_token() {
+        let api = FakeApi::with_sample_data("api-key");
+        let other_key = proxer(FakeApi::with_sample_data("other-key"));
+        assert_eq!(code(other_key.info().get_entry(53)), ProxerErrorCode::InsufficientPermissions);
+
+        let proxer = proxer(api);
+        proxer.set_login_token(Some("expired".to_owned()));
+        assert_eq!(code(proxer.ucp().get_listsum(None)), ProxerErrorCode::InvalidLoginToken);
+    }
+
+    #[test]
+    fn resume_checks_token_before_using_it() {
+        let proxer = Proxer::builder("api-key")
+            .disclaimer(|_| ())
+            .transport(FakeApi::with_sample_data("api-key"))
+            .relogin(Credentials::new("genesis", "passwort"))
+            .build()
+            .unwrap();
+        let token = proxer.login("souryo", "geheim", None).unwrap().token;
+
+        match User::resume(&proxer, "expired") {
+            Err(Error::SessionExpired) => {}
+            other => panic!("unexpected result: {:?}", other),
+        }
+        assert_eq!(proxer.login_token(), Some(token.clone()));
+
+        let user = User::resume(&proxer, &token).unwrap();
+        assert_eq!((user.uid, user.token), (2, token.clone()));
+        assert_eq!(proxer.login_token(), Some(token));
+    }
+
+    #[test]
+    fn saves_session_for_owner_only() {
+        let proxer = proxer(FakeApi::with_sample_data("api-key"));
+        let session = proxer.login("souryo", "geheim", None).unwrap().session();
+        let path = ::std::env::temp_dir().join(format!("proxer-session-{}.json", ::std::process::id()));
+        session.save(&path).unwrap();
+        let loaded = Session::load(&path);
+        #[cfg(unix)]
+        let mode = {
+            use std::os::unix::fs::PermissionsExt;
+            ::std::fs::metadata(&path).unwrap().permissions().mode()
+        };
+        ::std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded.unwrap().token, session.token);
+        #[cfg(unix)]
+        assert_eq!(mode & 0o777, 0o600);
+    }
+}
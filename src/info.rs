@@ -1,12 +1,14 @@
-use serde_json;
-
 use error::*;
+use lenient;
+use paginator::{Paged, Paginator};
+use params::Params;
 use Proxer;
 use models::*;
 
 #[derive(Deserialize, Debug, Clone, PartialEq)]
 pub struct FullEntry {
     /// Die ID des Entrys
+    #[serde(with = "lenient")]
     pub id: u64,
     /// Der Originaltitel des Entrys
     pub name: String,
@@ -19,20 +21,27 @@ pub struct FullEntry {
     /// Das Medium des Entrys (animeseries,movie,ova,hentai,mangaseries,oneshot,doujin,hmanga)
     pub medium: Medium,
     /// Die Anzahl der Kapitel/Episoden (Müssen nicht hochgeladen sein!)
+    #[serde(with = "lenient")]
     pub count: u64,
     /// Ein Integer-Wert, der den Status des Entrys beschreibt (0 = x....)
+    #[serde(with = "lenient")]
     pub state: u8,
     /// Die Summe aller Bewertungen
+    #[serde(with = "lenient")]
     pub rate_sum: u64,
     /// Die Anzahl der Bewertungen
+    #[serde(with = "lenient")]
     pub rate_count: u64,
     /// Die Anzahl der Klicks, die ein Anime/Manga bekommen hat. (Wird alle 3 Monate resettet)
+    #[serde(with = "lenient")]
     pub clicks: u64,
     /// Die Kategorie des Entrys ("anime" oder "manga")
     pub kat: Kategorie,
     /// 0= Unbekannt, 1 = Nicht lizenziert, 2 = lizenziert
+    #[serde(with = "lenient")]
     pub license: u8,
     /// Ein boolean-Wert, der anzeigt, ob der Anime ab 18 ist oder nicht. (Ist dieser Wert "true", so sollte das Alter des Users geprüft werden)
+    #[serde(with = "lenient")]
     pub gate: bool,
     /// Ein Array von Objekten der Synonyme des Entrys
     pub names: Vec<String>,
@@ -51,19 +60,23 @@ pub struct FullEntry {
 #[derive(Deserialize, Debug, Clone, PartialEq)]
 pub struct FullEntryDataSeasons {
     /// Die ID des Eintrags
+    #[serde(with = "lenient")]
     pub id: u64,
     /// ... (Nicht näher definiert)
     #[serde(rename = "type")]
     pub entry_type: String,
     /// Das Jahr der Season
+    #[serde(with = "lenient")]
     pub year: i32,
     /// Eine Zahl: 0=Keine Season,1...4=winter, fühling, sommer, herbst
+    #[serde(with = "lenient")]
     pub season: u8,
 }
 
 #[derive(Deserialize, Debug, Clone, PartialEq)]
 pub struct FullEntryDataGroups {
     /// Die ID der Gruppe
+    #[serde(with = "lenient")]
     pub id: u64,
     /// Der Name der Gruppe
     pub name: String,
@@ -74,6 +87,7 @@ pub struct FullEntryDataGroups {
 #[derive(Deserialize, Debug, Clone, PartialEq)]
 pub struct FullEntryDataPublisher {
     /// Die ID des Publishers
+    #[serde(with = "lenient")]
     pub id: u64,
     /// Der Name des Publishers
     pub name: String,
@@ -87,18 +101,22 @@ pub struct FullEntryDataPublisher {
 #[derive(Deserialize, Debug, Clone, PartialEq)]
 pub struct FullEntryDataTags {
     /// Die ID des Entrytags (NICHT die ID des Tags, sondern der Verknüpfung von Tag und Entry)
+    #[serde(with = "lenient")]
     pub id: u64,
     /// Die ID des Tags
+    #[serde(with = "lenient")]
     pub tid: u64,
     /// Der Zeitpunkt, zu dem der Tag in den Entry eingetragen wurde. (Format: YYYY-MM-DD HH:ii:ss)
     pub timestamp: String,
     /// Ob der Tag zum Entry passt (0 = Unbestimmt, 1 = passt zum Entry, unpassende Tags werden nicht ausgegeben)
+    #[serde(with = "lenient")]
     pub rate_flag: u8,
     /// Ob der Tag ein Spoiler ist (0 = Kein Spoiler, 1 = Spoiler).
     /// WARNUNG: "Kein Spoiler" ist der Default-Wert. Wenn also ein Tag noch nicht genug gevoted wurde,
     /// um ein Spoiler zu sein, obwohl er es ist, so hat er trotzdem den Wert 0.
     /// Deswegen ist es sinnvoll, alle "unbestimmten" Tags wie Spoiler zu behandeln.
     /// (Bzw. ihn zwar getrennt von den Spoilern anzuzeigen, aber nicht sofort offensichtlich zu machen)
+    #[serde(with = "lenient")]
     pub spoiler_flag: u8,
     /// Der Name des Tags
     pub tag: String,
@@ -111,6 +129,7 @@ pub struct FullEntryDataTags {
 #[derive(Deserialize, Debug, Clone, PartialEq)]
 pub struct Entry {
     /// Die ID des Entrys
+    #[serde(with = "lenient")]
     pub id: u64,
     /// Der Originaltitel des Entrys
     pub name: String,
@@ -123,18 +142,24 @@ pub struct Entry {
     /// Das Medium des Entrys (animeseries,movie,ova,hentai,mangaseries,oneshot,doujin,hmanga)
     pub medium: Medium,
     /// Die Anzahl der Kapitel/Episoden (Müssen nicht hochgeladen sein!)
+    #[serde(with = "lenient")]
     pub count: u64,
     /// Ein Integer-Wert, der den Status des Entrys beschreibt (0 = x....)
+    #[serde(with = "lenient")]
     pub state: u64,
     /// Die Summe aller Bewertungen
+    #[serde(with = "lenient")]
     pub rate_sum: u64,
     /// Die Anzahl der Bewertungen
+    #[serde(with = "lenient")]
     pub rate_count: u64,
     /// Die Anzahl der Klicks, die ein Anime/Manga bekommen hat. (Wird alle 3 Monate resettet)
+    #[serde(with = "lenient")]
     pub clicks: u64,
     /// Die Kategorie des Entrys ("anime" oder "manga")
     pub kat: Kategorie,
     /// 0= Unbekannt, 1 = Nicht lizenziert, 2 = lizenziert
+    #[serde(with = "lenient")]
     pub license: u8,
 }
 
@@ -142,8 +167,10 @@ pub struct Entry {
 #[derive(Deserialize, Debug, Clone, PartialEq)]
 pub struct Name {
     /// Die ID des Namens
+    #[serde(with = "lenient")]
     pub id: u64,
     /// Die ID des Entrys
+    #[serde(with = "lenient")]
     pub eid: u64,
     /// Der Typ des Namens (...)
     #[serde(rename = "type")]
@@ -156,15 +183,19 @@ pub struct Name {
 #[derive(Deserialize, Debug, Clone, PartialEq)]
 pub struct Season {
     /// Die ID des Eintrags
+    #[serde(with = "lenient")]
     pub id: u64,
     /// Die ID des Entrys
+    #[serde(with = "lenient")]
     pub eid: u64,
     /// ...
     #[serde(rename = "type")]
     pub season_type: String,
     /// Das Jahr der Season
+    #[serde(with = "lenient")]
     pub year: i32, // time crate saves years, min etc as i32,
     /// Eine Zahl: 0=Keine Season,1...4=winter, fühling, sommer, herbst
+    #[serde(with = "lenient")]
     pub season: u8,
 }
 
@@ -172,6 +203,7 @@ pub struct Season {
 #[derive(Deserialize, Debug, Clone, PartialEq)]
 pub struct Group {
     /// Die ID der Gruppe
+    #[serde(with = "lenient")]
     pub id: u64,
     /// Der Name der Gruppe
     pub name: String,
@@ -183,6 +215,7 @@ pub struct Group {
 #[derive(Deserialize, Debug, Clone, PartialEq)]
 pub struct Publisher {
     /// Die ID des Publishers
+    #[serde(with = "lenient")]
     pub id: u64,
     /// Der Name des Publishers
     pub name: String,
@@ -197,8 +230,10 @@ pub struct Publisher {
 #[derive(Deserialize, Debug, Clone, PartialEq)]
 pub struct ListInfo {
     /// Die Nummer des ersten Kapitels
+    #[serde(with = "lenient")]
     pub start: u64,
     /// Die Nummer des letzten Kapitels
+    #[serde(with = "lenient")]
     pub end: u64,
     /// Die Kategorie des Entrys ("anime" oder "manga")
     pub kat: Kategorie,
@@ -206,6 +241,7 @@ pub struct ListInfo {
     pub lang: String,
     /// Dieser Wert stellt den momentanen Stand des Users bei diesem Anime dar.
     /// Wenn kein User eingeloggt ist, so ist dieser Wert 0.
+    #[serde(with = "lenient")]
     pub state: u64,
     /// Die Daten der einzelnen Episoden/Kapitel.
     /// (Array von Objekten, erst sortiert nach Sprache, dann nach Episodennummer).
@@ -216,6 +252,7 @@ pub struct ListInfo {
 #[derive(Deserialize, Debug, Clone, PartialEq)]
 pub struct ListInfoDataEpisode {
     /// Die Nummer des Kapitels/Episode
+    #[serde(with = "lenient")]
     pub no: u64,
     /// Der Titel des Kapitels
     pub title: Option<String>,
@@ -231,13 +268,16 @@ pub struct ListInfoDataEpisode {
 #[derive(Deserialize, Debug, Clone, PartialEq)]
 pub struct Comment {
     /// Die ID des Kommentars
+    #[serde(with = "lenient")]
     pub id: u64,
     /// Die ID des Entrys
+    #[serde(with = "lenient")]
     pub tid: u64,
     /// Der Typ des Kommentars
     #[serde(rename = "type")]
     pub comment_type: String,
     /// Der beim Kommentar angegebene Status (0 = geschaut, 1 = am schauen, 2 = wird geschaut, 3 = abgebrochen)
+    #[serde(with = "lenient")]
     pub state: u8,
     /// Zusätzliche informationen, die als json-String abgespeichert werden.
     /// Es kann sich hierbei beispielsweise um die "Genre"-Bewertung handeln.
@@ -245,16 +285,21 @@ pub struct Comment {
     /// Der Kommentar-Text
     pub comment: String,
     /// Die Bewertung des Entrys
+    #[serde(with = "lenient")]
     pub rating: f32,
     /// Die Episode, bis zu der der Kommentarschreiber geschaut hat.
+    #[serde(with = "lenient")]
     pub episode: u64,
     /// Wie viele Personen den Kommentar hilfreich finden.
+    #[serde(with = "lenient")]
     pub positive: u64,
     /// Der Zeitpunkt der letzten Bearbeitung/Erstellung (?) des Kommentars (Unix-Timestamp in Sekunden)
+    #[serde(with = "lenient")]
     pub timestamp: i64,
     /// Der Username des Erstellers des Kommentars
     pub username: String,
     /// Die User-ID des Erstellers des Kommentars
+    #[serde(with = "lenient")]
     pub uid: u64,
     /// Das Profilbild des Erstellers des Kommentars
     pub avatar: String,
@@ -264,6 +309,7 @@ pub struct Comment {
 #[derive(Deserialize, Debug, Clone, PartialEq)]
 pub struct Relation {
     /// Die ID des Entrys
+    #[serde(with = "lenient")]
     pub id: u64,
     /// Der Originaltitel des Entrys
     pub name: String,
@@ -276,24 +322,32 @@ pub struct Relation {
     /// Das Medium des Entrys (animeseries,movie,ova,hentai,mangaseries,oneshot,doujin,hmanga)
     pub medium: Medium,
     /// Die Anzahl der Kapitel/Episoden (Müssen nicht hochgeladen sein!)
+    #[serde(with = "lenient")]
     pub count: u64,
     /// Ein Integer-Wert, der den Status des Entrys beschreibt (0 = x....)
+    #[serde(with = "lenient")]
     pub state: u64,
     /// Die Summe aller Bewertungen
+    #[serde(with = "lenient")]
     pub rate_sum: u64,
     /// Die Anzahl der Bewertungen
+    #[serde(with = "lenient")]
     pub rate_count: u64,
     /// Die Anzahl der Klicks, die ein Anime/Manga bekommen hat. (Wird alle 3 Monate resettet)
+    #[serde(with = "lenient")]
     pub clicks: u64,
     /// Die Kategorie des Entrys ("anime" oder "manga")
     pub kat: Kategorie,
     /// 0= Unbekannt, 1 = Nicht lizenziert, 2 = lizenziert
+    #[serde(with = "lenient")]
     pub license: u8,
     /// Die Sprachen, in denen der Entry verfügbar ist, als Komma-separierter String
     pub language: String,
     /// Das Jahr der Season
+    #[serde(with = "lenient")]
     pub year: i32,
     /// Die Season
+    #[serde(with = "lenient")]
     pub season: u8,
 }
 
@@ -301,14 +355,19 @@ pub struct Relation {
 #[derive(Deserialize, Debug, Clone, PartialEq)]
 pub struct EntryTag {
     /// Die ID des Entrytags (NICHT die ID des Tags, sondern der Verknüpfung von Tag und Entry)
+    #[serde(with = "lenient")]
     pub id: u64,
     /// Die ID des Tags
+    #[serde(with = "lenient")]
     pub tid: u64,
     /// Der Zeitpunkt, zu dem der Tag in den Entry eingetragen wurde. (Format: YYYY-MM-DD HH:ii:ss)
+    #[serde(with = "lenient")]
     pub timestamp: i64,
     /// Ob der Tag zum Entry passt (0 = Unbestimmt, 1 = passt zum Entry, unpassende Tags werden nicht ausgegeben)
+    #[serde(with = "lenient")]
     pub rate_flag: u8,
     /// Ob der Tag ein Spoiler ist (0 = Kein Spoiler, 1 = Spoiler). WARNUNG: "Kein Spoiler" ist der Default-Wert. Wenn also ein Tag noch nicht genug gevoted wurde, um ein Spoiler zu sein, obwohl er es ist, so hat er trotzdem den Wert 0. Deswegen ist es sinnvoll, alle "unbestimmten" Tags wie Spoiler zu behandeln. (Bzw. ihn zwar getrennt von den Spoilern anzuzeigen, aber nicht sofort offensichtlich zu machen)
+    #[serde(with = "lenient")]
     pub spoiler_flag: u8,
     /// Der Name des Tags
     pub tag: String,
@@ -320,6 +379,7 @@ pub struct EntryTag {
 #[derive(Deserialize, Debug, Clone, PartialEq)]
 pub struct TranslatorGroup {
     /// Die id der Gruppe
+    #[serde(with = "lenient")]
     pub id: u64,
     /// Der Name der Gruppe
     pub name: String,
@@ -332,15 +392,18 @@ pub struct TranslatorGroup {
     /// Die Beschreibung der Gruppe
     pub description: String,
     /// ...
-    pub count: String,
+    #[serde(with = "lenient")]
+    pub count: u64,
     /// ...
-    pub cprojects: String,
+    #[serde(with = "lenient")]
+    pub cprojects: u64,
 }
 
 /// Diese Funktion liefert alle Daten zu einer Firma anhand ihrer ID.
 #[derive(Deserialize, Debug, Clone, PartialEq)]
 pub struct Industry {
     /// Die id der Gruppe
+    #[serde(with = "lenient")]
     pub id: u64,
     /// Der Typ der Firma (publisher, studio, producer, record_label, talent_agent, streaming)
     #[serde(rename = "type")]
@@ -362,17 +425,194 @@ impl Industry {
     }
 }
 
+endpoint! {
+    /// Liefert ALLE Daten eines Animes/Mangas. Siehe `Info::get_fullentry`.
+    pub struct GetFullEntry -> FullEntry ["info", "fullentry"] {
+        /// Die ID des gewünschten Animes/Mangas.
+        pub id: u64,
+    }
+    |this| Params::new().add("id", &this.id)
+}
+
+endpoint! {
+    /// Liefert die Kerndaten eines Animes/Mangas. Siehe `Info::get_entry`.
+    pub struct GetEntry -> Entry ["info", "entry"] {
+        /// Die ID des gewünschten Animes/Mangas.
+        pub id: u64,
+    }
+    |this| Params::new().add("id", &this.id)
+}
+
+endpoint! {
+    /// Liefert die Synonyme eines Animes/Mangas. Siehe `Info::get_name`.
+    pub struct GetNames -> Vec<Name> ["info", "names"] {
+        /// Die ID des gewünschten Animes/Mangas.
+        pub id: u64,
+    }
+    |this| Params::new().add("id", &this.id)
+}
+
+endpoint! {
+    /// Liefert, ob ein Anime/Manga eine ab-18 Prüfung erfordert. Siehe `Info::get_gate`.
+    pub struct GetGate -> bool ["info", "gate"] {
+        /// Die ID des gewünschten Animes/Mangas.
+        pub id: u64,
+    }
+    |this| Params::new().add("id", &this.id)
+}
+
+endpoint! {
+    /// Liefert die Sprachen eines Animes/Mangas. Siehe `Info::get_language`.
+    pub struct GetLanguage -> Vec<String> ["info", "lang"] {
+        /// Die ID des gewünschten Animes/Mangas.
+        pub id: u64,
+    }
+    |this| Params::new().add("id", &this.id)
+}
+
+endpoint! {
+    /// Liefert die Seasons eines Animes/Mangas. Siehe `Info::get_season`.
+    pub struct GetSeason -> Vec<Season> ["info", "season"] {
+        /// Die ID des gewünschten Animes/Mangas.
+        pub id: u64,
+    }
+    |this| Params::new().add("id", &this.id)
+}
+
+endpoint! {
+    /// Liefert die Übersetzergruppen eines Animes/Mangas. Siehe `Info::get_groups`.
+    pub struct GetGroups -> Vec<Group> ["info", "groups"] {
+        /// Die ID des gewünschten Animes/Mangas.
+        pub id: u64,
+    }
+    |this| Params::new().add("id", &this.id)
+}
+
+endpoint! {
+    /// Liefert die Publisher eines Animes/Mangas. Siehe `Info::get_publisher`.
+    pub struct GetPublisher -> Vec<Publisher> ["info", "publisher"] {
+        /// Die ID des gewünschten Animes/Mangas.
+        pub id: u64,
+    }
+    |this| Params::new().add("id", &this.id)
+}
+
+endpoint! {
+    /// Liefert die Episoden/Kapitel eines Entrys. Siehe `Info::get_listinfo`.
+    pub struct GetListInfo -> ListInfo ["info", "listinfo"] {
+        /// Die ID des gewünschten Animes/Mangas.
+        pub id: u64,
+        /// Die zu ladende Seite der Liste, Start bei 0. Default 0.
+        pub page: Option<u64>,
+        /// Die Nummer der zu ladenden Episoden/Kapitel pro Seite. Default 50.
+        pub limit: Option<u64>,
+    }
+    |this| Params::new()
+        .add("id", &this.id)
+        .add("p", &this.page)
+        .add("limit", &this.limit)
+}
+
+impl Paged for GetListInfo {
+    type Item = ListInfoDataEpisode;
+
+    fn default_page_size(&self) -> u64 {
+        50
+    }
+
+    fn set_page(&mut self, page: u64, limit: u64) -> u64 {
+        self.page = Some(page);
+        self.limit = Some(limit);
+        limit
+    }
+
+    fn into_items(output: ListInfo) -> Vec<ListInfoDataEpisode> {
+        output.episodes
+    }
+}
+
+endpoint! {
+    /// Liefert die Kommentare eines Animes/Mangas. Siehe `Info::get_comments`.
+    pub struct GetComments -> Vec<Group> ["info", "comments"] {
+        /// Die ID des gewünschten Animes/Mangas.
+        pub id: u64,
+        /// Die zu ladende Seite der Liste, Start bei 0. Default 0.
+        pub page: Option<u64>,
+        /// Die Nummer der zu ladenden Kommentare pro Seite. Default 25.
+        pub limit: Option<u64>,
+        /// Die Sortierung der Liste.
+        pub sort: Option<Sort>,
+    }
+    |this| Params::new()
+        .add("id", &this.id)
+        .add("p", &this.page)
+        .add("limit", &this.limit)
+        .add("sort", &this.sort)
+}
+
+paged!(GetComments => Group, 25);
+
+endpoint! {
+    /// Liefert die Verbindungen eines Entrys. Siehe `Info::get_relations`.
+    pub struct GetRelations -> Vec<Relation> ["info", "relations"] {
+        /// Die ID des gewünschten Animes/Mangas.
+        pub id: u64,
+    }
+    |this| Params::new().add("id", &this.id)
+}
+
+endpoint! {
+    /// Liefert die Tags eines Entrys. Siehe `Info::get_entrytag`.
+    pub struct GetEntryTag -> Vec<EntryTag> ["info", "entrytag"] {
+        /// Die ID des gewünschten Animes/Mangas.
+        pub id: u64,
+    }
+    |this| Params::new().add("id", &this.id)
+}
+
+endpoint! {
+    /// Liefert die Daten einer Sub/Scanlation Gruppe. Siehe `Info::get_translatorgroup`.
+    pub struct GetTranslatorGroup -> TranslatorGroup ["info", "translatorgroup"] {
+        /// Die ID der gewünschten Gruppe.
+        pub id: u64,
+    }
+    |this| Params::new().add("id", &this.id)
+}
+
+endpoint! {
+    /// Liefert die Daten einer Firma. Siehe `Info::get_industry`.
+    pub struct GetIndustry -> Industry ["info", "industry"] {
+        /// Die ID der gewünschten Firma.
+        pub id: u64,
+    }
+    |this| Params::new().add("id", &this.id)
+}
+
+endpoint! {
+    /// Setzt einen Anime/Manga auf eine der Listen des Users. Siehe `Info::set_userinfo`.
+    pub struct SetUserInfo -> () ["info", "setuserinfo", mutates, login] {
+        /// Die ID des gewünschten Animes/Mangas.
+        pub id: u64,
+        /// Die Liste, zu der der Anime hinzugefügt werden soll.
+        pub watch_type: WatchType,
+    }
+    |this| Params::new()
+        .add("id", &this.id)
+        .add("type", &this.watch_type)
+}
+
 /// Diese Klasse beinhaltet alle Schnittstellen, die sich auf das Info-System Proxers beziehen,
 /// insbesondere in Bezug auf Informationen zu Animes und Mangas, aber auch zu den weiteren Verzeichnissen.
-#[derive(Debug)]
-pub struct Info<'a> {
-    proxer: &'a Proxer,
+#[derive(Debug, Clone)]
+pub struct Info {
+    proxer: Proxer,
 }
 
-impl<'a> Info<'a> {
-    #[doc(hidden)]
-    pub fn new(proxer: &'a Proxer) -> Info<'a> {
-        Info { proxer: proxer }
+impl Info {
+    pub(crate) fn new(proxer: &Proxer) -> Info {
+        Info {
+            proxer: proxer.clone(),
+        }
     }
 
     /// Diese Funktion liefert ALLE Daten eines Animes/Mangas anhand seiner ID.
@@ -383,12 +623,7 @@ impl<'a> Info<'a> {
     ///
     /// * `id` - Die ID des gewünschten Animes/Mangas.
     pub fn get_fullentry(&self, id: u64) -> Result<FullEntry> {
-        let url = url!("info", "fullentry");
-        let body = param_build!("id" => Some(id));
-        let response = self.proxer.connect(&url, &body)?;
-        let data: Response<FullEntry> = serde_json::from_reader(response)?;
-        check_error!(data.error, data.code.unwrap_or_default(), data.message);
-        check_data!(data.data)
+        self.proxer.execute(&GetFullEntry { id })
     }
 
     /// Diese Funktion liefert die Daten eines Animes/Mangas anhand seiner ID
@@ -399,12 +634,7 @@ impl<'a> Info<'a> {
     ///
     /// * `id` - Die ID des gewünschten Animes/Mangas.
     pub fn get_entry(&self, id: u64) -> Result<Entry> {
-        let url = url!("info", "entry");
-        let body = param_build!("id" => Some(id));
-        let response = self.proxer.connect(&url, &body)?;
-        let data: Response<Entry> = serde_json::from_reader(response)?;
-        check_error!(data.error, data.code.unwrap_or_default(), data.message);
-        check_data!(data.data)
+        self.proxer.execute(&GetEntry { id })
     }
 
     /// Diese Funktion liefert die unterschiedlichen Synonyme eines Animes/Mangas anhand seiner ID
@@ -413,12 +643,7 @@ impl<'a> Info<'a> {
     ///
     /// * `id` - Die ID des gewünschten Animes/Mangas.
     pub fn get_name(&self, id: u64) -> Result<Vec<Name>> {
-        let url = url!("info", "names");
-        let body = param_build!("id" => Some(id));
-        let response = self.proxer.connect(&url, &body)?;
-        let data: Response<Vec<Name>> = serde_json::from_reader(response)?;
-        check_error!(data.error, data.code.unwrap_or_default(), data.message);
-        check_data!(data.data)
+        self.proxer.execute(&GetNames { id })
     }
 
     /// Diese Funktion liefert einen boolean Wert zurück,
@@ -428,12 +653,7 @@ impl<'a> Info<'a> {
     ///
     /// * `id` - Die ID des gewünschten Animes/Mangas.
     pub fn get_gate(&self, id: u64) -> Result<bool> {
-        let url = url!("info", "gate");
-        let body = param_build!("id" => Some(id));
-        let response = self.proxer.connect(&url, &body)?;
-        let data: Response<bool> = serde_json::from_reader(response)?;
-        check_error!(data.error, data.code.unwrap_or_default(), data.message);
-        check_data!(data.data)
+        self.proxer.execute(&GetGate { id })
     }
 
     /// Diese Funktion liefert die für einen Anime/Manga Eingetragenen Sprachen
@@ -443,12 +663,7 @@ impl<'a> Info<'a> {
     ///
     /// * `id` - Die ID des gewünschten Animes/Mangas.
     pub fn get_language(&self, id: u64) -> Result<Vec<String>> {
-        let url = url!("info", "lang");
-        let body = param_build!("id" => Some(id));
-        let response = self.proxer.connect(&url, &body)?;
-        let data: Response<Vec<String>> = serde_json::from_reader(response)?;
-        check_error!(data.error, data.code.unwrap_or_default(), data.message);
-        check_data!(data.data)
+        self.proxer.execute(&GetLanguage { id })
     }
 
     /// Diese Funktion liefert die für einen Anime/Manga Eingetragenen Seasons.
@@ -457,12 +672,7 @@ impl<'a> Info<'a> {
     ///
     /// * `id` - Die ID des gewünschten Animes/Mangas.
     pub fn get_season(&self, id: u64) -> Result<Vec<Season>> {
-        let url = url!("info", "season");
-        let body = param_build!("id" => Some(id));
-        let response = self.proxer.connect(&url, &body)?;
-        let data: Response<Vec<Season>> = serde_json::from_reader(response)?;
-        check_error!(data.error, data.code.unwrap_or_default(), data.message);
-        check_data!(data.data)
+        self.proxer.execute(&GetSeason { id })
     }
 
     /// Diese Funktion liefert die für einen Anime/Manga Eingetragenen Übersetzergruppen.
@@ -471,12 +681,7 @@ impl<'a> Info<'a> {
     ///
     /// * `id` - Die ID des gewünschten Animes/Mangas.
     pub fn get_groups(&self, id: u64) -> Result<Vec<Group>> {
-        let url = url!("info", "groups");
-        let body = param_build!("id" => Some(id));
-        let response = self.proxer.connect(&url, &body)?;
-        let data: Response<Vec<Group>> = serde_json::from_reader(response)?;
-        check_error!(data.error, data.code.unwrap_or_default(), data.message);
-        check_data!(data.data)
+        self.proxer.execute(&GetGroups { id })
     }
 
     /// Diese Funktion liefert die für einen Anime/Manga Eingetragenen Publisher.
@@ -485,12 +690,7 @@ impl<'a> Info<'a> {
     ///
     /// * `id` - Die ID des gewünschten Animes/Mangas.
     pub fn get_publisher(&self, id: u64) -> Result<Vec<Publisher>> {
-        let url = url!("info", "publisher");
-        let body = param_build!("id" => Some(id));
-        let response = self.proxer.connect(&url, &body)?;
-        let data: Response<Vec<Publisher>> = serde_json::from_reader(response)?;
-        check_error!(data.error, data.code.unwrap_or_default(), data.message);
-        check_data!(data.data)
+        self.proxer.execute(&GetPublisher { id })
     }
 
     /// Diese Funktion liefert eine Liste aller Episoden/Kapitel eines Entrys anhand dessen ID.
@@ -501,14 +701,12 @@ impl<'a> Info<'a> {
     /// * `p` - (optional): Die zu ladende Seite der Liste, Start bei 0. Default 0.
     /// * `limit` - (optional): Die Nummer der zu ladenden Episoden/Kapitel pro Seite. Default 50.
     pub fn get_listinfo(&self, id: u64, page: Option<u64>, limit: Option<u64>) -> Result<ListInfo> {
-        let url = url!("info", "listinfo");
-        let body = param_build!("id" => Some(id),
-            "p" => page,
-            "limit" => limit);
-        let response = self.proxer.connect(&url, &body)?;
-        let data: Response<ListInfo> = serde_json::from_reader(response)?;
-        check_error!(data.error, data.code.unwrap_or_default(), data.message);
-        check_data!(data.data)
+        self.proxer.execute(&GetListInfo { id, page, limit })
+    }
+
+    /// Wie `get_listinfo`, lädt aber die Episoden/Kapitel aller Seiten nacheinander.
+    pub fn get_listinfo_all(&self, id: u64) -> Paginator<GetListInfo> {
+        self.proxer.paginate(GetListInfo { id, page: None, limit: None })
     }
 
     /// Diese Funktion liefert die für einen Anime/Manga abgegebenen Kommentare (mit mehr als 300 Zeichen).
@@ -519,7 +717,7 @@ impl<'a> Info<'a> {
     /// * `p` - Die zu ladende Seite der Liste, Start bei 0. Default 0.
     /// * `limit` - Die Nummer der zu ladenden Kommentare pro Seite. Default 25.
     /// * `sort` - Ändert die Sortierung der Liste. Setze Wert "rating" um nach meisten Empfehlungen zu sortieren,
-    /// ansonsten Sortierung nach Neueste zuerst.
+    ///   ansonsten Sortierung nach Neueste zuerst.
     pub fn get_comments(
         &self,
         id: u64,
@@ -527,15 +725,17 @@ impl<'a> Info<'a> {
         limit: Option<u64>,
         sort: Option<Sort>,
     ) -> Result<Vec<Group>> {
-        let url = url!("info", "comments");
-        let body = param_build!("id" => Some(id),
-            "p" => page,
-            "limit" => limit,
-            "sort" => sort);
-        let response = self.proxer.connect(&url, &body)?;
-        let data: Response<Vec<Group>> = serde_json::from_reader(response)?;
-        check_error!(data.error, data.code.unwrap_or_default(), data.message);
-        check_data!(data.data)
+        self.proxer.execute(&GetComments { id, page, limit, sort })
+    }
+
+    /// Wie `get_comments`, lädt aber die Kommentare aller Seiten nacheinander.
+    pub fn get_comments_all(&self, id: u64, sort: Option<Sort>) -> Paginator<GetComments> {
+        self.proxer.paginate(GetComments {
+            id,
+            page: None,
+            limit: None,
+            sort,
+        })
     }
 
     /// Diese Funktion liefert alle Verbindungen eines Entrys.
@@ -544,12 +744,7 @@ impl<'a> Info<'a> {
     ///
     /// * `id` - Die ID des gewünschten Animes/Mangas.
     pub fn get_relations(&self, id: u64) -> Result<Vec<Relation>> {
-        let url = url!("info", "relations");
-        let body = param_build!("id" => Some(id));
-        let response = self.proxer.connect(&url, &body)?;
-        let data: Response<Vec<Relation>> = serde_json::from_reader(response)?;
-        check_error!(data.error, data.code.unwrap_or_default(), data.message);
-        check_data!(data.data)
+        self.proxer.execute(&GetRelations { id })
     }
 
     /// Diese Funktion liefert alle Tags eines Entrys.
@@ -558,12 +753,7 @@ impl<'a> Info<'a> {
     ///
     /// * `id` - Die ID des gewünschten Animes/Mangas.
     pub fn get_entrytag(&self, id: u64) -> Result<Vec<EntryTag>> {
-        let url = url!("info", "entrytag");
-        let body = param_build!("id" => Some(id));
-        let response = self.proxer.connect(&url, &body)?;
-        let data: Response<Vec<EntryTag>> = serde_json::from_reader(response)?;
-        check_error!(data.error, data.code.unwrap_or_default(), data.message);
-        check_data!(data.data)
+        self.proxer.execute(&GetEntryTag { id })
     }
 
     /// Diese Funktion liefert alle Daten zu einer Sub/Scanlation Gruppe anhand ihrer ID.
@@ -572,12 +762,7 @@ impl<'a> Info<'a> {
     ///
     /// * `id` - Die ID der gewünschten Gruppe.
     pub fn get_translatorgroup(&self, id: u64) -> Result<TranslatorGroup> {
-        let url = url!("info", "translatorgroup");
-        let body = param_build!("id" => Some(id));
-        let response = self.proxer.connect(&url, &body)?;
-        let data: Response<TranslatorGroup> = serde_json::from_reader(response)?;
-        check_error!(data.error, data.code.unwrap_or_default(), data.message);
-        check_data!(data.data)
+        self.proxer.execute(&GetTranslatorGroup { id })
     }
 
     /// Diese Funktion liefert alle Daten zu einer Firma anhand ihrer ID.
@@ -586,12 +771,7 @@ impl<'a> Info<'a> {
     ///
     /// * `id` - Die ID der gewünschten Gruppe.
     pub fn get_industry(&self, id: u64) -> Result<Industry> {
-        let url = url!("info", "industry");
-        let body = param_build!("id" => Some(id));
-        let response = self.proxer.connect(&url, &body)?;
-        let data: Response<Industry> = serde_json::from_reader(response)?;
-        check_error!(data.error, data.code.unwrap_or_default(), data.message);
-        check_data!(data.data)
+        self.proxer.execute(&GetIndustry { id })
     }
 
     /// Diese Funktion setzt einen per ID spezifizierten Anime/Manga auf eine der Listen des Users, abhängig vom Paramter "type".
@@ -600,13 +780,8 @@ impl<'a> Info<'a> {
     ///
     /// * `id` - Die ID des gewünschten Animes/Mangas.
     /// * `type` - Die Liste, zu der der Anime hinzugefügt werden soll.
-    /// Erlaubt: "note" (Wird noch geschaut), "favor" (Favoriten), "finish" (Abgeschlossen)
+    ///   Erlaubt: "note" (Wird noch geschaut), "favor" (Favoriten), "finish" (Abgeschlossen)
     pub fn set_userinfo(&self, id: u64, watch_type: WatchType) -> Result<()> {
-        let url = url!("info", "setuserinfo");
-        let body = param_build!("id" => Some(id), "type" => Some(watch_type));
-        let response = self.proxer.connect(&url, &body)?;
-        let data: EmptyResponse = serde_json::from_reader(response)?;
-        check_error!(data.error, data.code.unwrap_or_default(), data.message);
-        Ok(())
+        self.proxer.execute(&SetUserInfo { id, watch_type })
     }
 }
@@ -0,0 +1,384 @@
+//! Nachsichtiges Einlesen von Zahlen und Wahrheitswerten.
+//!
+//! Die Proxer-API liefert Zahlen und Wahrheitswerte oft als Strings (`"123"`, `"1"`, `"true"`),
+//! manchmal auch als leeren String oder `null`. Felder mit `#[serde(with = "lenient")]` akzeptieren
+//! all diese Formen:
+//!
+//! * Zahlen: `123`, `"123"`, `123.0` und `true`/`false` (1/0). `""` und `null` werden zu 0.
+//! * `bool`: `true`, `1`, `"1"` und `"true"` bzw. `false`, `0`, `"0"` und `"false"`.
+//!   `""` und `null` werden zu `false`.
+//! * `String`: Zahlen und Wahrheitswerte werden als Text übernommen, `null` wird zu `""`.
+//! * `Option<T>`: `""` und `null` werden zu `None`, alles andere wie bei `T`.
+//!   Zusammen mit `#[serde(default)]` darf das Feld auch fehlen.
+//!
+//! Beim Serialisieren wird der Wert unverändert geschrieben.
+//!
+//! # Example
+//!
+//! ```
+//! extern crate proxer;
+//! #[macro_use] extern crate serde_derive;
+//! extern crate serde_json;
+//!
+//! use proxer::lenient;
+//!
+//! #[derive(Deserialize)]
+//! struct Entry {
+//!     #[serde(with = "lenient")]
+//!     count: u64,
+//!     #[serde(with = "lenient")]
+//!     licensed: bool,
+//!     #[serde(default, with = "lenient")]
+//!     tid: Option<u64>,
+//! }
+//!
+//! # fn main() {
+//! let entry: Entry = serde_json::from_str(r#"{"count": "12", "licensed": "1", "tid": ""}"#).unwrap();
+//! assert_eq!((entry.count, entry.licensed, entry.tid), (12, true, None));
+//! # }
+//! ```
+
+use std::convert::{TryFrom, TryInto};
+
+use serde::de::{self, Deserialize, Deserializer};
+use serde::{Serialize, Serializer};
+use serde_json::Value;
+
+/// Ein Typ, der nachsichtig aus einem JSON-Wert gelesen werden kann.
+pub trait Lenient: Sized {
+    /// Was erwartet wird, für die Fehlermeldung.
+    const EXPECTING: &'static str;
+
+    /// Liest den Wert, `None` wenn `value` nicht passt.
+    fn from_json(value: &Value) -> Option<Self>;
+}
+
+/// Liest ein Feld nachsichtig. Siehe `Lenient`.
+pub fn deserialize<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Lenient,
+{
+    let value = Value::deserialize(deserializer)?;
+    T::from_json(&value)
+        .ok_or_else(|| de::Error::custom(format_args!("invalid value {}, expected {}", value, T::EXPECTING)))
+}
+
+/// Schreibt ein Feld unverändert.
+pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Serialize,
+    S: Serializer,
+{
+    value.serialize(serializer)
+}
+
+/// Ob `value` für "kein Wert" steht: `null` oder ein leerer String.
+fn is_empty(value: &Value) -> bool {
+    match *value {
+        Value::Null => true,
+        Value::String(ref text) => text.trim().is_empty(),
+        _ => false,
+    }
+}
+
+macro_rules! lenient_integer
+{
+    ( $( $ty:ty ),* ) =>
+    (
+        $(
+            impl Lenient for $ty {
+                const EXPECTING: &'static str = concat!("a ", stringify!($ty), " or a string containing one");
+
+                fn from_json(value: &Value) -> Option<$ty> {
+                    match *value {
+                        _ if is_empty(value) => Some(0),
+                        Value::Bool(flag) => Some(flag as $ty),
+                        Value::Number(ref number) => number
+                            .as_u64()
+                            .and_then(|number| number.try_into().ok())
+                            .or_else(|| number.as_i64().and_then(|number| number.try_into().ok()))
+                            .or_else(|| number.as_f64().and_then(float_to_integer)),
+                        Value::String(ref text) => {
+                            let text = text.trim();
+                            text.parse().ok().or_else(|| text.parse().ok().and_then(float_to_integer))
+                        }
+                        _ => None,
+                    }
+                }
+            }
+        )*
+    );
+}
+
+/// Eine Kommazahl ohne Nachkommastellen als Ganzzahl, z.B. `12.0`.
+fn float_to_integer<T: TryFrom<i64>>(number: f64) -> Option<T> {
+    if number.fract() == 0.0 && number >= i64::MIN as f64 && number <= i64::MAX as f64 {
+        T::try_from(number as i64).ok()
+    } else {
+        None
+    }
+}
+
+lenient_integer!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+macro_rules! lenient_float
+{
+    ( $( $ty:ty ),* ) =>
+    (
+        $(
+            impl Lenient for $ty {
+                const EXPECTING: &'static str = "a number or a string containing one";
+
+                fn from_json(value: &Value) -> Option<$ty> {
+                    match *value {
+                        _ if is_empty(value) => Some(0.0),
+                        Value::Bool(flag) => Some(if flag { 1.0 } else { 0.0 }),
+                        Value::Number(ref number) => number.as_f64().map(|number| number as $ty),
+                        Value::String(ref text) => text.trim().parse().ok(),
+                        _ => None,
+                    }
+                }
+            }
+        )*
+    );
+}
+
+lenient_float!(f32, f64);
+
+impl Lenient for bool {
+    const EXPECTING: &'static str = "a boolean, 0/1 or a string containing one";
+
+    fn from_json(value: &Value) -> Option<bool> {
+        match *value {
+            _ if is_empty(value) => Some(false),
+            Value::Bool(flag) => Some(flag),
+            Value::Number(ref number) => match number.as_u64() {
+                Some(0) => Some(false),
+                Some(1) => Some(true),
+                _ => None,
+            },
+            Value::String(ref text) => match text.trim() {
+                "1" | "true" | "TRUE" | "True" => Some(true),
+                "0" | "false" | "FALSE" | "False" => Some(false),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+impl Lenient for String {
+    const EXPECTING: &'static str = "a string, number or boolean";
+
+    fn from_json(value: &Value) -> Option<String> {
+        match *value {
+            Value::Null => Some(String::new()),
+            Value::String(ref text) => Some(text.clone()),
+            Value::Number(ref number) => Some(number.to_string()),
+            Value::Bool(flag) => Some(flag.to_string()),
+            _ => None,
+        }
+    }
+}
+
+impl<T: Lenient> Lenient for Option<T> {
+    const EXPECTING: &'static str = T::EXPECTING;
+
+    fn from_json(value: &Value) -> Option<Option<T>> {
+        if is_empty(value) {
+            Some(None)
+        } else {
+            T::from_json(value).map(Some)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{self, json};
+
+    use super::*;
+    use info::Season;
+    use models::{Kategorie, Medium};
+    use notification::News;
+    use testing::ScriptedTransport;
+
+    /// Die Eingaben aus den Antworten der API: `"123"`, `123`, `""`, `null`, `"true"` und `"1"`.
+    fn inputs() -> Vec<Value> {
+        vec![json!("123"), json!(123), json!(""), json!(null), json!("true"), json!("1")]
+    }
+
+    fn parse_all<T: Lenient>() -> Vec<Option<T>> {
+        inputs().iter().map(T::from_json).collect()
+    }
+
+    macro_rules! integer_tests
+    {
+        ( $( $name:ident: $ty:ty ),* ) =>
+        (
+            $(
+                #[test]
+                fn $name() {
+                    assert_eq!(parse_all::<$ty>(), vec![Some(123), Some(123), Some(0), Some(0), None, Some(1)]);
+                    assert_eq!(<$ty>::from_json(&json!(true)), Some(1));
+                    assert_eq!(<$ty>::from_json(&json!("12.0")), Some(12));
+                    assert_eq!(<$ty>::from_json(&json!(12.5)), None);
+                    assert_eq!(<$ty>::from_json(&json!("abc")), None);
+                    assert_eq!(<$ty>::from_json(&json!([1])), None);
+                }
+            )*
+        );
+    }
+
+    integer_tests!(
+        u8_values: u8,
+        u16_values: u16,
+        u32_values: u32,
+        u64_values: u64,
+        usize_values: usize,
+        i8_values: i8,
+        i16_values: i16,
+        i32_values: i32,
+        i64_values: i64,
+        isize_values: isize
+    );
+
+    #[test]
+    fn integer_ranges() {
+        assert_eq!(u8::from_json(&json!(300)), None);
+        assert_eq!(u64::from_json(&json!(-1)), None);
+        assert_eq!(u64::from_json(&json!("-1")), None);
+        assert_eq!(i64::from_json(&json!("-1")), Some(-1));
+        assert_eq!(u64::from_json(&json!(" 42 ")), Some(42));
+    }
+
+    #[test]
+    fn float_values() {
+        assert_eq!(parse_all::<f64>(), vec![Some(123.0), Some(123.0), Some(0.0), Some(0.0), None, Some(1.0)]);
+        assert_eq!(parse_all::<f32>(), vec![Some(123.0), Some(123.0), Some(0.0), Some(0.0), None, Some(1.0)]);
+        assert_eq!(f64::from_json(&json!("4.5")), Some(4.5));
+    }
+
+    #[test]
+    fn bool_values() {
+        assert_eq!(parse_all::<bool>(), vec![None, None, Some(false), Some(false), Some(true), Some(true)]);
+        assert_eq!(bool::from_json(&json!(0)), Some(false));
+        assert_eq!(bool::from_json(&json!("false")), Some(false));
+        assert_eq!(bool::from_json(&json!(false)), Some(false));
+    }
+
+    #[test]
+    fn string_values() {
+        let expected = ["123", "123", "", "", "true", "1"];
+        assert_eq!(
+            parse_all::<String>(),
+            expected.iter().map(|text| Some(text.to_string())).collect::<Vec<_>>()
+        );
+        assert_eq!(String::from_json(&json!(true)), Some("true".to_owned()));
+        assert_eq!(String::from_json(&json!({})), None);
+    }
+
+    #[test]
+    fn option_values() {
+        assert_eq!(
+            parse_all::<Option<u64>>(),
+            vec![Some(Some(123)), Some(Some(123)), Some(None), Some(None), None, Some(Some(1))]
+        );
+        assert_eq!(
+            parse_all::<Option<bool>>(),
+            vec![None, None, Some(None), Some(None), Some(Some(true)), Some(Some(true))]
+        );
+        assert_eq!(
+            parse_all::<Option<String>>(),
+            vec![
+                Some(Some("123".to_owned())),
+                Some(Some("123".to_owned())),
+                Some(None),
+                Some(None),
+                Some(Some("true".to_owned())),
+                Some(Some("1".to_owned())),
+            ]
+        );
+    }
+
+    #[derive(Deserialize, Serialize, Debug, PartialEq)]
+    struct Fields {
+        #[serde(with = "super")]
+        count: u64,
+        #[serde(default, with = "super")]
+        tid: Option<u64>,
+    }
+
+    #[test]
+    fn deserializes_fields() {
+        let fields: Fields = serde_json::from_str(r#"{"count": "7"}"#).unwrap();
+        assert_eq!(fields, Fields { count: 7, tid: None });
+        assert_eq!(serde_json::to_string(&fields).unwrap(), r#"{"count":7,"tid":null}"#);
+
+        let err = serde_json::from_str::<Fields>(r#"{"count": "sieben"}"#).unwrap_err();
+        assert!(err.to_string().contains("invalid value \"sieben\", expected a u64"), "{}", err);
+    }
+
+    #[test]
+    fn season_year_as_string() {
+        let season = r#"{"id": "1", "eid": "53", "type": "start", "year": "2017", "season": "2"}"#;
+        let season: Season = serde_json::from_str(season).unwrap();
+        assert_eq!((season.year, season.season), (2017, 2));
+    }
+
+    #[test]
+    fn news_image_id_stays_text() {
+        let news = json!({
+            "nid": "7", "time": "1500000000", "mid": "9", "description": "", "image_id": "a1b2c3",
+            "image_style": "", "subject": "", "hits": "0", "thread": "9", "uid": "1", "uname": "genesis",
+            "posts": "0", "catid": "1", "catname": "News",
+        });
+        let news: News = serde_json::from_value(news).unwrap();
+        assert_eq!(news.image_id, "a1b2c3");
+    }
+
+    #[test]
+    fn news_notification_image_id_stays_text() {
+        let news = r#"{"error": "0", "notifications": [{
+            "nid": "7", "time": "1500000000", "description": "", "image_id": "a1b2c3", "image_style": "",
+            "subject": "", "hits": "0", "thread": "9", "uid": "1", "uname": "genesis", "posts": "0",
+            "catid": "1", "catname": "News"
+        }]}"#;
+        let news = ScriptedTransport::new().json(news).proxer().get_news().unwrap();
+
+        assert_eq!(news[0].image_id, "a1b2c3");
+        assert_eq!(news[0].get_image(), "http://cdn.proxer.me/news/7_a1b2c3.png");
+    }
+
+    #[test]
+    fn parses_lowercase_kategorie_and_medium() {
+        let kategorien: Vec<Kategorie> = serde_json::from_value(json!(["anime", "manga"])).unwrap();
+        assert_eq!(kategorien, vec![Kategorie::Anime, Kategorie::Manga]);
+
+        let media: Vec<Medium> = serde_json::from_value(json!([
+            "animeseries",
+            "movie",
+            "ova",
+            "hentai",
+            "mangaseries",
+            "oneshot",
+            "doujin",
+            "hmanga"
+        ]))
+        .unwrap();
+        assert_eq!(
+            media,
+            vec![
+                Medium::Animeseries,
+                Medium::Movie,
+                Medium::OVA,
+                Medium::Hentai,
+                Medium::Mangaseries,
+                Medium::Oneshot,
+                Medium::Doujin,
+                Medium::Hmanga,
+            ]
+        );
+    }
+}
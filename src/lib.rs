@@ -3,7 +3,7 @@
 //! Die meisten Dokumentationen sind aus dem Proxer-API wiki.
 //! Sollte etwas in der crate fehlen, oder anderweitig nicht stimmen, bitte auf Github melden.
 //!
-//! Aktuell unterstützte Rust Version >= 1.15
+//! Aktuell unterstützte Rust Version >= 1.70
 //!
 //! # Auszug aus dem Proxer-API wiki
 //!
@@ -12,16 +12,16 @@
 //!
 //! ##Nutzungsbedingungen
 //! * Die Anwendung muss ausdrücklich als "inoffizielle Anwendung"
-//! deklariert werden (z.B. beim Starten der Anwendung in Form eines Dialogs,
-//! in der Anwendungsbeschreibung, im Impressum und/oder in den Credits).
+//!   deklariert werden (z.B. beim Starten der Anwendung in Form eines Dialogs,
+//!   in der Anwendungsbeschreibung, im Impressum und/oder in den Credits).
 //! * Die Anzahl der Anfragen an den Proxer-Server muss so gering gehalten werden wie nur möglich.
-//! *Auf die Sicherheit der Anwendung muss stark geachtet werden.
-//! Falls möglich, soll immer SSL zur Verschlüsselung der Kommunikation verwendet werden.
-//! Gemeldete Sicherheitsprobleme müssen in kürzester Zeit behoben werden.
+//! * Auf die Sicherheit der Anwendung muss stark geachtet werden.
+//!   Falls möglich, soll immer SSL zur Verschlüsselung der Kommunikation verwendet werden.
+//!   Gemeldete Sicherheitsprobleme müssen in kürzester Zeit behoben werden.
 //! * In besonderen Fällen kann genesis zusätzliche Forderungen stellen, die dann ebenfalls eingehalten werden müssen.
 //! * Die Nutzungsbedingungen könnten sich jederzeit ändern.
-//! Nach einer Änderung müssen die Änderungen innerhalb von 30 Tagen umgesetzt werden.
-//! Alle Änderungen werden in der Mailing-List erläutert.
+//!   Nach einer Änderung müssen die Änderungen innerhalb von 30 Tagen umgesetzt werden.
+//!   Alle Änderungen werden in der Mailing-List erläutert.
 //!
 //! #Finanzielles
 //! Nehmen wir an, es gibt eine "Kodi" Anwendung.
@@ -35,48 +35,48 @@
 //!
 //! ## Verwaltung
 //! * `Mailing-List`
-//! Der Eintrag in der Mailing-List ist freiwillig.
-//! Du kannst dich in unsere Mailing-List eintragen lassen um per Proxer-PN über eine Änderung an den Nutzungsbedingungen
-//! oder der API benachrichtigt zu werden.
-//! Schreibe hierfür eine kurze PN an genesis mit dem Inhalt: "Ich möchte in der API-Mailingliste eingetragen werden".
-//! Wir laden dich dann in eine Proxer-Gruppe ein.
+//!   Der Eintrag in der Mailing-List ist freiwillig.
+//!   Du kannst dich in unsere Mailing-List eintragen lassen um per Proxer-PN über eine Änderung an den Nutzungsbedingungen
+//!   oder der API benachrichtigt zu werden.
+//!   Schreibe hierfür eine kurze PN an genesis mit dem Inhalt: "Ich möchte in der API-Mailingliste eingetragen werden".
+//!   Wir laden dich dann in eine Proxer-Gruppe ein.
 //! * `Open Source`
-//! Open-Source Projekte haben die Möglichkeit, "mehr" als nur eine inoffizielle Anwendung zu bleiben.
-//! Falls das Projekt z.B. auf github oder einer anderen Versionsverwaltung gehostet ist, besteht die Möglichkeit,
-//! dass wir die Anwendung zu unseren offiziellen Anwendungen aufnehmen.
-//! Offizielle Anwendungen werden wir beispielsweise auf unserer Facebook-Seite empfehlen und aktiv unterstützen.
+//!   Open-Source Projekte haben die Möglichkeit, "mehr" als nur eine inoffizielle Anwendung zu bleiben.
+//!   Falls das Projekt z.B. auf github oder einer anderen Versionsverwaltung gehostet ist, besteht die Möglichkeit,
+//!   dass wir die Anwendung zu unseren offiziellen Anwendungen aufnehmen.
+//!   Offizielle Anwendungen werden wir beispielsweise auf unserer Facebook-Seite empfehlen und aktiv unterstützen.
 //! * `Support`
-//! Proxer stellt für den Support deiner Anwendung eine Forum-Kategorie zur Verfügung.
-//! Hier kannst du für deine Anwendung einen Thread erstellen, welcher für Fragen, Vorschläge, Probleme und Kritik offen steht.
-//! In den ersten Beitrag beschreibst du deine Anwendung, postest dein Changelog,
-//! wie man deine Anwendung benutzt und ggf. weitere Links wie z.B. zum git-repository.
-//! Werbung für deine private Webseite sind strengstens untersagt und ist gegen die Forumsregeln.
-//! Für sonstige Diskussionen und initiierung neuer Projekte haben wir ebenfalls eine eigene Kategorie.
+//!   Proxer stellt für den Support deiner Anwendung eine Forum-Kategorie zur Verfügung.
+//!   Hier kannst du für deine Anwendung einen Thread erstellen, welcher für Fragen, Vorschläge, Probleme und Kritik offen steht.
+//!   In den ersten Beitrag beschreibst du deine Anwendung, postest dein Changelog,
+//!   wie man deine Anwendung benutzt und ggf. weitere Links wie z.B. zum git-repository.
+//!   Werbung für deine private Webseite sind strengstens untersagt und ist gegen die Forumsregeln.
+//!   Für sonstige Diskussionen und initiierung neuer Projekte haben wir ebenfalls eine eigene Kategorie.
 //!
 //! ## Datenschutz
 //! * `Passwörter`
-//! Eingegebene Passwörter sollten stets entweder in geschützten Speicherbereichen
-//! (z.B. SharedPreferences in Android) und/oder verschlüsselt gespeichert werden.
+//!   Eingegebene Passwörter sollten stets entweder in geschützten Speicherbereichen
+//!   (z.B. SharedPreferences in Android) und/oder verschlüsselt gespeichert werden.
 //! * `Statistiken`
-//! Du darfst gerne Google Analytics verwenden, wir empfehlen jedoch die
-//! datenschutzfreundliche(https://www.datenschutzbeauftragter-info.de/fachbeitraege/google-analytics-datenschutzkonform-einsetzen/)
-//! Version zu verwenden.
+//!   Du darfst gerne Google Analytics verwenden, wir empfehlen jedoch die
+//!   datenschutzfreundliche(https://www.datenschutzbeauftragter-info.de/fachbeitraege/google-analytics-datenschutzkonform-einsetzen/)
+//!   Version zu verwenden.
 //! * `Transparenz`
-//! Deine Anwendung sollte möglichst transparent arbeiten und keine dubiosen Aktionen im Hintergrund durchführen.
-//! Wir werden in bestimmten Abständen Anwendungen prüfen
-//! und bei festgestelltem Missbrauch der Schnittstelle die Erlaubnis zur Nutzung der Anwendung entziehen.
+//!   Deine Anwendung sollte möglichst transparent arbeiten und keine dubiosen Aktionen im Hintergrund durchführen.
+//!   Wir werden in bestimmten Abständen Anwendungen prüfen
+//!   und bei festgestelltem Missbrauch der Schnittstelle die Erlaubnis zur Nutzung der Anwendung entziehen.
 //!
 //! ## Technische Informationen
 //! * `Ressourcen`
-//! Wir bitten ausdrücklich, sparsam mit unseren Ressourcen umzugehen.
-//! Das ist sehr wichtig, da unsere Server besonders an Wochenenden an ihren Grenzen sind.
-//! Um dies zu erzielen, muss darauf geachtet werden, dass keine Anfrage unnötig gesendet wird.
-//! Im Falle einer Anwendung, die darstellt ob eine neue Episode eines Animes erschienen ist,
-//! würde es beispielsweise ausreichen, wenn die Abfrage höchstens alle 30 Minuten durchgeführt wird.
+//!   Wir bitten ausdrücklich, sparsam mit unseren Ressourcen umzugehen.
+//!   Das ist sehr wichtig, da unsere Server besonders an Wochenenden an ihren Grenzen sind.
+//!   Um dies zu erzielen, muss darauf geachtet werden, dass keine Anfrage unnötig gesendet wird.
+//!   Im Falle einer Anwendung, die darstellt ob eine neue Episode eines Animes erschienen ist,
+//!   würde es beispielsweise ausreichen, wenn die Abfrage höchstens alle 30 Minuten durchgeführt wird.
 //! * `Firewall`
-//! Proxer verwendet Cloudflare, um im Falle eines Ddos-Angriffes die Aufrechterhaltung unserer Dienste zu gewährleisten.
-//! Aktuell besteht leider keine Möglichkeit, diese zu umgehen.
-//! Fehlerhafte Anfragen sollten daher abgefangen werden, damit die Anwendung im Falle eines Angriffes nicht abstürzt.
+//!   Proxer verwendet Cloudflare, um im Falle eines Ddos-Angriffes die Aufrechterhaltung unserer Dienste zu gewährleisten.
+//!   Aktuell besteht leider keine Möglichkeit, diese zu umgehen.
+//!   Fehlerhafte Anfragen sollten daher abgefangen werden, damit die Anwendung im Falle eines Angriffes nicht abstürzt.
 //!
 //! ## Dokumentation der Schnittstellen
 //! Im Juni 2016 wurde eine Neuentwicklung der Schnittstellen durchgeführt.
@@ -92,44 +92,89 @@
 
 #![doc(html_logo_url = "", html_favicon_url = "")]
 
-#[macro_use] extern crate hyper;
+extern crate hyper;
 extern crate hyper_native_tls;
+extern crate serde;
 #[macro_use] extern crate serde_derive;
 extern crate serde_json;
+extern crate url;
 #[macro_use] extern crate log;
 
 #[macro_use] mod macros;
 pub mod error;
 pub mod models;
 pub mod anime;
+#[cfg(feature = "async")]
+pub mod asynchronous;
+pub mod batch;
+pub mod cache;
+pub mod cassette;
+pub mod endpoint;
+#[cfg(feature = "fake-server")]
+pub mod fake_server;
 pub mod info;
+pub mod lenient;
 pub mod list;
 pub mod manga;
 pub mod media;
 pub mod messenger;
+pub mod middleware;
 pub mod notification;
+pub mod paginator;
+pub mod params;
+pub mod ratelimit;
+pub mod retry;
+pub mod transport;
 pub mod ucp;
 pub mod user;
+#[cfg(test)]
+mod testing;
 
-use hyper::client::Client;
-use hyper::header::{ Headers, ContentType, UserAgent };
-use hyper::net::HttpsConnector;
-use hyper_native_tls::NativeTlsClient;
+use std::fmt;
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::{Duration, Instant};
+
+use serde::de::DeserializeOwned;
 
 use ::error::*;
+use batch::{Batch, InFlight};
+use cache::ResponseCache;
+use endpoint::Endpoint;
+use middleware::{Middleware, RequestInfo, ResponseInfo};
+use models::EmptyResponse;
+use paginator::{Paged, Paginator};
+use params::Params;
+use ratelimit::RateLimiter;
+use retry::RetryPolicy;
+use transport::{HyperConfig, HyperTransport, Request, Transport};
+use anime::Anime;
+use info::Info;
+use list::List;
+use manga::Manga;
+use media::Media;
+use messenger::Messenger;
+use notification::Notification;
+use ucp::Ucp;
+use user::{CredentialProvider, User};
 
-static BASE_URL: &'static str = "https://proxer.me/api";
-static API_VERSION: &'static str = "v1";
-static USER_AGENT: &'static str = concat!("proxer-rs (https://github.com/souryo/proxer-rs, ",
+static BASE_URL: &str = "https://proxer.me/api";
+static API_VERSION: &str = "v1";
+static USER_AGENT: &str = concat!("proxer-rs (https://github.com/souryo/proxer-rs, ",
     env!("CARGO_PKG_VERSION"), ")");
 
-static NEWS_URL: &'static str = "http://proxer.me/notifications?format=json&s=news&p=1";
+static API_KEY_HEADER: &str = "proxer-api-key";
+static LOGIN_TOKEN_HEADER: &str = "proxer-api-token";
+
+static DISCLAIMER: &str = "proxer-rs ist eine inoffiziell Bibliothek!";
+
+static NEWS_URL: &str = "http://proxer.me/notifications?format=json&s=news&p=1";
 
 /// Ermöglicht den Zugriff auf die Proxer News. Hierbei werden pro Seite höchstens 15 News ausgegeben.
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct ProxerNews
 {
     /// 0 (erfolgreich) oder 1.
+    #[serde(with = "lenient")]
     pub error: u64,
     /// Eine Meldung im Falle eines Fehlers.
     pub message: Option<String>,
@@ -164,27 +209,35 @@ pub struct ProxerNews
 pub struct NewsNotification
 {
     /// News id
+    #[serde(with = "lenient")]
     pub nid: u64,
     /// Time als Unix-Timestamp
+    #[serde(with = "lenient")]
     pub time: i64,
     /// Die Beschreibung
     pub description: String,
     /// Die Bild id
-    pub image_id: u64,
+    #[serde(with = "lenient")]
+    pub image_id: String,
     /// Bildstyles
     pub image_style: String,
     pub subject: String,
     /// Aufrufe der Notification
+    #[serde(with = "lenient")]
     pub hits: u64,
     /// thread id
+    #[serde(with = "lenient")]
     pub thread: u64,
     /// Die Benutzer-ID des Autors.
+    #[serde(with = "lenient")]
     pub uid: u64,
     /// Der Benutzername des Autors.
     pub uname: String,
     /// Anzahl der Antworten/Kommentare auf die News.
+    #[serde(with = "lenient")]
     pub posts: u64,
     /// Die Kategorie-ID der Kategorie, in der sich die News befindet.
+    #[serde(with = "lenient")]
     pub catid: u64,
     /// Der Name der Kategorie, in der sich die News befindet.
     pub catname: String,
@@ -214,39 +267,180 @@ impl NewsNotification
 }
 
 /// Klasse um die http/s Verbindungen aufzubauen und die API-Daten zu verwalten.
-#[derive(Debug)]
+///
+/// Ein `Proxer` kann günstig geklont werden, alle Klone teilen sich Verbindung, Login-Token,
+/// Zwischenspeicher und Rate-Limit. Er ist `Send + Sync` und kann daher von beliebig vielen
+/// Threads gleichzeitig verwendet werden.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::thread;
+/// use proxer::Proxer;
+///
+/// let proxer = Proxer::new("api-key").unwrap();
+/// let workers: Vec<_> = vec![53, 1217].into_iter().map(|id| {
+///     let info = proxer.info();
+///     thread::spawn(move || info.get_entry(id).map(|entry| entry.name))
+/// }).collect();
+/// for worker in workers {
+///     println!("{:?}", worker.join().unwrap());
+/// }
+/// ```
+#[derive(Clone)]
 pub struct Proxer
 {
-    client: hyper::Client,
-    header: hyper::header::Headers,
+    inner: Arc<ProxerInner>,
+}
+
+struct ProxerInner
+{
+    transport: Box<dyn Transport>,
+    header: Vec<(String, String)>,
+    base_url: String,
+    api_version: String,
+    login_token: RwLock<Option<String>>,
+    relogin: Option<Box<dyn CredentialProvider>>,
+    relogin_lock: Mutex<()>,
+    retry: Option<RetryPolicy>,
+    rate_limit: Option<RateLimiter>,
+    cache: Option<ResponseCache>,
+    middleware: Vec<Box<dyn Middleware>>,
+    in_flight: InFlight,
+}
+
+impl fmt::Debug for Proxer
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result
+    {
+        f.debug_struct("Proxer")
+            .field("header", &transport::redact_headers(&self.inner.header))
+            .field("base_url", &self.inner.base_url)
+            .field("api_version", &self.inner.api_version)
+            .field("logged_in", &self.is_logged_in())
+            .field("relogin", &self.inner.relogin.is_some())
+            .field("retry", &self.inner.retry)
+            .field("rate_limit", &self.inner.rate_limit)
+            .field("cache", &self.inner.cache.as_ref().map(ResponseCache::stats))
+            .field("middleware", &self.inner.middleware.len())
+            .finish()
+    }
 }
 
-// TODO use ssl/tls
 impl Proxer
 {
     /// Erstellt eine Proxer-Sitzung mit dem angegebenen API-Key.
     pub fn new(api_key: &str)
     -> Result<Self>
     {
-        println!("proxer-rs ist eine inoffiziell Bibliothek!");
+        Proxer::builder(api_key).build()
+    }
 
-        let mut header = Headers::new();
-        header.set(ContentType::form_url_encoded());
-        header.set(UserAgent(USER_AGENT.to_owned()));
+    /// Erstellt eine Proxer-Sitzung mit dem angegebenen API-Key, die alle Anfragen über `transport` sendet.
+    pub fn with_transport<T>(api_key: &str, transport: T)
+    -> Result<Self>
+        where T: Transport + 'static
+    {
+        Proxer::builder(api_key).transport(transport).build()
+    }
 
-        header!{ (ProxerApiToken, "proxer-api-token") => [String] };
-        header.set(ProxerApiToken(api_key.to_owned()));
-        // TODO remove unwrap()
-        let ssl = NativeTlsClient::new().unwrap();
-        let connector = HttpsConnector::new(ssl);
-        let client = Client::with_connector(connector);
+    /// Erstellt einen `ProxerBuilder`, um die Proxer-Sitzung genauer einzustellen.
+    pub fn builder(api_key: &str)
+    -> ProxerBuilder
+    {
+        ProxerBuilder::new(api_key)
+    }
 
-        let proxer = Proxer
-        {
-            client: client,
-            header: header,
-        };
-        Ok(proxer)
+    /// Das Login-Token, das bei jeder Anfrage mitgesendet wird.
+    /// Wird von `User::login` gesetzt und von `User::logout` entfernt.
+    pub fn login_token(&self)
+    -> Option<String>
+    {
+        self.inner.login_token.read().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    /// Setzt oder entfernt das Login-Token, das bei jeder Anfrage mitgesendet wird.
+    pub fn set_login_token(&self, token: Option<String>)
+    {
+        *self.inner.login_token.write().unwrap_or_else(|e| e.into_inner()) = token;
+    }
+
+    /// Ob aktuell ein Login-Token gesetzt ist.
+    pub fn is_logged_in(&self)
+    -> bool
+    {
+        self.inner.login_token.read().unwrap_or_else(|e| e.into_inner()).is_some()
+    }
+
+    /// Der mit `ProxerBuilder::cache` gesetzte Zwischenspeicher, z.B. um die Statistiken abzufragen.
+    pub fn cache(&self)
+    -> Option<&ResponseCache>
+    {
+        self.inner.cache.as_ref()
+    }
+
+    /// Die Schnittstellen der Klasse "anime".
+    pub fn anime(&self)
+    -> Anime
+    {
+        Anime::new(self)
+    }
+
+    /// Die Schnittstellen der Klasse "info".
+    pub fn info(&self)
+    -> Info
+    {
+        Info::new(self)
+    }
+
+    /// Die Schnittstellen der Klasse "list".
+    pub fn list(&self)
+    -> List
+    {
+        List::new(self)
+    }
+
+    /// Die Schnittstellen der Klasse "manga".
+    pub fn manga(&self)
+    -> Manga
+    {
+        Manga::new(self)
+    }
+
+    /// Die Schnittstellen der Klasse "media".
+    pub fn media(&self)
+    -> Media
+    {
+        Media::new(self)
+    }
+
+    /// Die Schnittstellen der Klasse "messenger".
+    pub fn messenger(&self)
+    -> Messenger
+    {
+        Messenger::new(self)
+    }
+
+    /// Die Schnittstellen der Klasse "notifications".
+    pub fn notification(&self)
+    -> Notification
+    {
+        Notification::new(self)
+    }
+
+    /// Die Schnittstellen der Klasse "ucp".
+    pub fn ucp(&self)
+    -> Ucp
+    {
+        Ucp::new(self)
+    }
+
+    /// Loggt einen User ein und liefert die Schnittstellen der Klasse "user" für ihn. Siehe `User::login`.
+    pub fn login(&self, username: &str, password: &str, secret_key: Option<&str>)
+    -> Result<User>
+    {
+        let login = User::login(self, username, password, secret_key)?;
+        Ok(User::from_login(self, login))
     }
 
     /// Funktion um, über die alte? News API, News abzurufen.
@@ -254,20 +448,569 @@ impl Proxer
     pub fn get_news(&self)
     -> Result<Vec<NewsNotification>>
     {
-        let url = NEWS_URL;
-        let result = self.connect(&url, "")?;
-        let data: ProxerNews = serde_json::from_reader(result)?;
+        self.check_offline("notifications", "news", false)?;
+        let params = Params::new();
+        let request = RequestInfo
+        {
+            api_class: "notifications",
+            api_function: "news",
+            params: &params,
+            mutating: false,
+        };
+        let result = self.send_retrying(&request, &self.request(NEWS_URL, ""))?;
+        let data: ProxerNews = serde_json::from_slice(&result)?;
         check_error!(data.error, 0, data.message.unwrap_or_default());
         check_data!(data.notifications)
     }
 
-    // TODO try to remove clone() call, if possible
-    fn connect(&self, url: &str, body: &str)
-    -> Result<hyper::client::Response>
+    /// Sendet die API-Funktion `endpoint` und liefert ihre Daten.
+    ///
+    /// Alle Methoden von `Info`, `List` usw. laufen hierüber, Zwischenspeicher,
+    /// Rate-Limit, Retry-Policy und der automatische erneute Login greifen also auch hier.
+    /// Benötigt die Funktion einen eingeloggten User und ist keiner eingeloggt,
+    /// wird ohne Anfrage `Error::LoginRequired` zurückgegeben (bzw. zuvor neu eingeloggt, siehe `ProxerBuilder::relogin`).
+    pub fn execute<E>(&self, endpoint: &E)
+    -> Result<E::Output>
+        where E: Endpoint
+    {
+        if endpoint.needs_login() && !self.is_logged_in()
+        {
+            if self.inner.relogin.is_none()
+            {
+                return Err(Error::LoginRequired);
+            }
+            self.relogin(None)?;
+        }
+        let response = self.connect(endpoint.api_class(), endpoint.api_function(), &endpoint.params(),
+            endpoint.mutates())?;
+        endpoint.parse(&response)
+    }
+
+    /// Sendet eine beliebige API-Funktion, auch wenn sie (noch) nicht als `Endpoint` modelliert ist.
+    ///
+    /// `params` werden wie bei den übrigen Funktionen als Formular gesendet, Fehlercodes
+    /// des Servers werden wie gewohnt als `Error::ProxerError` zurückgegeben.
+    /// Geliefert wird der Inhalt von `data`, bzw. `Value::Null`, wenn die Antwort keine Daten enthält.
+    /// Ob die Funktion Daten verändert, wird anhand ihres Namens geschätzt (`set*`, `delete*`, `new*`).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use proxer::Proxer;
+    ///
+    /// let proxer = Proxer::new("api-key").unwrap();
+    /// let entry = proxer.call_raw("info", "entry", &[("id", "53")]).unwrap();
+    /// println!("{}", entry["name"]);
+    /// ```
+    pub fn call_raw(&self, api_class: &str, api_function: &str, params: &[(&str, &str)])
+    -> Result<serde_json::Value>
     {
-        Ok(self.client.post(url)
-            .headers(self.header.clone())
-            .body(body)
-            .send()?)
+        self.call_as(api_class, api_function, params)
+    }
+
+    /// Wie `call_raw`, liest `data` aber direkt in den Typ `T`.
+    pub fn call_as<T>(&self, api_class: &str, api_function: &str, params: &[(&str, &str)])
+    -> Result<T>
+        where T: DeserializeOwned
+    {
+        let mutating = Proxer::is_mutating(api_class, api_function);
+        let response = self.connect(api_class, api_function, &Params::from(params), mutating)?;
+        endpoint::parse_response(&response)
+    }
+
+    /// Führt `endpoints` parallel aus. Siehe `Batch`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use proxer::Proxer;
+    /// use proxer::info::GetEntry;
+    ///
+    /// let proxer = Proxer::new("api-key").unwrap();
+    /// let entries = proxer.batch((1..=50).map(|id| GetEntry { id })).concurrency(8).run();
+    /// assert_eq!(entries.len(), 50);
+    /// ```
+    pub fn batch<E, I>(&self, endpoints: I)
+    -> Batch<E>
+        where E: Endpoint + Sync, E::Output: Send, I: IntoIterator<Item = E>
+    {
+        Batch::new(self, endpoints)
+    }
+
+    /// Lädt die Einträge von `endpoint` seitenweise. Siehe `Paginator`.
+    pub fn paginate<E>(&self, endpoint: E)
+    -> Paginator<E>
+        where E: Paged
+    {
+        Paginator::new(self, endpoint)
+    }
+
+    fn connect(&self, api_class: &str, api_function: &str, params: &Params, mutating: bool)
+    -> Result<Vec<u8>>
+    {
+        let body = &params.encode();
+        let token = self.login_token();
+        if let (Some(cache), false) = (self.inner.cache.as_ref(), mutating)
+        {
+            if let Some(response) = cache.get(api_class, api_function, body, token.as_deref())
+            {
+                return Ok(response);
+            }
+        }
+        self.check_offline(api_class, api_function, mutating)?;
+
+        let fetch = ||
+        {
+            let url = format!("{}/{}/{}/{}", self.inner.base_url, self.inner.api_version, api_class, api_function);
+            let redacted = params.redacted();
+            debug!("Requesting url: {:?} with {}", url, redacted);
+            let request = RequestInfo
+            {
+                api_class,
+                api_function,
+                params: &redacted,
+                mutating,
+            };
+            let mut response = self.send_retrying(&request, &self.request(&url, body))?;
+
+            if self.inner.relogin.is_some() && !(api_class == "user" && api_function == "login")
+                && Proxer::is_auth_error(&response)
+            {
+                self.relogin(token.clone())?;
+                response = self.send_retrying(&request, &self.request(&url, body))?;
+            }
+            Ok(response)
+        };
+        let response = if mutating
+        {
+            fetch()?
+        }
+        else
+        {
+            let key = (api_class.to_owned(), api_function.to_owned(), body.clone(), token.clone());
+            self.inner.in_flight.run(key, fetch)?
+        };
+
+        if let Some(ref cache) = self.inner.cache
+        {
+            if mutating
+            {
+                cache.invalidate_after(api_class, api_function);
+            }
+            else
+            {
+                cache.insert(api_class, api_function, body, self.login_token().as_deref(), &response);
+            }
+        }
+        Ok(response)
+    }
+
+    /// Sendet die API-Funktion `endpoint` mit dem Login-Token `token` statt dem hinterlegten.
+    ///
+    /// Das hinterlegte Token bleibt unverändert, Zwischenspeicher und erneuter Login werden umgangen.
+    pub(crate) fn execute_with_token<E>(&self, endpoint: &E, token: &str)
+    -> Result<E::Output>
+        where E: Endpoint
+    {
+        let (api_class, api_function) = (endpoint.api_class(), endpoint.api_function());
+        self.check_offline(api_class, api_function, endpoint.mutates())?;
+        let params = endpoint.params();
+        let url = format!("{}/{}/{}/{}", self.inner.base_url, self.inner.api_version, api_class, api_function);
+        let mut request = self.request(&url, &params.encode());
+        request.headers.retain(|header| header.0 != LOGIN_TOKEN_HEADER);
+        request.headers.push((LOGIN_TOKEN_HEADER.to_owned(), token.to_owned()));
+
+        let info = RequestInfo
+        {
+            api_class,
+            api_function,
+            params: &params.redacted(),
+            mutating: endpoint.mutates(),
+        };
+        endpoint.parse(&self.send_retrying(&info, &request)?)
+    }
+
+    /// Schlägt im Offline-Modus des Zwischenspeichers fehl, bevor eine Anfrage gesendet wird,
+    /// deren Antwort zwischengespeichert würde, aber nicht gespeichert ist.
+    /// Verändernde und nicht zwischengespeicherte Anfragen werden weiterhin gesendet.
+    fn check_offline(&self, api_class: &str, api_function: &str, mutating: bool)
+    -> Result<()>
+    {
+        match self.inner.cache
+        {
+            Some(ref cache) if cache.is_offline() && !mutating && cache.ttl_for(api_class, api_function).is_some()
+                => Err(Error::NotCached
+            {
+                api_class: api_class.to_owned(),
+                api_function: api_function.to_owned(),
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Ob die API-Funktion Daten auf dem Server verändert, geschätzt anhand ihres Namens.
+    fn is_mutating(api_class: &str, api_function: &str)
+    -> bool
+    {
+        match (api_class, api_function)
+        {
+            ("user", "login") | ("user", "logout") | ("messenger", "report") => true,
+            (_, function) => function.starts_with("set") || function.starts_with("delete")
+                || function.starts_with("new"),
+        }
+    }
+
+    fn is_auth_error(response: &[u8])
+    -> bool
+    {
+        Proxer::error_code(response).is_some_and(|code| code.is_auth_error())
+    }
+
+    /// Der Fehlercode einer Antwort, falls der Server einen gemeldet hat.
+    fn error_code(response: &[u8])
+    -> Option<ProxerErrorCode>
+    {
+        match serde_json::from_slice::<EmptyResponse>(response)
+        {
+            Ok(ref data) if data.error == 1 => Some(ProxerErrorCode::from(data.code.unwrap_or_default())),
+            _ => None,
+        }
+    }
+
+    /// Loggt den User mit den Daten des `CredentialProvider` erneut ein.
+    /// `rejected_token` ist das Token, das vom Server abgelehnt wurde.
+    fn relogin(&self, rejected_token: Option<String>)
+    -> Result<()>
+    {
+        let provider = match self.inner.relogin
+        {
+            Some(ref provider) => provider,
+            None => return Ok(()),
+        };
+        let _guard = self.inner.relogin_lock.lock().unwrap_or_else(|e| e.into_inner());
+        if self.login_token() != rejected_token
+        {
+            // Ein anderer Thread hat sich in der Zwischenzeit bereits neu eingeloggt.
+            return Ok(());
+        }
+
+        let credentials = provider.credentials().ok_or_else(||
+            Error::ReloginFailed(Box::new(Error::Other("No credentials available for relogin.".to_owned()))))?;
+        info!("Login token was rejected, logging in again as {:?}", credentials.username);
+        User::login(self, &credentials.username, &credentials.password, credentials.secret_key.as_deref())
+            .map(|_| ())
+            .map_err(|err| Error::ReloginFailed(Box::new(err)))
+    }
+
+    /// Sendet die Anfrage unter Beachtung von Rate-Limit und Retry-Policy.
+    /// Jeder einzelne Versuch verbraucht eine Anfrage aus dem Budget der API-Klasse.
+    fn send_retrying(&self, info: &RequestInfo, request: &Request)
+    -> Result<Vec<u8>>
+    {
+        let send = ||
+        {
+            if let Some(ref limiter) = self.inner.rate_limit
+            {
+                limiter.acquire(info.api_class)?;
+            }
+            self.send(info, request)
+        };
+        match self.inner.retry
+        {
+            Some(ref policy) => policy.run(&request.url, info.mutating, send),
+            None => send(),
+        }
+    }
+
+    /// Sendet die Anfrage einmal und ruft dabei die `Middleware`s auf.
+    fn send(&self, info: &RequestInfo, request: &Request)
+    -> Result<Vec<u8>>
+    {
+        for middleware in &self.inner.middleware
+        {
+            middleware.before_request(info);
+        }
+        let start = Instant::now();
+        let response = self.inner.transport.send(request);
+        let status = response.as_ref().ok().map(|response| response.status);
+        let result = response.and_then(transport::Response::into_json);
+
+        if !self.inner.middleware.is_empty()
+        {
+            let response = ResponseInfo
+            {
+                status,
+                error_code: match result
+                {
+                    Ok(ref response) => Proxer::error_code(response),
+                    Err(ref err) => err.proxer_code(),
+                },
+                latency: start.elapsed(),
+                error: result.as_ref().err(),
+            };
+            for middleware in &self.inner.middleware
+            {
+                middleware.after_response(info, &response);
+            }
+        }
+        result
+    }
+
+    fn request(&self, url: &str, body: &str)
+    -> Request
+    {
+        let mut headers = self.inner.header.clone();
+        if let Some(token) = self.login_token()
+        {
+            headers.push((LOGIN_TOKEN_HEADER.to_owned(), token));
+        }
+
+        Request
+        {
+            url: url.to_owned(),
+            headers,
+            body: body.to_owned(),
+        }
+    }
+}
+
+/// Stellt sicher, dass ein `Proxer` und die Klassen zwischen Threads geteilt werden können.
+#[allow(dead_code)]
+fn assert_send_sync()
+{
+    fn check<T: Send + Sync + Clone>() {}
+    check::<Proxer>();
+    check::<Anime>();
+    check::<Info>();
+    check::<List>();
+    check::<Manga>();
+    check::<Media>();
+    check::<Messenger>();
+    check::<Notification>();
+    check::<Ucp>();
+    check::<User>();
+}
+
+/// Stellt eine `Proxer`-Sitzung ein.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use proxer::Proxer;
+///
+/// let proxer = Proxer::builder("api-key")
+///     .user_agent("MeineApp/1.0 (proxer-rs)")
+///     .read_timeout(Duration::from_secs(10))
+///     .disclaimer(|text| println!("MeineApp: {}", text))
+///     .build()
+///     .unwrap();
+/// ```
+pub struct ProxerBuilder
+{
+    api_key: String,
+    base_url: String,
+    api_version: String,
+    user_agent: String,
+    config: HyperConfig,
+    transport: Option<Box<dyn Transport>>,
+    relogin: Option<Box<dyn CredentialProvider>>,
+    disclaimer: Box<dyn Fn(&str)>,
+    retry: Option<RetryPolicy>,
+    rate_limit: Option<RateLimiter>,
+    cache: Option<ResponseCache>,
+    middleware: Vec<Box<dyn Middleware>>,
+}
+
+impl fmt::Debug for ProxerBuilder
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result
+    {
+        f.debug_struct("ProxerBuilder")
+            .field("base_url", &self.base_url)
+            .field("api_version", &self.api_version)
+            .field("user_agent", &self.user_agent)
+            .field("config", &self.config)
+            .field("retry", &self.retry)
+            .field("rate_limit", &self.rate_limit)
+            .field("cache", &self.cache)
+            .field("middleware", &self.middleware.len())
+            .finish()
+    }
+}
+
+impl ProxerBuilder
+{
+    /// Erstellt einen `ProxerBuilder` mit den Standardwerten und dem angegebenen API-Key.
+    pub fn new(api_key: &str)
+    -> Self
+    {
+        ProxerBuilder
+        {
+            api_key: api_key.to_owned(),
+            base_url: BASE_URL.to_owned(),
+            api_version: API_VERSION.to_owned(),
+            user_agent: USER_AGENT.to_owned(),
+            config: HyperConfig::default(),
+            transport: None,
+            relogin: None,
+            disclaimer: Box::new(|text| println!("{}", text)),
+            retry: None,
+            rate_limit: None,
+            cache: None,
+            middleware: Vec::new(),
+        }
+    }
+
+    /// Die Basis-URL der API. Default: "https://proxer.me/api".
+    pub fn base_url(mut self, base_url: &str)
+    -> Self
+    {
+        self.base_url = base_url.trim_end_matches('/').to_owned();
+        self
+    }
+
+    /// Die zu verwendende API-Version. Default: "v1".
+    pub fn api_version(mut self, api_version: &str)
+    -> Self
+    {
+        self.api_version = api_version.to_owned();
+        self
+    }
+
+    /// Der User-Agent aller Anfragen. Laut Nutzungsbedingungen sollte hier der Name der Anwendung auftauchen.
+    pub fn user_agent(mut self, user_agent: &str)
+    -> Self
+    {
+        self.user_agent = user_agent.to_owned();
+        self
+    }
+
+    /// Wie lange höchstens auf den Verbindungsaufbau gewartet wird.
+    pub fn connect_timeout(mut self, timeout: Duration)
+    -> Self
+    {
+        self.config.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Wie lange höchstens auf eine Antwort des Servers gewartet wird.
+    pub fn read_timeout(mut self, timeout: Duration)
+    -> Self
+    {
+        self.config.read_timeout = Some(timeout);
+        self
+    }
+
+    /// Sendet alle Anfragen über den angegebenen HTTP-Proxy.
+    pub fn proxy(mut self, host: &str, port: u16)
+    -> Self
+    {
+        self.config.proxy = Some((host.to_owned(), port));
+        self
+    }
+
+    /// Wird beim Erstellen der Sitzung mit dem Hinweis aufgerufen, dass es sich um eine inoffizielle Bibliothek handelt.
+    /// Default: Ausgabe auf stdout.
+    pub fn disclaimer<F>(mut self, disclaimer: F)
+    -> Self
+        where F: Fn(&str) + 'static
+    {
+        self.disclaimer = Box::new(disclaimer);
+        self
+    }
+
+    /// Sendet alle Anfragen über `transport` statt über einen `HyperTransport`.
+    /// Timeouts und Proxy werden dann nicht beachtet.
+    pub fn transport<T>(mut self, transport: T)
+    -> Self
+        where T: Transport + 'static
+    {
+        self.transport = Some(Box::new(transport));
+        self
+    }
+
+    /// Loggt den User automatisch neu ein, wenn der Server das Login-Token ablehnt
+    /// (siehe `ProxerErrorCode::is_auth_error`), und wiederholt die fehlgeschlagene Anfrage einmal.
+    /// Schlägt der erneute Login fehl, wird `Error::ReloginFailed` zurückgegeben.
+    pub fn relogin<P>(mut self, provider: P)
+    -> Self
+        where P: CredentialProvider + 'static
+    {
+        self.relogin = Some(Box::new(provider));
+        self
+    }
+
+    /// Wiederholt fehlgeschlagene Anfragen gemäß `policy`. Default: Jede Anfrage wird genau einmal gesendet.
+    pub fn retry(mut self, policy: RetryPolicy)
+    -> Self
+    {
+        self.retry = Some(policy);
+        self
+    }
+
+    /// Begrenzt die Anfragen pro API-Klasse mit `limiter`. Default: Keine Begrenzung.
+    pub fn rate_limit(mut self, limiter: RateLimiter)
+    -> Self
+    {
+        self.rate_limit = Some(limiter);
+        self
+    }
+
+    /// Speichert erfolgreiche Antworten in `cache` zwischen. Default: Kein Zwischenspeicher.
+    pub fn cache(mut self, cache: ResponseCache)
+    -> Self
+    {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Fügt eine `Middleware` hinzu, die vor und nach jeder Anfrage aufgerufen wird.
+    /// Mehrere `Middleware`s werden in der Reihenfolge aufgerufen, in der sie hinzugefügt wurden.
+    pub fn middleware<M>(mut self, middleware: M)
+    -> Self
+        where M: Middleware + 'static
+    {
+        self.middleware.push(Box::new(middleware));
+        self
+    }
+
+    /// Erstellt die Proxer-Sitzung.
+    /// Schlägt fehl, wenn TLS nicht initialisiert werden konnte.
+    pub fn build(self)
+    -> Result<Proxer>
+    {
+        let transport = match self.transport
+        {
+            Some(transport) => transport,
+            None => Box::new(HyperTransport::with_config(&self.config)?),
+        };
+
+        (self.disclaimer)(DISCLAIMER);
+
+        let header = vec![
+            ("Content-Type".to_owned(), "application/x-www-form-urlencoded".to_owned()),
+            ("User-Agent".to_owned(), self.user_agent),
+            (API_KEY_HEADER.to_owned(), self.api_key),
+        ];
+
+        Ok(Proxer
+        {
+            inner: Arc::new(ProxerInner
+            {
+                transport,
+                header,
+                base_url: self.base_url,
+                api_version: self.api_version,
+                login_token: RwLock::new(None),
+                relogin: self.relogin,
+                relogin_lock: Mutex::new(()),
+                retry: self.retry,
+                rate_limit: self.rate_limit,
+                cache: self.cache,
+                middleware: self.middleware,
+                in_flight: InFlight::default(),
+            }),
+        })
     }
 }
@@ -1,6 +1,7 @@
-use serde_json;
-
 use error::*;
+use lenient;
+use paginator::Paginator;
+use params::Params;
 use Proxer;
 use models::*;
 
@@ -8,6 +9,7 @@ use models::*;
 #[derive(Deserialize, Debug, Clone, PartialEq)]
 pub struct EntrySearch {
     /// Die ID des Entrys
+    #[serde(with = "lenient")]
     pub id: u64,
     /// Der Name des Entrys (Der selbe Entry kann mit unterschiedlichen Namen mehrfach auftreten)
     pub name: String,
@@ -16,6 +18,7 @@ pub struct EntrySearch {
     /// Das Medium des Entrys (animeseries,movie,ova,hentai,mangaseries,oneshot,doujin,hmanga)
     pub medium: Medium,
     /// Die Anzahl der Kapitel/Episoden (Müssen nicht hochgeladen sein!)
+    #[serde(with = "lenient")]
     pub count: u64,
     /// Ein Integer-Wert, der den Status des Entrys beschreibt
     /// 0: Nicht Erschienen (Pre-Airing)
@@ -23,10 +26,13 @@ pub struct EntrySearch {
     /// 2: Airing/Am Laufen
     /// 3: Abgebrochen
     /// 4: Abgeschlossen/Nicht fertiggesubbt
+    #[serde(with = "lenient")]
     pub state: u8,
     /// Die Summe aller Bewertungen
+    #[serde(with = "lenient")]
     pub rate_sum: u64,
     /// Die Anzahl der Bewertungen
+    #[serde(with = "lenient")]
     pub rate_count: u64,
     /// Die Sprachen, in denen der Entry verfügbar ist, als Komma-separierter String
     pub language: Vec<String>,
@@ -35,6 +41,7 @@ pub struct EntrySearch {
 #[derive(Deserialize, Debug, Clone, PartialEq)]
 pub struct EntryList {
     /// Die ID des Entrys
+    #[serde(with = "lenient")]
     pub id: u64,
     /// Der Name des Entrys (Der selbe Entry kann mit unterschiedlichen Namen mehrfach auftreten)
     pub name: String,
@@ -43,6 +50,7 @@ pub struct EntryList {
     /// Das Medium des Entrys (animeseries,movie,ova,hentai,mangaseries,oneshot,doujin,hmanga)
     pub medium: Medium,
     /// Die Anzahl der Kapitel/Episoden (Müssen nicht hochgeladen sein!)
+    #[serde(with = "lenient")]
     pub count: u64,
     /// Ein Integer-Wert, der den Status des Entrys beschreibt
     /// 0: Nicht Erschienen (Pre-Airing)
@@ -50,10 +58,13 @@ pub struct EntryList {
     /// 2: Airing/Am Laufen
     /// 3: Abgebrochen
     /// 4: Abgeschlossen/Nicht fertiggesubbt
+    #[serde(with = "lenient")]
     pub state: u64,
     /// Die Summe aller Bewertungen
+    #[serde(with = "lenient")]
     pub rate_sum: u64,
     /// Die Anzahl der Bewertungen
+    #[serde(with = "lenient")]
     pub rate_count: u64,
     /// Die Sprachen, in denen der Entry verfügbar ist, als Komma-separierter String
     pub language: Vec<String>,
@@ -72,6 +83,7 @@ pub struct TagIDs {
 #[derive(Deserialize, Debug, Clone, PartialEq)]
 pub struct Tag {
     /// Die ID des Tags
+    #[serde(with = "lenient")]
     pub id: u64,
     /// Der Typ des Tags (entry_genre,entry_tag,entry_tag_h,gallery)
     #[serde(rename = "type")]
@@ -81,6 +93,7 @@ pub struct Tag {
     /// Die Beschreibung des Tags
     pub description: String,
     /// Ob der Tag verwendet werden kann (Dieser Wert ist immer gleich 0, da geblacklistete Tags nicht ausgegeben werden)
+    #[serde(with = "lenient")]
     pub blacklist: u8,
     /// In welche Kategorie der Tag gehört. (Arten siehe Eingabeparameter)
     pub subtype: Kategorie,
@@ -90,6 +103,7 @@ pub struct Tag {
 #[derive(Deserialize, Debug, Clone, PartialEq)]
 pub struct TranslatorGroup {
     /// Die id der Gruppe
+    #[serde(with = "lenient")]
     pub id: u64,
     /// Der Name der Gruppe
     pub name: String,
@@ -103,6 +117,7 @@ pub struct TranslatorGroup {
 #[derive(Deserialize, Debug, Clone, PartialEq)]
 pub struct Industry {
     /// Die id der Gruppe
+    #[serde(with = "lenient")]
     pub id: u64,
     /// Der Typ der Firma (publisher, studio, producer, record_label, talent_agent, streaming)
     #[serde(rename = "type")]
@@ -119,6 +134,7 @@ pub struct Industry {
 #[derive(Deserialize, Debug, Clone, PartialEq)]
 pub struct TranslatorGroupProject {
     /// Die id des Entrys
+    #[serde(with = "lenient")]
     pub id: u64,
     /// Der Name des Entrys (Originalname)
     pub name: String,
@@ -137,10 +153,13 @@ pub struct TranslatorGroupProject {
     /// 2: Airing/Am Laufen
     /// 3: Abgebrochen
     /// 4: Abgeschlossen/Nicht fertiggesubbt
+    #[serde(with = "lenient")]
     pub state: u8,
     /// Die Summe aller Bewertungen
+    #[serde(with = "lenient")]
     pub rate_sum: u64,
     /// Die Anzahl der Bewertungen
+    #[serde(with = "lenient")]
     pub rate_count: u64,
 }
 
@@ -148,6 +167,7 @@ pub struct TranslatorGroupProject {
 #[derive(Deserialize, Debug, Clone, PartialEq)]
 pub struct IndustrysProject {
     /// Die id des Entrys
+    #[serde(with = "lenient")]
     pub id: u64,
     /// Der Name des Entrys (Originalname)
     pub name: String,
@@ -166,27 +186,240 @@ pub struct IndustrysProject {
     /// 2: Airing/Am Laufen
     /// 3: Abgebrochen
     /// 4: Abgeschlossen/Nicht fertiggesubbt
+    #[serde(with = "lenient")]
     pub state: u8,
     /// Die Summe aller Bewertungen
+    #[serde(with = "lenient")]
     pub rate_sum: u64,
     /// Die Anzahl der Bewertungen
+    #[serde(with = "lenient")]
     pub rate_count: u64,
 }
 
+endpoint! {
+    /// Die erweiterte Suche. Siehe `List::entry_search` für die erlaubten Werte.
+    #[derive(Default)]
+    pub struct SearchEntry -> Vec<EntrySearch> ["list", "entrysearch"] {
+        /// Ein zu suchender Entryname.
+        pub name: Option<String>,
+        /// Die zu suchende Sprache ("de", "en").
+        pub language: Option<String>,
+        /// Der zu suchende Typ.
+        pub medium_type: Option<Medium>,
+        /// Genre, die der Entry enthalten soll.
+        pub genre: Option<String>,
+        /// Genre, die der Entry nicht enthalten darf.
+        pub nogenre: Option<String>,
+        /// Die zu suchenden Gefahrensymbole/Altersbeschränkungen.
+        pub fsk: Option<String>,
+        /// Wie die Ergebnisse sortiert werden sollen.
+        pub sort: Option<SearchSort>,
+        /// Die Anzahl der Kapitel/Episoden, die ein Entry mindestens/höchstens haben darf.
+        pub length: Option<u64>,
+        /// Ob `length` als Minimal- oder Maximalwert verwendet werden soll.
+        pub lengthlimit: Option<LengthLimit>,
+        /// Tags, die der Entry enthalten soll.
+        pub tags: Option<String>,
+        /// Tags, die der Entry nicht enthalten darf.
+        pub notags: Option<String>,
+        /// Welche Art Tags berücksichtigt werden soll.
+        pub tagratefilter: Option<String>,
+        /// Inwieweit Spoiler-Tags berücksichtigt werden sollen.
+        pub tagspoilerfilter: Option<String>,
+        /// Die zu ladende Seite, Beginn bei 0, Default 0.
+        pub page: Option<u64>,
+        /// Wie viele Einträge eine Seite enthalten soll. Default 100.
+        pub limit: Option<u64>,
+    }
+    |this| Params::new()
+        .add("name", &this.name)
+        .add("language", &this.language)
+        .add("type", &this.medium_type)
+        .add("genre", &this.genre)
+        .add("nogenre", &this.nogenre)
+        .add("fsk", &this.fsk)
+        .add("sort", &this.sort)
+        .add("length", &this.length)
+        .add("length-limit", &this.lengthlimit)
+        .add("tags", &this.tags)
+        .add("notags", &this.notags)
+        .add("tagratefilter", &this.tagratefilter)
+        .add("tagspoilerfilter", &this.tagspoilerfilter)
+        .add("p", &this.page)
+        .add("limit", &this.limit)
+}
+
+paged!(SearchEntry => EntrySearch, 100);
+
+endpoint! {
+    /// Liefert alle Entrys einer Kategorie. Siehe `List::get_entrylist`.
+    pub struct GetEntryList -> Vec<EntryList> ["list", "entrylist"] {
+        /// Die Kategorie des Entrys. Default: Anime.
+        pub kat: Option<Kategorie>,
+        /// Welche Art Medium geladen werden soll.
+        pub medium: Option<Medium>,
+        /// Ob die Liste Hentais enthalten soll.
+        pub is_h: Option<bool>,
+        /// Mit welchem String der Name der Entrys beginnen soll.
+        pub start: Option<String>,
+        /// Die zu ladende Seite, Beginn bei 0, Default 0.
+        pub page: Option<u64>,
+        /// Wie viele Einträge eine Seite enthalten soll. Default 100.
+        pub limit: Option<u64>,
+    }
+    |this| Params::new()
+        .add("kat", &this.kat)
+        .add("medium", &this.medium)
+        .add("isH", &this.is_h)
+        .add("start", &this.start)
+        .add("p", &this.page)
+        .add("limit", &this.limit)
+}
+
+paged!(GetEntryList => EntryList, 100);
+
+endpoint! {
+    /// Liefert die IDs aller Tags in einem String. Siehe `List::get_tag_ids`.
+    pub struct GetTagIds -> TagIDs ["list", "tagids"] {
+        /// Ein beliebiger String mit durch Leerzeichen getrennten Tags.
+        pub search: String,
+    }
+    |this| Params::new().add("search", &this.search)
+}
+
+endpoint! {
+    /// Liefert eine Liste aller Tags. Siehe `List::get_tags`.
+    pub struct GetTags -> Vec<Tag> ["list", "tags"] {
+        /// Nur Tags, deren Name oder Beschreibung diesen String enthalten.
+        pub search: Option<String>,
+        /// Welcher Typ Tag angezeigt werden soll.
+        pub tag_type: Option<String>,
+        /// Nach welchem Ausgabeparameter die Liste sortiert werden soll.
+        pub sort: Option<String>,
+        /// Die Reihenfolge der Sortierung ("ASC", "DESC").
+        pub sort_type: Option<String>,
+        /// Die Kategorie des Tags.
+        pub sub_type: Option<SubType>,
+    }
+    |this| Params::new()
+        .add("search", &this.search)
+        .add("type", &this.tag_type)
+        .add("sort", &this.sort)
+        .add("sort_type", &this.sort_type)
+        .add("sub_type", &this.sub_type)
+}
+
+endpoint! {
+    /// Liefert eine Liste aller Sub/Scanlation Gruppen. Siehe `List::get_translatorgroups`.
+    pub struct GetTranslatorGroups -> Vec<TranslatorGroup> ["list", "translatorgroups"] {
+        /// Mit welchem String der Name der Gruppen beginnen soll.
+        pub start: Option<String>,
+        /// Nur Gruppen, deren Name diesen String enthält.
+        pub contains: Option<String>,
+        /// Die zu ladende Seite, Beginn bei 0, Default 0.
+        pub page: Option<u64>,
+        /// Wie viele Einträge eine Seite enthalten soll. Default 100.
+        pub limit: Option<u64>,
+    }
+    |this| Params::new()
+        .add("start", &this.start)
+        .add("contains", &this.contains)
+        .add("p", &this.page)
+        .add("limit", &this.limit)
+}
+
+paged!(GetTranslatorGroups => TranslatorGroup, 100);
+
+endpoint! {
+    /// Liefert eine Liste aller Firmen. Siehe `List::get_industrys`.
+    pub struct GetIndustrys -> Vec<Industry> ["list", "industrys"] {
+        /// Mit welchem String der Name der Firma beginnen soll.
+        pub start: Option<String>,
+        /// Nur Firmen, deren Name diesen String enthält.
+        pub contains: Option<String>,
+        /// Filtert Firmen nach Sprache ("de", "us", "jp", "misc").
+        pub country: Option<String>,
+        /// Filtert Firmen nach Typ.
+        pub firma_type: Option<Firma>,
+        /// Die zu ladende Seite, Beginn bei 0, Default 0.
+        pub page: Option<u64>,
+        /// Wie viele Einträge eine Seite enthalten soll. Default 100.
+        pub limit: Option<u64>,
+    }
+    |this| Params::new()
+        .add("start", &this.start)
+        .add("contains", &this.contains)
+        .add("country", &this.country)
+        .add("type", &this.firma_type)
+        .add("p", &this.page)
+        .add("limit", &this.limit)
+}
+
+paged!(GetIndustrys => Industry, 100);
+
+endpoint! {
+    /// Liefert die Projekte einer Gruppe. Siehe `List::get_translatorgroups_projects`.
+    pub struct GetTranslatorGroupProjects -> Vec<TranslatorGroupProject> ["list", "translatorgroupprojects"] {
+        /// Die ID der gewünschten Gruppe.
+        pub id: u64,
+        /// Nur Entrys mit diesem Übersetzungs-Status.
+        pub status_type: Option<TranslationStatus>,
+        /// Steuert die Ausgabe von H-Inhalten: -1 (kein H, Default), 0 (beides), 1 (nur H).
+        pub is_h: Option<i8>,
+        /// Die zu ladende Seite, Beginn bei 0, Default 0.
+        pub page: Option<u64>,
+        /// Wie viele Einträge eine Seite enthalten soll. Default 100.
+        pub limit: Option<u64>,
+    }
+    |this| Params::new()
+        .add("id", &this.id)
+        .add("type", &this.status_type)
+        .add("isH", &this.is_h)
+        .add("p", &this.page)
+        .add("limit", &this.limit)
+}
+
+paged!(GetTranslatorGroupProjects => TranslatorGroupProject, 100);
+
+endpoint! {
+    /// Liefert die Projekte einer Firma. Siehe `List::get_industry_projects`.
+    pub struct GetIndustryProjects -> Vec<IndustrysProject> ["list", "industryprojects"] {
+        /// Die ID der gewünschten Firma.
+        pub id: u64,
+        /// Filtert Entrys nach dem Typ der Firma.
+        pub firma_type: Option<Firma>,
+        /// Steuert die Ausgabe von H-Inhalten: -1 (kein H, Default), 0 (beides), 1 (nur H).
+        pub is_h: Option<i8>,
+        /// Die zu ladende Seite, Beginn bei 0, Default 0.
+        pub page: Option<u64>,
+        /// Wie viele Einträge eine Seite enthalten soll. Default 100.
+        pub limit: Option<u64>,
+    }
+    |this| Params::new()
+        .add("id", &this.id)
+        .add("type", &this.firma_type)
+        .add("isH", &this.is_h)
+        .add("p", &this.page)
+        .add("limit", &this.limit)
+}
+
+paged!(GetIndustryProjects => IndustrysProject, 100);
+
 /// Diese Klasse dient als Hauptmethode um die Daten von Entrys zu erhalten,
 /// insbesondere der ID (die für jede spezielle Entry Schnittstelle nötig ist).
 /// Sie enthält zwei Arten von Schnittstellen: Eine "Search" Schnittstelle,
 /// die wie die Erweiterte Suche auf Proxer funktioniert, sowie Auflistungs-Schnittstellen,
 /// die Animelisten nach bestimmten Schemata erstellen.
-#[derive(Debug)]
-pub struct List<'a> {
-    proxer: &'a Proxer,
+#[derive(Debug, Clone)]
+pub struct List {
+    proxer: Proxer,
 }
 
-impl<'a> List<'a> {
-    #[doc(hidden)]
-    pub fn new(proxer: &'a Proxer) -> List<'a> {
-        List { proxer: proxer }
+impl List {
+    pub(crate) fn new(proxer: &Proxer) -> List {
+        List {
+            proxer: proxer.clone(),
+        }
     }
 
     /// Diese Funktion erfüllt die Aufgabe der erweiterten Suche
@@ -194,36 +427,37 @@ impl<'a> List<'a> {
     /// # Arguments
     ///
     /// * `name` - Ein zu suchender Entryname. Die Schnittstelle sucht sowohl nach exakten Matches
-    /// als auch nach Teil-Matches im Titel des Entrys (oder einem seiner Synonyme)
+    ///   als auch nach Teil-Matches im Titel des Entrys (oder einem seiner Synonyme)
     /// * `language` - Die zu suchende Sprache. Erlaubte Werte: "de","en". Default: Beide.
     /// * `type` - Der zu suchende Typ. Erlaubte Werte:
-    /// 'animeseries', 'movie', 'ova', 'mangaseries', 'oneshot', 'doujin', 'hentai', 'hmanga',
-    /// 'all-anime'(kein H), 'all-manga'(kein H), 'all' (Default, kein H), 'all18'(H)
+    ///   'animeseries', 'movie', 'ova', 'mangaseries', 'oneshot', 'doujin', 'hentai', 'hmanga',
+    ///   'all-anime'(kein H), 'all-manga'(kein H), 'all' (Default, kein H), 'all18'(H)
     /// * `genre` - Genre, die der Entry enthalten soll. Als Leerzeichen (oder Plus "+")
-    /// separierter String, Genre-Namen wie in Suche. (Also ausgeschrieben, z.B. "Action") (Kein Effekt wenn leer)
+    ///   separierter String, Genre-Namen wie in Suche. (Also ausgeschrieben, z.B. "Action") (Kein Effekt wenn leer)
     /// * `nogenre` - Genre, die der Entry nicht enthalten darf. Angabe wie genre. (Kein Effekt wenn leer)
     /// * `fsk` - Die zu suchenden Gefahrensymbole/Altersbeschränkungen. Erlaubte Werte:
-    /// "fsk0", "fsk6", "fsk12", "fsk16", "fsk18", "bad_language", "violence", "fear", "sex".
-    /// Anzugeben als Leerzeichen oder Plus ("+") separierter String. (Kein Effekt wenn leer)
+    ///   "fsk0", "fsk6", "fsk12", "fsk16", "fsk18", "bad_language", "violence", "fear", "sex".
+    ///   Anzugeben als Leerzeichen oder Plus ("+") separierter String. (Kein Effekt wenn leer)
     /// * `sort` - Wie die Ergebnisse sortiert werden sollen. Erlaubte Werte:
-    /// "relevance" (Qualität des Name-Matches, Default), "clicks", "rating"
-    /// (Sortierung erst nach Anzahl und dann Wertung der Stimmen), "count" (Anzahl der Kapitel/Episoden),
-    /// "name" (Alphabetisch)
+    ///   "relevance" (Qualität des Name-Matches, Default), "clicks", "rating"
+    ///   (Sortierung erst nach Anzahl und dann Wertung der Stimmen), "count" (Anzahl der Kapitel/Episoden),
+    ///   "name" (Alphabetisch)
     /// * `length` - Die Anzahl der Kapitel/Episoden (Müssen nicht hochgeladen sein!),
-    /// die ein Entry mindestens/höchstens haben darf. Zwischen 0 und 400 (exklusive). (Kein Effekt wenn leer)
+    ///   die ein Entry mindestens/höchstens haben darf. Zwischen 0 und 400 (exklusive). (Kein Effekt wenn leer)
     /// * `length-limit` - Ob der Parameter "length" als Minimal- oder Maximalwert verwendet werden soll.
-    /// Erlaubte Werte: "up" (größer gleich), "down" (kleiner gleich, Default).
-    /// Hat keinen Effekt wenn "length" nicht korrekt gesetzt ist.
+    ///   Erlaubte Werte: "up" (größer gleich), "down" (kleiner gleich, Default).
+    ///   Hat keinen Effekt wenn "length" nicht korrekt gesetzt ist.
     /// * `tags` - Tags, die der Entry enthalten soll. Als Leerzeichen (oder Plus "+")
-    /// separierter String von Tag-Ids. (Kein Effekt wenn leer)
+    ///   separierter String von Tag-Ids. (Kein Effekt wenn leer)
     /// * `notags` - Tags, die der Entry nicht enthalten darf.
-    /// Als Leerzeichen (oder Plus "+") separierter String von Tag-Ids. (Kein Effekt wenn leer)
+    ///   Als Leerzeichen (oder Plus "+") separierter String von Tag-Ids. (Kein Effekt wenn leer)
     /// * `tagratefilter` - Welche Art Tags berücksichtigt werden soll (negativ als auch positiv),
-    /// "rate_1" für nur eingetragene Tags (Default), "rate_10" für zusätzlich Unbestimmte Tags.
+    ///   "rate_1" für nur eingetragene Tags (Default), "rate_10" für zusätzlich Unbestimmte Tags.
     /// * `tagspoilerfilter` - Inwieweit Spoiler-Tags berücksichtigt werden sollen (negativ als auch positiv),
-    /// "spoiler_0" für keine Spoiler (Default), "spoiler_10" für Spoiler und Nicht-Spoiler, "spoiler_1" für nur Spoiler.
+    ///   "spoiler_0" für keine Spoiler (Default), "spoiler_10" für Spoiler und Nicht-Spoiler, "spoiler_1" für nur Spoiler.
     /// * `page` - Die zu ladende Seite, Beginn bei 0, Default 0.
     /// * `limit` - Wie viele Einträge eine Seite enthalten soll. Default 100.
+    #[allow(clippy::too_many_arguments)]
     pub fn entry_search(
         &self,
         name: Option<String>,
@@ -242,40 +476,44 @@ impl<'a> List<'a> {
         page: Option<u64>,
         limit: Option<u64>,
     ) -> Result<Vec<EntrySearch>> {
-        let url = url!("list", "entrysearch");
-        let body = param_build!("name" => name,
-            "language" => language,
-            "type" => medium_type,
-            "genre" => genre,
-            "nogenre" => nogenre,
-            "fsk" => fsk,
-            "sort" => sort,
-            "length" => length,
-            "length-limit" => lengthlimit,
-            "tags" => tags,
-            "notags" => notags,
-            "tagratefilter" => tagratefilter,
-            "tagspoilerfilter" => tagspoilerfilter,
-            "p" => page,
-            "limit" => limit);
-        let response = self.proxer.connect(&url, &body)?;
-        let data: Response<Vec<EntrySearch>> = serde_json::from_reader(response)?;
-        check_error!(data.error, data.code.unwrap_or_default(), data.message);
-        check_data!(data.data)
+        self.proxer.execute(&SearchEntry {
+            name,
+            language,
+            medium_type,
+            genre,
+            nogenre,
+            fsk,
+            sort,
+            length,
+            lengthlimit,
+            tags,
+            notags,
+            tagratefilter,
+            tagspoilerfilter,
+            page,
+            limit,
+        })
+    }
+
+    /// Wie `entry_search`, lädt aber die Ergebnisse aller Seiten nacheinander.
+    /// `page` und `limit` von `query` werden dabei überschrieben.
+    pub fn entry_search_all(&self, query: SearchEntry) -> Paginator<SearchEntry> {
+        self.proxer.paginate(query)
     }
+
     /// Diese Funktion liefert eine Liste aller Entrys einer Kategorie mit bestimmten Einschränkungsmöglichkeiten.
     ///
     /// # Arguments
     ///
     /// * `kat` - Die Kategorie des Entrys. Erlaubte Werte: "anime","manga". Default: "anime"
     /// * `medium` - Welche Art medium geladen werden soll.
-    /// Erlaubte Werte: "animeseries", "movie", "ova", "hentai", "mangaseries", "oneshot", "doujin", "hmanga".
-    /// (Kein Effekt wenn leer)
+    ///   Erlaubte Werte: "animeseries", "movie", "ova", "hentai", "mangaseries", "oneshot", "doujin", "hmanga".
+    ///   (Kein Effekt wenn leer)
     /// * `is_h` - Ob die Liste Hentais enthalten soll. "true" für Hentai-Liste, sonst non-Hentai Liste (default).
-    /// Dieser Parameter hat keinen Effekt wenn "medium" gesetzt ist
+    ///   Dieser Parameter hat keinen Effekt wenn "medium" gesetzt ist
     /// * `start` - Mit welchem String der Name der Entrys beginnen soll.
-    /// Nützlich, um z.B. nach Anfangsbuchstaben zu filtern.
-    /// Um nach nicht-alphabetischen Anfängen (Erstes Zeichen) zu filtern, 'nonAlpha' angeben. (Kein Effekt wenn leer)
+    ///   Nützlich, um z.B. nach Anfangsbuchstaben zu filtern.
+    ///   Um nach nicht-alphabetischen Anfängen (Erstes Zeichen) zu filtern, 'nonAlpha' angeben. (Kein Effekt wenn leer)
     /// * `page` - Die zu ladende Seite, Beginn bei 0, Default 0.
     /// * `limit` - Wie viele Einträge eine Seite enthalten soll. Default 100.
     pub fn get_entrylist(
@@ -287,17 +525,32 @@ impl<'a> List<'a> {
         page: Option<u64>,
         limit: Option<u64>,
     ) -> Result<Vec<EntryList>> {
-        let url = url!("list", "entrylist");
-        let body = param_build!("kat" => kat,
-            "medium" => medium,
-            "isH" => is_h,
-            "start" => start,
-            "p" => page,
-            "limit" => limit);
-        let response = self.proxer.connect(&url, &body)?;
-        let data: Response<Vec<EntryList>> = serde_json::from_reader(response)?;
-        check_error!(data.error, data.code.unwrap_or_default(), data.message);
-        check_data!(data.data)
+        self.proxer.execute(&GetEntryList {
+            kat,
+            medium,
+            is_h,
+            start,
+            page,
+            limit,
+        })
+    }
+
+    /// Wie `get_entrylist`, lädt aber die Entrys aller Seiten nacheinander.
+    pub fn get_entrylist_all(
+        &self,
+        kat: Option<Kategorie>,
+        medium: Option<Medium>,
+        is_h: Option<bool>,
+        start: Option<String>,
+    ) -> Paginator<GetEntryList> {
+        self.proxer.paginate(GetEntryList {
+            kat,
+            medium,
+            is_h,
+            start,
+            page: None,
+            limit: None,
+        })
     }
 
     /// Diese Funktion zieht aus einem String die IDs aller darin vorkommenden Tags und gibt sie zurück.
@@ -305,17 +558,12 @@ impl<'a> List<'a> {
     /// # Arguments
     ///
     /// * `search` - Ein beliebiger String. Sollte sinnvollerweise Tags enthalten,
-    /// einzelne Tags durch Leerzeichen getrennt (also keine Kommata zur Trennung oder dergleichen verwenden).
-    /// Es können nur Tags erkannt werden, vor und nach denen ein Leerzeichen bzw der Anfang/das Ende des Strings sind.
-    /// Zudem darf vor einem Tag (Also nach dem Leerzeichen/Beginn des Strings vor dem Tag) ein Minus ("-") stehen.
-    /// Solcherart gekennzeichnete Tags werden gesondert ausgegeben.
+    ///   einzelne Tags durch Leerzeichen getrennt (also keine Kommata zur Trennung oder dergleichen verwenden).
+    ///   Es können nur Tags erkannt werden, vor und nach denen ein Leerzeichen bzw der Anfang/das Ende des Strings sind.
+    ///   Zudem darf vor einem Tag (Also nach dem Leerzeichen/Beginn des Strings vor dem Tag) ein Minus ("-") stehen.
+    ///   Solcherart gekennzeichnete Tags werden gesondert ausgegeben.
     pub fn get_tag_ids(&self, search: String) -> Result<TagIDs> {
-        let url = url!("list", "tagids");
-        let body = param_build!("search" => Some(search));
-        let response = self.proxer.connect(&url, &body)?;
-        let data: Response<TagIDs> = serde_json::from_reader(response)?;
-        check_error!(data.error, data.code.unwrap_or_default(), data.message);
-        check_data!(data.data)
+        self.proxer.execute(&GetTagIds { search })
     }
 
     /// Diese Funktion liefert eine Liste aller Tags, anhand bestimmter Kriterien.
@@ -323,14 +571,14 @@ impl<'a> List<'a> {
     /// # Arguments
     ///
     /// * `search` - Ein beliebiger String. Nur Tags,
-    /// deren Name oder Beschreibung diesen String als Substring haben oder gleich ihm sind sind werden ausgegeben.
+    ///   deren Name oder Beschreibung diesen String als Substring haben oder gleich ihm sind sind werden ausgegeben.
     /// * `type` - Welcher Typ Tag angezeigt werden soll.
-    /// Mögliche Werte: entry_genre (Genres, momentan nicht in Benutzung), entry_tag (normale Tags),
-    /// entry_tag_h (H-tags), gallery (Gallerie Tags, momentan nicht in Benutzung). Default: Alles außer H-Tags.
+    ///   Mögliche Werte: entry_genre (Genres, momentan nicht in Benutzung), entry_tag (normale Tags),
+    ///   entry_tag_h (H-tags), gallery (Gallerie Tags, momentan nicht in Benutzung). Default: Alles außer H-Tags.
     /// * `sort` - Nach welchem Ausgabeparameter die Liste sortiert werden soll.
-    /// Erlaubte Werte: id, type, tag, description, blacklist, subtype. Default: tag
+    ///   Erlaubte Werte: id, type, tag, description, blacklist, subtype. Default: tag
     /// * `sort_type` - In welcher Reihenfolge die Sortierung ist. 'ASC' für aufsteigend,
-    /// 'DESC' für absteigend (Jeder andere Wert wird zu DESC). Default: ASC
+    ///   'DESC' für absteigend (Jeder andere Wert wird zu DESC). Default: ASC
     /// * `subtype` - Die Kategorie des Tags
     pub fn get_tags(
         &self,
@@ -340,16 +588,13 @@ impl<'a> List<'a> {
         sort_type: Option<String>,
         sub_type: Option<SubType>,
     ) -> Result<Vec<Tag>> {
-        let url = url!("list", "tags");
-        let body = param_build!("search" => search,
-            "type" => tag_type,
-            "sort" => sort,
-            "sort_type" => sort_type,
-            "sub_type" => sub_type);
-        let response = self.proxer.connect(&url, &body)?;
-        let data: Response<Vec<Tag>> = serde_json::from_reader(response)?;
-        check_error!(data.error, data.code.unwrap_or_default(), data.message);
-        check_data!(data.data)
+        self.proxer.execute(&GetTags {
+            search,
+            tag_type,
+            sort,
+            sort_type,
+            sub_type,
+        })
     }
 
     /// Diese Funktion liefert eine Liste aller Sub/Scanlation Gruppen, anhand bestimmter Kriterien.
@@ -357,12 +602,12 @@ impl<'a> List<'a> {
     /// # Arguments
     ///
     /// * `start` - Mit welchem String der Name der Gruppen beginnen soll.
-    /// Nützlich, um z.B. nach Anfangsbuchstaben zu filtern.
-    /// Um nach nicht-alphabetischen Anfängen (Erstes Zeichen) zu filtern, 'nonAlpha' angeben. (Kein Effekt wenn leer)
+    ///   Nützlich, um z.B. nach Anfangsbuchstaben zu filtern.
+    ///   Um nach nicht-alphabetischen Anfängen (Erstes Zeichen) zu filtern, 'nonAlpha' angeben. (Kein Effekt wenn leer)
     /// * `contains` - Nur Gruppen, die diesen String als Substring ihres Namens haben, werden ausgegeben.
-    /// (Kein Effekt wenn leer)
-    /// country (optional): Ermöglich, Gruppen nach Sprache zu filtern,
-    /// erlaubte Werte: "de", "en", "misc". Default: Alle.
+    ///   (Kein Effekt wenn leer)
+    ///   country (optional): Ermöglich, Gruppen nach Sprache zu filtern,
+    ///   erlaubte Werte: "de", "en", "misc". Default: Alle.
     /// * `page` - Die zu ladende Seite, Beginn bei 0, Default 0.
     /// * `limit` - Wie viele Einträge eine Seite enthalten soll. Default 100.
     pub fn get_translatorgroups(
@@ -372,15 +617,26 @@ impl<'a> List<'a> {
         page: Option<u64>,
         limit: Option<u64>,
     ) -> Result<Vec<TranslatorGroup>> {
-        let url = url!("list", "translatorgroups");
-        let body = param_build!("start" => start,
-            "contains" => contains,
-            "p" => page,
-            "limit" => limit);
-        let response = self.proxer.connect(&url, &body)?;
-        let data: Response<Vec<TranslatorGroup>> = serde_json::from_reader(response)?;
-        check_error!(data.error, data.code.unwrap_or_default(), data.message);
-        check_data!(data.data)
+        self.proxer.execute(&GetTranslatorGroups {
+            start,
+            contains,
+            page,
+            limit,
+        })
+    }
+
+    /// Wie `get_translatorgroups`, lädt aber die Gruppen aller Seiten nacheinander.
+    pub fn get_translatorgroups_all(
+        &self,
+        start: Option<String>,
+        contains: Option<String>,
+    ) -> Paginator<GetTranslatorGroups> {
+        self.proxer.paginate(GetTranslatorGroups {
+            start,
+            contains,
+            page: None,
+            limit: None,
+        })
     }
 
     /// Diese Funktion liefert eine Liste aller Firmen, anhand bestimmter Kriterien.
@@ -388,14 +644,14 @@ impl<'a> List<'a> {
     /// # Arguments
     ///
     /// * `start` - Mit welchem String der Name der Firma beginnen soll.
-    /// Nützlich, um z.B. nach Anfangsbuchstaben zu filtern.
-    /// Um nach nicht-alphabetischen Anfängen (Erstes Zeichen) zu filtern, 'nonAlpha' angeben.(Kein Effekt wenn leer)
+    ///   Nützlich, um z.B. nach Anfangsbuchstaben zu filtern.
+    ///   Um nach nicht-alphabetischen Anfängen (Erstes Zeichen) zu filtern, 'nonAlpha' angeben.(Kein Effekt wenn leer)
     /// * `contains` - Nur Firmen, die diesen String als Substring ihres Namens haben,
-    /// werden ausgegeben. (Kein Effekt wenn leer)
+    ///   werden ausgegeben. (Kein Effekt wenn leer)
     /// * `country` - Ermöglich, Firmen nach Sprache zu filtern,
-    /// erlaubte Werte: "de", "us", "jp", "misc". Default: Alle.
+    ///   erlaubte Werte: "de", "us", "jp", "misc". Default: Alle.
     /// * `type` - Ermöglicht, Firmen nach Typ zu filtern,
-    ///  erlaubte Werte: 'publisher','studio','producer','record_label','talent_agent','streaming'. Default: Alle.
+    ///   erlaubte Werte: 'publisher','studio','producer','record_label','talent_agent','streaming'. Default: Alle.
     /// * `page` - Die zu ladende Seite, Beginn bei 0, Default 0.
     /// * `limit` - Wie viele Einträge eine Seite enthalten soll. Default 100.
     pub fn get_industrys(
@@ -407,17 +663,32 @@ impl<'a> List<'a> {
         page: Option<u64>,
         limit: Option<u64>,
     ) -> Result<Vec<Industry>> {
-        let url = url!("list", "industrys");
-        let body = param_build!("start" => start,
-            "contains" => contains,
-            "country" => country,
-            "type" => firma_type,
-            "p" => page,
-            "limit" => limit);
-        let response = self.proxer.connect(&url, &body)?;
-        let data: Response<Vec<Industry>> = serde_json::from_reader(response)?;
-        check_error!(data.error, data.code.unwrap_or_default(), data.message);
-        check_data!(data.data)
+        self.proxer.execute(&GetIndustrys {
+            start,
+            contains,
+            country,
+            firma_type,
+            page,
+            limit,
+        })
+    }
+
+    /// Wie `get_industrys`, lädt aber die Firmen aller Seiten nacheinander.
+    pub fn get_industrys_all(
+        &self,
+        start: Option<String>,
+        contains: Option<String>,
+        country: Option<String>,
+        firma_type: Option<Firma>,
+    ) -> Paginator<GetIndustrys> {
+        self.proxer.paginate(GetIndustrys {
+            start,
+            contains,
+            country,
+            firma_type,
+            page: None,
+            limit: None,
+        })
     }
 
     /// Diese Funktion liefert eine Liste aller Projekte (=Entrys) einer Gruppe anhand ihrer ID.
@@ -426,9 +697,9 @@ impl<'a> List<'a> {
     ///
     /// * `id` - Die ID der gewünschten Gruppe.
     /// * `type` - Ein Integer. Wenn gesetzt werden nur Entrys des gegebenen Übersezungs-Status ausgegeben
-    /// (0=undefined, 1=abgeschlossen, 2=am laufen, 3=Geplant, 4=abgebrochen, 5=lizenziert). Default: Alle.
+    ///   (0=undefined, 1=abgeschlossen, 2=am laufen, 3=Geplant, 4=abgebrochen, 5=lizenziert). Default: Alle.
     /// * `is_h` - Ein Integer. Steuert die Ausgabe von H-Inhalten. Werte:
-    /// -1 (kein H, Default), 0 (beides), 1 (nur H)
+    ///   -1 (kein H, Default), 0 (beides), 1 (nur H)
     /// * `page` - Die zu ladende Seite, Beginn bei 0, Default 0.
     /// * `limit` - Wie viele Einträge eine Seite enthalten soll. Default 100.
     pub fn get_translatorgroups_projects(
@@ -439,16 +710,29 @@ impl<'a> List<'a> {
         page: Option<u64>,
         limit: Option<u64>,
     ) -> Result<Vec<TranslatorGroupProject>> {
-        let url = url!("list", "translatorgroupprojects");
-        let body = param_build!("id" => Some(id),
-            "type" => status_type,
-            "isH" => is_h,
-            "p" => page,
-            "limit" => limit);
-        let response = self.proxer.connect(&url, &body)?;
-        let data: Response<Vec<TranslatorGroupProject>> = serde_json::from_reader(response)?;
-        check_error!(data.error, data.code.unwrap_or_default(), data.message);
-        check_data!(data.data)
+        self.proxer.execute(&GetTranslatorGroupProjects {
+            id,
+            status_type,
+            is_h,
+            page,
+            limit,
+        })
+    }
+
+    /// Wie `get_translatorgroups_projects`, lädt aber die Projekte aller Seiten nacheinander.
+    pub fn get_translatorgroups_projects_all(
+        &self,
+        id: u64,
+        status_type: Option<TranslationStatus>,
+        is_h: Option<i8>,
+    ) -> Paginator<GetTranslatorGroupProjects> {
+        self.proxer.paginate(GetTranslatorGroupProjects {
+            id,
+            status_type,
+            is_h,
+            page: None,
+            limit: None,
+        })
     }
 
     /// Diese Funktion liefert eine Liste aller Projekte (=Entrys) einer Firma anhand ihrer ID.
@@ -457,10 +741,10 @@ impl<'a> List<'a> {
     ///
     /// * `id` - Die ID der gewünschten Firma.
     /// * `type` - Ermöglicht, Entrys nach Typ der Firma zu filtern
-    /// (eine Firma kann als verschiedene Typen auftreten),
-    /// erlaubte Werte: 'publisher','studio','producer','record_label','talent_agent','streaming'. Default: Alle.
+    ///   (eine Firma kann als verschiedene Typen auftreten),
+    ///   erlaubte Werte: 'publisher','studio','producer','record_label','talent_agent','streaming'. Default: Alle.
     /// * `is_h` - Ein Integer. Steuert die Ausgabe von H-Inhalten.
-    /// Werte: -1 (kein H, Default), 0 (beides), 1 (nur H)
+    ///   Werte: -1 (kein H, Default), 0 (beides), 1 (nur H)
     /// * `page` - Die zu ladende Seite, Beginn bei 0, Default 0.
     /// * `limit` - Wie viele Einträge eine Seite enthalten soll. Default 100.
     pub fn get_industry_projects(
@@ -471,15 +755,28 @@ impl<'a> List<'a> {
         page: Option<u64>,
         limit: Option<u64>,
     ) -> Result<Vec<IndustrysProject>> {
-        let url = url!("list", "industryprojects");
-        let body = param_build!("id" => Some(id),
-            "type" => firma_type,
-            "isH" => is_h,
-            "p" => page,
-            "limit" => limit);
-        let response = self.proxer.connect(&url, &body)?;
-        let data: Response<Vec<IndustrysProject>> = serde_json::from_reader(response)?;
-        check_error!(data.error, data.code.unwrap_or_default(), data.message);
-        check_data!(data.data)
+        self.proxer.execute(&GetIndustryProjects {
+            id,
+            firma_type,
+            is_h,
+            page,
+            limit,
+        })
+    }
+
+    /// Wie `get_industry_projects`, lädt aber die Projekte aller Seiten nacheinander.
+    pub fn get_industry_projects_all(
+        &self,
+        id: u64,
+        firma_type: Option<Firma>,
+        is_h: Option<i8>,
+    ) -> Paginator<GetIndustryProjects> {
+        self.proxer.paginate(GetIndustryProjects {
+            id,
+            firma_type,
+            is_h,
+            page: None,
+            limit: None,
+        })
     }
 }
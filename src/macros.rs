@@ -1,75 +1,172 @@
 #[macro_export]
-macro_rules! url
+macro_rules! check_error
 {
-    ($api_class:expr, $api_function:expr) =>
+    ($error:expr, $code:expr, $message:expr) =>
     (
         {
-            use BASE_URL;
-            use API_VERSION;
-            debug!("Requesting url: {:?}",
-                format!("{}/{}/{}/{}", BASE_URL, API_VERSION, $api_class, $api_function));
-            &format!("{}/{}/{}/{}", BASE_URL, API_VERSION, $api_class, $api_function)
+            use error::*;
+            if $error == 1
+            {
+                return Err(Error::from(($code, $message)))
+            }
         }
     );
 }
 
 #[macro_export]
-macro_rules! check_error
+macro_rules! check_data
 {
-    ($error:expr, $code:expr, $message:expr) =>
+    ( $data:expr ) =>
     (
         {
-            use error::*;
-            if $error == 1
+            match $data
             {
-                error!("Found error: {}:{}", $code, DecodeErrorCode::decode($code));
-                return Err(Error::ProxerError($code, $message))
+                None =>
+                {
+                    error!("The received data was none.");
+                    Err(Error::Other("Found Empty data after error check.".to_owned()))
+                },
+                Some(r) => Ok(r),
             }
         }
     );
 }
 
-#[macro_export]
-macro_rules! param_build
+/// Definiert ein Struct für eine API-Funktion und implementiert `Endpoint` dafür.
+///
+/// `|this| ...` erzeugt aus dem Struct (`this`) die Parameter der Anfrage.
+/// Optional folgen `mutates` und/oder `login` in den eckigen Klammern.
+/// Bei `-> ()` wird `data` der Antwort nicht ausgewertet.
+macro_rules! endpoint
 {
-    ( $( $name:expr => $value:expr ),* ) =>
     (
+        $(#[$attr:meta])*
+        pub struct $name:ident -> () [$class:expr, $function:expr $(, $flag:ident)*]
         {
-            let mut body: String = String::new();
-            $(
-                match $value
-                {
-                    None => {},
-                    Some(r) => if body.len() <= 1
-                    {
-                        body.push_str(&format!("{}={}", $name, r));
-                    }
-                    else
-                    {
-                        body.push_str(&format!("&{}={}", $name, r));
-                    },
-                }
-            )*
-            debug!("Build body with: {:?}", &body);
-            body
+            $( $(#[$field_attr:meta])* pub $field:ident : $field_ty:ty, )*
+        }
+        |$this:ident| $params:expr
+    ) =>
+    (
+        endpoint! {
+            @define [empty]
+            $(#[$attr])*
+            pub struct $name -> () [$class, $function $(, $flag)*]
+            {
+                $( $(#[$field_attr])* pub $field : $field_ty, )*
+            }
+            |$this| $params
+        }
+    );
+    (
+        $(#[$attr:meta])*
+        pub struct $name:ident -> $output:ty [$class:expr, $function:expr $(, $flag:ident)*]
+        {
+            $( $(#[$field_attr:meta])* pub $field:ident : $field_ty:ty, )*
+        }
+        |$this:ident| $params:expr
+    ) =>
+    (
+        endpoint! {
+            @define []
+            $(#[$attr])*
+            pub struct $name -> $output [$class, $function $(, $flag)*]
+            {
+                $( $(#[$field_attr])* pub $field : $field_ty, )*
+            }
+            |$this| $params
+        }
+    );
+    (
+        @define [$($extra:ident)*]
+        $(#[$attr:meta])*
+        pub struct $name:ident -> $output:ty [$class:expr, $function:expr $(, $flag:ident)*]
+        {
+            $( $(#[$field_attr:meta])* pub $field:ident : $field_ty:ty, )*
+        }
+        |$this:ident| $params:expr
+    ) =>
+    (
+        $(#[$attr])*
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct $name
+        {
+            $( $(#[$field_attr])* pub $field: $field_ty, )*
+        }
+
+        impl ::endpoint::Endpoint for $name
+        {
+            type Output = $output;
+
+            fn api_class(&self) -> &'static str
+            {
+                $class
+            }
+
+            fn api_function(&self) -> &'static str
+            {
+                $function
+            }
+
+            #[allow(unused_variables)]
+            fn params(&self) -> ::params::Params
+            {
+                let $this = self;
+                $params
+            }
+
+            $( endpoint!(@flag $flag); )*
+            $( endpoint!(@flag $extra); )*
+        }
+    );
+    (@flag empty) =>
+    (
+        fn parse(&self, response: &[u8]) -> ::error::Result<()>
+        {
+            ::endpoint::parse_empty_response(response)
+        }
+    );
+    (@flag mutates) =>
+    (
+        fn mutates(&self) -> bool
+        {
+            true
+        }
+    );
+    (@flag login) =>
+    (
+        fn needs_login(&self) -> bool
+        {
+            true
         }
     );
 }
 
-#[macro_export]
-macro_rules! check_data
+/// Implementiert `Paged` für ein mit `endpoint!` definiertes Struct mit den Feldern `page` und `limit`,
+/// dessen `Output` eine Liste von `$item` ist.
+macro_rules! paged
 {
-    ( $data:expr ) =>
+    ( $name:ident => $item:ty, $page_size:expr ) =>
     (
+        impl ::paginator::Paged for $name
         {
-            match $data
+            type Item = $item;
+
+            fn default_page_size(&self) -> u64
             {
-                None =>
-                {
-                    error!("The received data was none.");
-                    Err(Error::Other(format!("Found Empty data after error check.")))
-                },
-                Some(r) => Ok(r),
+                $page_size
+            }
+
+            fn set_page(&mut self, page: u64, limit: u64) -> u64
+            {
+                self.page = Some(page);
+                self.limit = Some(limit);
+                limit
+            }
+
+            fn into_items(output: Vec<$item>) -> Vec<$item>
+            {
+                output
             }
         }
     );
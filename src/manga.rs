@@ -1,30 +1,35 @@
-use serde_json;
-
 use error::*;
+use lenient;
+use params::Params;
 use Proxer;
-use models::*;
 
 /// Diese Funktion liefert ein Kapitel eines festgelegten Mangas.
 /// Um Mangapunkte für das Lesen zu erhalten muss ein User angemeldet sein.
 #[derive(Deserialize, Debug, Clone, PartialEq)]
 pub struct Chapter {
     /// Die ID des Kapitels
+    #[serde(with = "lenient")]
     pub cid: u64,
     /// Die ID des Entrys
+    #[serde(with = "lenient")]
     pub eid: u64,
     /// Der Titel des Kapitels
     pub title: String,
     /// Die ID des Uploaders
+    #[serde(with = "lenient")]
     pub uploader: u64,
     /// Der Name des Uploaders
     pub username: String,
     /// Der Hochladezeitpunkt (Unix-Timestamp als Integer)
+    #[serde(with = "lenient")]
     pub timestamp: i64,
     /// Die ID der zugewiesenen Scangruppe, null wenn nicht vorhanden.
+    #[serde(with = "lenient")]
     pub tid: u64,
     /// Der Name der zugewiesenen Scangruppe, null wenn nicht vorhanden.
     pub tname: String,
     /// Notwendig zur Anzeige der Seiten, siehe "pages"
+    #[serde(with = "lenient")]
     pub server: u32,
     /// Ein Array aus Arrays, die die Seiten des Kapitels in geordneter Reihenfolge enthalten.
     /// Jedes Unter-Array enthält dabei folgende Daten:
@@ -39,40 +44,54 @@ pub struct Chapter {
 impl Chapter {
     pub fn get_chapter_link(&self) -> String {
         // format!("https://manga{}.proxer.me/f/{}/{}/{}", self.server, self.eid, self.cid, self.)
-        format!("TODO")
+        "TODO".to_owned()
+    }
+}
+
+endpoint! {
+    /// Liefert ein Kapitel eines Mangas. Siehe `Manga::get_chapter`.
+    pub struct GetChapter -> Vec<Chapter> ["manga", "chapter"] {
+        /// Die id des Entrys.
+        pub id: u64,
+        /// Die Episodennummer des zu ladenden Kapitels.
+        pub episode: u64,
+        /// Die zu ladende Sprache (de,en).
+        pub language: String,
     }
+    |this| Params::new()
+        .add("id", &this.id)
+        .add("episode", &this.episode)
+        .add("language", &this.language)
 }
 
 /// Diese Klasse dient dazu, Kapitel für Mangas zu erhalten,
 /// und andere rein Manga-bezogene Aktionen durchzuführen.
 /// Der Zugriff auf diese Klasse ist stark beschränkt.
-#[derive(Debug)]
-pub struct Manga<'a> {
-    proxer: &'a Proxer,
+#[derive(Debug, Clone)]
+pub struct Manga {
+    proxer: Proxer,
 }
 
-impl<'a> Manga<'a> {
-    #[doc(hidden)]
-    pub fn new(proxer: &'a Proxer) -> Manga<'a> {
-        Manga { proxer: proxer }
+impl Manga {
+    pub(crate) fn new(proxer: &Proxer) -> Manga {
+        Manga {
+            proxer: proxer.clone(),
+        }
     }
 
     /// Diese Funktion liefert ein Kapitel eines festgelegten Mangas.
     /// Um Mangapunkte für das Lesen zu erhalten muss ein User angemeldet sein.
     ///
-    ///	# Arguments
+    /// # Arguments
     ///
     /// * `id` - Die id des Entrys
     /// * `episode` - Die Episodennummer des zu ladenden Kapitels
     /// * `language` - Die zu ladende Sprache (de,en)
     pub fn get_chapter(&self, id: u64, episode: u64, language: &str) -> Result<Vec<Chapter>> {
-        let url = url!("manga", "chapter");
-        let body = param_build!("id" => Some(id),
-            "episode" => Some(episode),
-            "language" => Some(language));
-        let response = self.proxer.connect(&url, &body)?;
-        let data: Response<Vec<Chapter>> = serde_json::from_reader(response)?;
-        check_error!(data.error, data.code.unwrap_or_default(), data.message);
-        check_data!(data.data)
+        self.proxer.execute(&GetChapter {
+            id,
+            episode,
+            language: language.to_owned(),
+        })
     }
 }
@@ -1,14 +1,14 @@
-use serde_json;
-
 use error::*;
+use lenient;
+use params::Params;
 use Proxer;
-use models::*;
 
 /// Diese Funktion liefert einen zufälligen Header.
 /// Bildpfad: //cdn.proxer.me/gallery/originals/<catpath>/<imgfilename>
 #[derive(Deserialize, Debug, Clone, PartialEq)]
 pub struct RandomHeader {
     /// Die ID des Headers in der Gallerie
+    #[serde(with = "lenient")]
     pub gid: u64,
     /// Der Pfad zum Bild
     pub catpath: String,
@@ -30,6 +30,7 @@ impl RandomHeader {
 #[derive(Deserialize, Debug, Clone, PartialEq)]
 pub struct HeaderList {
     /// Die ID des Headers in der Gallerie
+    #[serde(with = "lenient")]
     pub gid: u64,
     /// Der Pfad zum Bild
     pub catpath: String,
@@ -46,38 +47,44 @@ impl HeaderList {
     }
 }
 
+endpoint! {
+    /// Liefert einen zufälligen Header. Siehe `Media::get_randomheader`.
+    pub struct GetRandomHeader -> Vec<RandomHeader> ["media", "randomheader"] {
+        /// Der Stil des Headers.
+        pub style: Option<String>,
+    }
+    |this| Params::new().add("style", &this.style)
+}
+
+endpoint! {
+    /// Liefert eine Liste aller aktuellen Header. Siehe `Media::get_headerlist`.
+    pub struct GetHeaderList -> Vec<HeaderList> ["media", "headerlist"] {}
+    |this| Params::new()
+}
+
 /// Diese Klasse dient dazu, verschiedene Medien von Proxer zu erhalten.
 /// Dabei sind Animes und Mangas explizit ausgeschlossen, diese werden in eigenen Klassen behandelt.
-#[derive(Debug)]
-pub struct Media<'a> {
-    proxer: &'a Proxer,
+#[derive(Debug, Clone)]
+pub struct Media {
+    proxer: Proxer,
 }
 
-impl<'a> Media<'a> {
-    #[doc(hidden)]
-    pub fn new(proxer: &'a Proxer) -> Media<'a> {
-        Media { proxer: proxer }
+impl Media {
+    pub(crate) fn new(proxer: &Proxer) -> Media {
+        Media {
+            proxer: proxer.clone(),
+        }
     }
 
     /// Diese Funktion liefert einen zufälligen Header.
     /// Bildpfad: //cdn.proxer.me/gallery/originals/<catpath>/<imgfilename>
     pub fn get_randomheader(&self, style: Option<String>) -> Result<Vec<RandomHeader>> {
-        let url = url!("media", "randomheader");
-        let body = param_build!("style" => style);
-        let response = self.proxer.connect(&url, &body)?;
-        let data: Response<Vec<RandomHeader>> = serde_json::from_reader(response)?;
-        check_error!(data.error, data.code.unwrap_or_default(), data.message);
-        check_data!(data.data)
+        self.proxer.execute(&GetRandomHeader { style })
     }
 
     /// Diese Funktion liefert eine Liste aller aktuellen Header.
     /// Bildpfad: //cdn.proxer.me/gallery/originals/<catpath>/<imgfilename>
     pub fn get_headerlist(&self) -> Result<Vec<HeaderList>> {
-        let url = url!("media", "headerlist");
-        let body = String::new();
-        let response = self.proxer.connect(&url, &body)?;
-        let data: Response<Vec<HeaderList>> = serde_json::from_reader(response)?;
-        check_error!(data.error, data.code.unwrap_or_default(), data.message);
-        check_data!(data.data)
+        self.proxer.execute(&GetHeaderList {})
     }
 }
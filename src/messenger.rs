@@ -1,6 +1,7 @@
-use serde_json;
-
 use error::*;
+use lenient;
+use paginator::{Paged, Paginator};
+use params::Params;
 use Proxer;
 use models::*;
 
@@ -10,19 +11,19 @@ use models::*;
 #[derive(Deserialize, Debug, Clone, PartialEq)]
 pub struct Constants {
     /// Maximalanzahl an Zeichen pro Nachricht.
-    #[serde(rename = "textCount")]
+    #[serde(rename = "textCount", with = "lenient")]
     pub text_count: u64,
     /// Anzahl der Elemente, die "Get Conferences" maximal pro Aufruf/pro Seite liefert.
-    #[serde(rename = "conferenceLimit")]
+    #[serde(rename = "conferenceLimit", with = "lenient")]
     pub conference_limit: u64,
     /// Anzahl der Elemente, die "Get Messages" maximal pro Aufruf/pro Seite liefert.
-    #[serde(rename = "messagesLimit")]
+    #[serde(rename = "messagesLimit", with = "lenient")]
     pub messages_limit: u64,
     /// Maximalanzahl an Benutzern pro Gruppenkonferenz.
-    #[serde(rename = "userLimit")]
+    #[serde(rename = "userLimit", with = "lenient")]
     pub user_limit: u64,
     /// Maximalanzahl an Zeichen für Konferenzthema.
-    #[serde(rename = "tropicCount")]
+    #[serde(rename = "tropicCount", with = "lenient")]
     pub topic_count: u64,
 }
 
@@ -30,6 +31,7 @@ pub struct Constants {
 #[derive(Deserialize, Debug, Clone, PartialEq)]
 pub struct Conference {
     /// Die Konferenz-ID
+    #[serde(with = "lenient")]
     pub id: u64,
     /// Der Titel einer Konferenz.
     /// Dies ist entweder der Benutzername des Gesprächspartners, oder das Thema einer Gruppenkonferenz.
@@ -37,16 +39,22 @@ pub struct Conference {
     /// Der Titel, der vom Benutzer festgelegt wurde.
     pub topic_custom: String,
     /// Anzahl der Konferenzteilnehmer.
+    #[serde(with = "lenient")]
     pub count: u64,
     /// true oder false. true Falls es sich um eine Gruppenkonferenz handelt. Ansonsten false.
+    #[serde(with = "lenient")]
     pub group: bool,
     /// Zeitstempel der letzten Nachricht.
-    pub timestamp_end: String,
+    #[serde(with = "lenient")]
+    pub timestamp_end: i64,
     /// true oder false: true falls Benutzer letzte Nachricht dieser Konferenz gelesen hat.
+    #[serde(with = "lenient")]
     pub read: bool,
     /// Anzahl der ungelesenen Nachrichten.
+    #[serde(with = "lenient")]
     pub read_count: u64,
     /// Die Message-ID der letzten gelesenen Nachricht.
+    #[serde(with = "lenient")]
     pub read_mid: u64,
     /// Bild der Konferenz. Format: "Bild-Typ:Bild-ID".
     /// Typ ist meistens "avatar" und ID beispielsweise "62_yF5zd7.jpg".
@@ -68,16 +76,20 @@ pub struct ConferenceInfoDataConference {
     /// Thema der Konferenz.
     pub topic: String,
     /// Anzahl der Konferenzteilnehmer.
+    #[serde(with = "lenient")]
     pub count: u64,
     /// Zeitstempel der letzten Konferenz-Nachricht.
+    #[serde(with = "lenient")]
     pub timestamp_end: i64,
     /// Die Benutzer-ID des Konferenzleiters.
+    #[serde(with = "lenient")]
     pub leader: u64,
 }
 
 #[derive(Deserialize, Debug, Clone, PartialEq)]
 pub struct ConferenceInfoDataUsers {
     /// User-ID
+    #[serde(with = "lenient")]
     pub uid: u64,
     /// Bild-ID des Avatars.
     /// Falls kein Avatar gesetzt ist, ist dieses Feld leer. Beispiel für Bild-ID: "62_yF5zd7.jpg"
@@ -105,30 +117,33 @@ pub struct UserInfo {
 #[derive(Deserialize, Debug, Clone, PartialEq)]
 pub struct Messages {
     /// conference_id=0 und message_id=0:
-    /// 	Gibt die letzten Nachrichten des Benutzers zurück.
-    /// 	Die letzte Nachricht dieser Ausgabe kann verwendet werden.
-    /// 	Empfohlen für den ersten Programmstart um die ID der letzten Nachricht zu erhalten.
+    ///   Gibt die letzten Nachrichten des Benutzers zurück.
+    ///   Die letzte Nachricht dieser Ausgabe kann verwendet werden.
+    ///   Empfohlen für den ersten Programmstart um die ID der letzten Nachricht zu erhalten.
     /// conference_id=0 und message_id!=0:
-    /// 	Gibt die letzten Nachrichten ab einer bestimmten Nachrichten-ID zurück.
-    /// 	Dieser Fall kann für die Abfrage der Benachrichtigungen verwendet werden.
+    ///   Gibt die letzten Nachrichten ab einer bestimmten Nachrichten-ID zurück.
+    ///   Dieser Fall kann für die Abfrage der Benachrichtigungen verwendet werden.
     /// conference_id!=0 und message_id=0:
-    /// 	Gibt die letzten Nachrichten einer bestimmten Konferenz zurück.
-    /// 	Kann für den Fall verwendet werden, wenn ein Benutzer zum ersten Mal eine Konferenz öffnet.
-    /// 	Falls read=true: Konferenz wird als gelesen markiert
-    /// 	(read=true, read_count=0, read_mid=letzte Nachrichten-ID).
+    ///   Gibt die letzten Nachrichten einer bestimmten Konferenz zurück.
+    ///   Kann für den Fall verwendet werden, wenn ein Benutzer zum ersten Mal eine Konferenz öffnet.
+    ///   Falls read=true: Konferenz wird als gelesen markiert
+    ///   (read=true, read_count=0, read_mid=letzte Nachrichten-ID).
     /// conference_id!=0 und message_id!=0:
-    /// 	Gibt die letzten Nachrichten einer bestimmten Konferenz bis zu einer bestimmten
-    ///		Nachricht zurück.
-    /// 	Kann verwendet werden, falls ein Benutzer beispielsweise weitere Nachrichten einer
-    ///		Konferenz anzeigen möchte.
-    /// 	Falls read=true: Konferenz wird als gelesen markiert
-    ///		(read=true, read_count=0, read_mid=letzte Nachrichten-ID).
+    ///   Gibt die letzten Nachrichten einer bestimmten Konferenz bis zu einer bestimmten
+    ///   Nachricht zurück.
+    ///   Kann verwendet werden, falls ein Benutzer beispielsweise weitere Nachrichten einer
+    ///   Konferenz anzeigen möchte.
+    ///   Falls read=true: Konferenz wird als gelesen markiert
+    ///   (read=true, read_count=0, read_mid=letzte Nachrichten-ID).
     /// Das Array-Element enthalten die folgenden Spalten:
     /// Die Nachrichten-ID.
+    #[serde(with = "lenient")]
     pub message_id: u64,
     /// Die Konferenz-ID.
+    #[serde(with = "lenient")]
     pub conference_id: u64,
     /// Die User-ID des Verfassers.
+    #[serde(with = "lenient")]
     pub user_id: u64,
     /// Der Username des Verfassers.
     pub username: String,
@@ -137,35 +152,212 @@ pub struct Messages {
     /// Falls es sich um ein Befehl handelt, ist hier der Befehl angegeben.
     pub action: String,
     /// Zeitstempel des Absendezeitpunktes.
+    #[serde(with = "lenient")]
     pub timestamp: i64,
     /// Das Gerät/die Anwendung aus der eine Nachricht gesendet wurde.
     pub device: String,
 }
 
+endpoint! {
+    /// Liefert die Messengerkonstanten. Siehe `Messenger::get_constants`.
+    pub struct GetConstants -> Vec<Constants> ["messenger", "constants", login] {}
+    |this| Params::new()
+}
+
+endpoint! {
+    /// Liefert die aktuellen Konferenzen. Siehe `Messenger::get_conferences`.
+    pub struct GetConferences -> Vec<Conference> ["messenger", "conferences", login] {
+        /// Nur Konferenzen mit dieser Markierung bzw. diesem Typ.
+        pub conference_type: Option<ConferenceOption>,
+        /// Die Seite der Konferenzen. Default ist 0.
+        pub page: Option<u64>,
+    }
+    |this| Params::new()
+        .add("type", &this.conference_type)
+        .add("p", &this.page)
+}
+
+impl Paged for GetConferences {
+    type Item = Conference;
+
+    /// Der Server liefert immer 48 Konferenzen pro Seite.
+    fn default_page_size(&self) -> u64 {
+        48
+    }
+
+    fn set_page(&mut self, page: u64, _limit: u64) -> u64 {
+        self.page = Some(page);
+        48
+    }
+
+    fn into_items(output: Vec<Conference>) -> Vec<Conference> {
+        output
+    }
+}
+
+endpoint! {
+    /// Liefert Informationen zu einer Konferenz. Siehe `Messenger::get_conference_info`.
+    pub struct GetConferenceInfo -> ConferenceInfo ["messenger", "conferenceinfo", login] {
+        /// Die ID der Konferenz.
+        pub conference_id: u64,
+    }
+    |this| Params::new().add("conference_id", &this.conference_id)
+}
+
+endpoint! {
+    /// Liefert Informationen zu einem Benutzer. Siehe `Messenger::get_user_info`.
+    pub struct GetUserInfo -> UserInfo ["messenger", "userinfo", login] {
+        /// Die ID des betroffenen Benutzers.
+        pub user_id: u64,
+    }
+    |this| Params::new().add("user_id", &this.user_id)
+}
+
+endpoint! {
+    /// Liefert die letzten Nachrichten einer Konferenz. Siehe `Messenger::get_messages`.
+    pub struct GetMessages -> Vec<Messages> ["messenger", "messages", login] {
+        /// Die Konferenz-ID.
+        pub conference_id: Option<u64>,
+        /// Die Nachrichten-ID.
+        pub message: Option<u64>,
+        /// Ob die Konferenz als gelesen markiert werden soll. Default: `true`.
+        pub read: Option<bool>,
+    }
+    |this| Params::new()
+        .add("conference_id", &this.conference_id)
+        .add("message", &this.message)
+        .add("read", &this.read)
+}
+
+endpoint! {
+    /// Erstellt eine Unterhaltung zwischen zwei Benutzern. Siehe `Messenger::new_conference`.
+    pub struct NewConference -> u64 ["messenger", "newconference", mutates, login] {
+        /// Die erste Nachricht.
+        pub text: String,
+        /// Der Benutzername des Empfängers.
+        pub username: String,
+    }
+    |this| Params::new()
+        .add("text", &this.text)
+        .add("username", &this.username)
+}
+
+endpoint! {
+    /// Erstellt eine Gruppen-Unterhaltung. Siehe `Messenger::new_conferencegroup`.
+    pub struct NewConferenceGroup -> u64 ["messenger", "newconferencegroup", mutates, login] {
+        /// Die Benutzernamen der Teilnehmer.
+        pub users: Vec<String>,
+        /// Das Thema/der Name der Konferenz.
+        pub tropic: String,
+        /// Die erste Nachricht der Konferenz.
+        pub text: Option<String>,
+    }
+    |this| Params::new()
+        .add("users", &this.users)
+        .add("tropic", &this.tropic)
+        .add("text", &this.text)
+}
+
+endpoint! {
+    /// Meldet eine Konferenz bei der Proxer-Administration. Siehe `Messenger::report`.
+    pub struct Report -> () ["messenger", "report", mutates, login] {
+        /// Ein kurzer Grund für die Meldung.
+        pub text: String,
+        /// Die ID der Konferenz.
+        pub conference_id: u64,
+    }
+    |this| Params::new()
+        .add("text", &this.text)
+        .add("conference_id", &this.conference_id)
+}
+
+endpoint! {
+    /// Sendet eine Nachricht an eine Konferenz. Siehe `Messenger::set_message`.
+    pub struct SetMessage -> String ["messenger", "setmessage", mutates, login] {
+        /// Die Konferenz-ID.
+        pub conference_id: u64,
+        /// Die Nachricht oder ein Befehl.
+        pub text: String,
+    }
+    |this| Params::new()
+        .add("conference_id", &this.conference_id)
+        .add("text", &this.text)
+}
+
+endpoint! {
+    /// Markiert eine Konferenz als gelesen. Siehe `Messenger::set_read`.
+    pub struct SetRead -> () ["messenger", "setread", mutates, login] {
+        /// Die Konferenz-ID.
+        pub conference_id: u64,
+    }
+    |this| Params::new().add("conference_id", &this.conference_id)
+}
+
+endpoint! {
+    /// Markiert eine Konferenz als ungelesen. Siehe `Messenger::set_unread`.
+    pub struct SetUnread -> () ["messenger", "setunread", mutates, login] {
+        /// Die Konferenz-ID.
+        pub conference_id: u64,
+    }
+    |this| Params::new().add("conference_id", &this.conference_id)
+}
+
+endpoint! {
+    /// Blockiert eine Konferenz. Siehe `Messenger::set_block`.
+    pub struct SetBlock -> () ["messenger", "setblock", mutates, login] {
+        /// Die Konferenz-ID.
+        pub conference_id: u64,
+    }
+    |this| Params::new().add("conference_id", &this.conference_id)
+}
+
+endpoint! {
+    /// Hebt die Blockierung einer Konferenz auf. Siehe `Messenger::set_unblock`.
+    pub struct SetUnblock -> () ["messenger", "setunblock", mutates, login] {
+        /// Die Konferenz-ID.
+        pub conference_id: u64,
+    }
+    |this| Params::new().add("conference_id", &this.conference_id)
+}
+
+endpoint! {
+    /// Markiert eine Konferenz als Favorit. Siehe `Messenger::set_favour`.
+    pub struct SetFavour -> () ["messenger", "setfavour", mutates, login] {
+        /// Die Konferenz-ID.
+        pub conference_id: u64,
+    }
+    |this| Params::new().add("conference_id", &this.conference_id)
+}
+
+endpoint! {
+    /// Entfernt eine Konferenz aus den Favoriten. Siehe `Messenger::set_unfavour`.
+    pub struct SetUnfavour -> () ["messenger", "setunfavour", mutates, login] {
+        /// Die Konferenz-ID.
+        pub conference_id: u64,
+    }
+    |this| Params::new().add("conference_id", &this.conference_id)
+}
+
 /// Der Proxer Messenger ist ein Nachrichtensystem, welches es ermöglichen soll,
 /// über verschiedene Plattformen hinweg, mit anderen Proxer-Nutzern Nachrichten auszutauschen.
 /// Die aktuelle Schnittstelle basiert auf polling. Es ist eine zusätzliche Schnittstelle,
 /// die auf push-Nachrichten und Sockets basiert, geplant.
-#[derive(Debug)]
-pub struct Messenger<'a> {
-    proxer: &'a Proxer,
+#[derive(Debug, Clone)]
+pub struct Messenger {
+    proxer: Proxer,
 }
 
-impl<'a> Messenger<'a> {
-    #[doc(hidden)]
-    pub fn new(proxer: &'a Proxer) -> Messenger<'a> {
-        Messenger { proxer: proxer }
+impl Messenger {
+    pub(crate) fn new(proxer: &Proxer) -> Messenger {
+        Messenger {
+            proxer: proxer.clone(),
+        }
     }
 
     /// Liefert Messengerkonstanten. Bitte höchstens beim erstmaligen Start einer Anwendung durchführen.
     /// Diese Werte werden sich höchstens alle paar Monate mal ändern.
     pub fn get_constants(&self) -> Result<Vec<Constants>> {
-        let url = url!("messenger", "constants");
-        let body = String::new();
-        let response = self.proxer.connect(&url, &body)?;
-        let data: Response<Vec<Constants>> = serde_json::from_reader(response)?;
-        check_error!(data.error, data.code.unwrap_or_default(), data.message);
-        check_data!(data.data)
+        self.proxer.execute(&GetConstants {})
     }
 
     /// Liefert eine Liste der aktuellen Konferenzen.
@@ -179,12 +371,19 @@ impl<'a> Messenger<'a> {
         conference_type: Option<ConferenceOption>,
         page: Option<u64>,
     ) -> Result<Vec<Conference>> {
-        let url = url!("messenger", "conferences");
-        let body = param_build!("type" => conference_type, "p" => page);
-        let response = self.proxer.connect(&url, &body)?;
-        let data: Response<Vec<Conference>> = serde_json::from_reader(response)?;
-        check_error!(data.error, data.code.unwrap_or_default(), data.message);
-        check_data!(data.data)
+        self.proxer.execute(&GetConferences {
+            conference_type,
+            page,
+        })
+    }
+
+    /// Wie `get_conferences`, lädt aber die Konferenzen aller Seiten nacheinander.
+    /// Die Seitengröße ist vom Server fest vorgegeben.
+    pub fn get_conferences_all(&self, conference_type: Option<ConferenceOption>) -> Paginator<GetConferences> {
+        self.proxer.paginate(GetConferences {
+            conference_type,
+            page: None,
+        })
     }
 
     /// Informationen zu einer bestimmten Konferenz.
@@ -193,12 +392,7 @@ impl<'a> Messenger<'a> {
     ///
     /// * `conference_id` - Die ID der Konferenz.
     pub fn get_conference_info(&self, conference_id: u64) -> Result<ConferenceInfo> {
-        let url = url!("messenger", "conferenceinfo");
-        let body = param_build!("conference_id" => Some(conference_id));
-        let response = self.proxer.connect(&url, &body)?;
-        let data: Response<ConferenceInfo> = serde_json::from_reader(response)?;
-        check_error!(data.error, data.code.unwrap_or_default(), data.message);
-        check_data!(data.data)
+        self.proxer.execute(&GetConferenceInfo { conference_id })
     }
 
     /// Informationen zu einem bestimmten Benutzer.
@@ -207,12 +401,7 @@ impl<'a> Messenger<'a> {
     ///
     /// * `user_id` - Die ID des betroffenen Benutzers.
     pub fn get_user_info(&self, user_id: u64) -> Result<UserInfo> {
-        let url = url!("messenger", "userinfo");
-        let body = param_build!("user_id" => Some(user_id));
-        let response = self.proxer.connect(&url, &body)?;
-        let data: Response<UserInfo> = serde_json::from_reader(response)?;
-        check_error!(data.error, data.code.unwrap_or_default(), data.message);
-        check_data!(data.data)
+        self.proxer.execute(&GetUserInfo { user_id })
     }
 
     /// Gibt die letzten Nachrichten einer Konferenz/eines Benutzers zurück.
@@ -222,21 +411,18 @@ impl<'a> Messenger<'a> {
     /// * `conference_id` - Die Konferenz-ID.
     /// * `message_id` - Die Nachrichten-ID.
     /// * `read` - Ob eine Konferenz als gelesen markiert werden soll.
-    /// Mögliche String-Werte: "true" (default) oder "false".
+    ///   Mögliche String-Werte: "true" (default) oder "false".
     pub fn get_messages(
         &self,
         conference_id: Option<u64>,
         message: Option<u64>,
         read: Option<bool>,
     ) -> Result<Vec<Messages>> {
-        let url = url!("messenger", "messages");
-        let body = param_build!("conference_id" => conference_id,
-            "message" => message,
-            "read" => read);
-        let response = self.proxer.connect(&url, &body)?;
-        let data: Response<Vec<Messages>> = serde_json::from_reader(response)?;
-        check_error!(data.error, data.code.unwrap_or_default(), data.message);
-        check_data!(data.data)
+        self.proxer.execute(&GetMessages {
+            conference_id,
+            message,
+            read,
+        })
     }
 
     /// Erstellt eine neue Unterhaltung, oder fügt eine Nachricht einer vorhandenen Unterhaltung hinzu (zwei Benutzer).
@@ -246,13 +432,7 @@ impl<'a> Messenger<'a> {
     /// * `text` Eine Eingabenachricht. Beim Erstellen von Konferenzen werden Befehlseingaben ignoriert.
     /// * `username` Der Benutzername eines Proxer-Nutzers, an die eine Nachricht gesendet werden soll.
     pub fn new_conference(&self, text: String, username: String) -> Result<u64> {
-        let url = url!("messenger", "newconference");
-        let body = param_build!("text" => Some(text),
-            "username" => Some(username));
-        let response = self.proxer.connect(&url, &body)?;
-        let data: Response<u64> = serde_json::from_reader(response)?;
-        check_error!(data.error, data.code.unwrap_or_default(), data.message);
-        check_data!(data.data)
+        self.proxer.execute(&NewConference { text, username })
     }
 
     /// Erstellt eine neue Gruppen-Unterhaltung.
@@ -260,24 +440,17 @@ impl<'a> Messenger<'a> {
     /// # Arguments
     ///
     /// * `users` Ein Array aus Benutzernamen. Benutzer, die einer Konferenz hinzugefügt werden sollen.
-    /// Eingeschränkt durch die Konstante userLimit.
+    ///   Eingeschränkt durch die Konstante userLimit.
     /// * `topic` Das Thema/der Name der Konferenz. Eingeschränkt durch die Konstante topicCount.
     /// * `text` Die erste Nachricht der neu erstellten Konferenz.
-    /// Eingeschränkt durch die Konstante textCount. Beim Erstellen von Konferenzen werden Befehlseingaben ignoriert.
+    ///   Eingeschränkt durch die Konstante textCount. Beim Erstellen von Konferenzen werden Befehlseingaben ignoriert.
     pub fn new_conferencegroup(
         &self,
-        users: String,
+        users: Vec<String>,
         tropic: String,
         text: Option<String>,
     ) -> Result<u64> {
-        let url = url!("messenger", "newconferencegroup");
-        let body = param_build!("users" => Some(users),
-            "tropic" => Some(tropic),
-            "text" => text);
-        let response = self.proxer.connect(&url, &body)?;
-        let data: Response<u64> = serde_json::from_reader(response)?;
-        check_error!(data.error, data.code.unwrap_or_default(), data.message);
-        check_data!(data.data)
+        self.proxer.execute(&NewConferenceGroup { users, tropic, text })
     }
 
     /// Funktionalität zum Melden einer Konferenz bei der Proxer-Administration.
@@ -287,13 +460,7 @@ impl<'a> Messenger<'a> {
     /// * `text` - Ein kurzer Grund, weshalb die Konferenz gemeldet wird.
     /// * `conference_id` ID der Konferenz, die gemeldet werden soll.
     pub fn report(&self, text: String, conference_id: u64) -> Result<()> {
-        let url = url!("messenger", "report");
-        let body = param_build!("text" => Some(text),
-            "conference_id" => Some(conference_id));
-        let response = self.proxer.connect(&url, &body)?;
-        let data: EmptyResponse = serde_json::from_reader(response)?;
-        check_error!(data.error, data.code.unwrap_or_default(), data.message);
-        Ok(())
+        self.proxer.execute(&Report { text, conference_id })
     }
 
     /// Sendet eine Nachricht an eine bestimmte Konferenz.
@@ -302,18 +469,12 @@ impl<'a> Messenger<'a> {
     ///
     /// * `conference_id` - Die Konferenz-ID.
     /// * `text` - Eine Eingabenachricht. Die Nachricht ist eingeschränkt durch die Konstante textCount. Es gibt zwei Arten von Nachrichten:
-    /// Normale Nachricht: Eine vom Benutzer abgesendete Nachricht.
-    /// Eine Befehl: Ein Befehl fängt mit einem Schrägstrich an.
-    /// Ein Beispiel für ein Befehl ist wie folgt: /addUser ProxerBot.
-    /// Dieser Befehl würde den Benutzer ProxerBot zu der aktuellen Konferenz hinzufügen.
+    ///   Normale Nachricht: Eine vom Benutzer abgesendete Nachricht.
+    ///   Eine Befehl: Ein Befehl fängt mit einem Schrägstrich an.
+    ///   Ein Beispiel für ein Befehl ist wie folgt: /addUser ProxerBot.
+    ///   Dieser Befehl würde den Benutzer ProxerBot zu der aktuellen Konferenz hinzufügen.
     pub fn set_message(&self, conference_id: u64, text: String) -> Result<String> {
-        let url = url!("messenger", "setmessage");
-        let body = param_build!("conference_id" => Some(conference_id),
-            "text" => Some(text));
-        let response = self.proxer.connect(&url, &body)?;
-        let data: Response<String> = serde_json::from_reader(response)?;
-        check_error!(data.error, data.code.unwrap_or_default(), data.message);
-        check_data!(data.data)
+        self.proxer.execute(&SetMessage { conference_id, text })
     }
 
     /// Markiert eine Konferenz als gelesen (read=true, read_count=0). Beachte bitte: Funktion Get Messages.
@@ -322,12 +483,7 @@ impl<'a> Messenger<'a> {
     ///
     /// * `conference_id` - Die Konferenz-ID.
     pub fn set_read(&self, conference_id: u64) -> Result<()> {
-        let url = url!("messenger", "setread");
-        let body = param_build!("conference_id" => Some(conference_id));
-        let response = self.proxer.connect(&url, &body)?;
-        let data: EmptyResponse = serde_json::from_reader(response)?;
-        check_error!(data.error, data.code.unwrap_or_default(), data.message);
-        Ok(())
+        self.proxer.execute(&SetRead { conference_id })
     }
 
     /// Markiert eine Konferenz als ungelesen. Beachte bitte: Funktion Get Messages.
@@ -336,12 +492,7 @@ impl<'a> Messenger<'a> {
     ///
     /// * `conference_id` - Die Konferenz-ID.
     pub fn set_unread(&self, conference_id: u64) -> Result<()> {
-        let url = url!("messenger", "setunread");
-        let body = param_build!("conference_id" => Some(conference_id));
-        let response = self.proxer.connect(&url, &body)?;
-        let data: EmptyResponse = serde_json::from_reader(response)?;
-        check_error!(data.error, data.code.unwrap_or_default(), data.message);
-        Ok(())
+        self.proxer.execute(&SetUnread { conference_id })
     }
 
     /// Markiert eine Konferenz als blockiert.
@@ -350,12 +501,7 @@ impl<'a> Messenger<'a> {
     ///
     /// * `conference_id` - Die Konferenz-ID.
     pub fn set_block(&self, conference_id: u64) -> Result<()> {
-        let url = url!("messenger", "setblock");
-        let body = param_build!("conference_id" => Some(conference_id));
-        let response = self.proxer.connect(&url, &body)?;
-        let data: EmptyResponse = serde_json::from_reader(response)?;
-        check_error!(data.error, data.code.unwrap_or_default(), data.message);
-        Ok(())
+        self.proxer.execute(&SetBlock { conference_id })
     }
 
     /// Hebt eine Blockierung einer Konferenz auf.
@@ -364,12 +510,7 @@ impl<'a> Messenger<'a> {
     ///
     /// * `conference_id` - Die Konferenz-ID.
     pub fn set_unblock(&self, conference_id: u64) -> Result<()> {
-        let url = url!("messenger", "setunblock");
-        let body = param_build!("conference_id" => Some(conference_id));
-        let response = self.proxer.connect(&url, &body)?;
-        let data: EmptyResponse = serde_json::from_reader(response)?;
-        check_error!(data.error, data.code.unwrap_or_default(), data.message);
-        Ok(())
+        self.proxer.execute(&SetUnblock { conference_id })
     }
 
     /// Markiert eine Konferenz als favorit.
@@ -378,12 +519,7 @@ impl<'a> Messenger<'a> {
     ///
     /// * `conference_id` - Die Konferenz-ID.
     pub fn set_favour(&self, conference_id: u64) -> Result<()> {
-        let url = url!("messenger", "setfavour");
-        let body = param_build!("conference_id" => Some(conference_id));
-        let response = self.proxer.connect(&url, &body)?;
-        let data: EmptyResponse = serde_json::from_reader(response)?;
-        check_error!(data.error, data.code.unwrap_or_default(), data.message);
-        Ok(())
+        self.proxer.execute(&SetFavour { conference_id })
     }
 
     /// Hebt eine Favorisierung einer Konferenz auf.
@@ -392,11 +528,6 @@ impl<'a> Messenger<'a> {
     ///
     /// * `conference_id` - Die Konferenz-ID.
     pub fn set_unfavour(&self, conference_id: u64) -> Result<()> {
-        let url = url!("messenger", "setunfavour");
-        let body = param_build!("conference_id" => Some(conference_id));
-        let response = self.proxer.connect(&url, &body)?;
-        let data: EmptyResponse = serde_json::from_reader(response)?;
-        check_error!(data.error, data.code.unwrap_or_default(), data.message);
-        Ok(())
+        self.proxer.execute(&SetUnfavour { conference_id })
     }
 }
@@ -0,0 +1,118 @@
+//! Hooks für Logging, Metriken und Auditing.
+//!
+//! Eine `Middleware` wird mit `ProxerBuilder::middleware` gesetzt und bei jedem Versuch einer
+//! Anfrage an den Server aufgerufen, auch bei Wiederholungen durch die Retry-Policy.
+//! Antworten aus dem Zwischenspeicher und geteilte Antworten gleichzeitiger Anfragen
+//! (siehe `batch`) lösen keine Aufrufe aus.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use proxer::Proxer;
+//! use proxer::middleware;
+//!
+//! let proxer = Proxer::builder("api-key")
+//!     .middleware(middleware::after_response(|request, response| {
+//!         println!("{}/{} -> {:?} in {:?}", request.api_class, request.api_function,
+//!             response.status, response.latency);
+//!     }))
+//!     .build()
+//!     .unwrap();
+//! ```
+
+use std::fmt;
+use std::time::Duration;
+
+use error::*;
+use params::Params;
+
+/// Die Daten einer Anfrage, wie sie an eine `Middleware` übergeben werden.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RequestInfo<'a> {
+    /// Die API-Klasse, z.B. "info".
+    pub api_class: &'a str,
+    /// Die API-Funktion, z.B. "fullentry".
+    pub api_function: &'a str,
+    /// Die Parameter der Anfrage. Passwörter und Nachrichtentexte sind durch "***" ersetzt, siehe `Params::redacted`.
+    pub params: &'a Params,
+    /// Ob die Funktion Daten auf dem Server verändert.
+    pub mutating: bool,
+}
+
+/// Das Ergebnis einer Anfrage, wie es an eine `Middleware` übergeben wird.
+#[derive(Debug)]
+pub struct ResponseInfo<'a> {
+    /// Der HTTP-Statuscode, falls eine Antwort empfangen wurde.
+    pub status: Option<u16>,
+    /// Der Fehlercode, falls der Server einen gemeldet hat.
+    pub error_code: Option<ProxerErrorCode>,
+    /// Die Zeit vom Senden bis zum Empfang der Antwort.
+    pub latency: Duration,
+    /// Der Fehler, falls die Anfrage auf HTTP-Ebene fehlgeschlagen ist.
+    /// Fehlercodes des Servers stehen nur in `error_code`.
+    pub error: Option<&'a Error>,
+}
+
+/// Wird vor und nach jeder Anfrage an den Server aufgerufen.
+///
+/// Beide Methoden sind standardmäßig leer. Sie werden auf dem Thread der Anfrage
+/// ausgeführt und sollten daher nicht blockieren.
+pub trait Middleware: Send + Sync {
+    /// Wird aufgerufen, bevor die Anfrage gesendet wird.
+    fn before_request(&self, _request: &RequestInfo) {}
+
+    /// Wird aufgerufen, nachdem eine Antwort empfangen wurde oder die Anfrage fehlgeschlagen ist.
+    fn after_response(&self, _request: &RequestInfo, _response: &ResponseInfo) {}
+}
+
+/// Eine `Middleware`, die nur `before_request` mit `hook` umsetzt.
+pub fn before_request<F>(hook: F) -> BeforeRequest<F>
+where
+    F: Fn(&RequestInfo) + Send + Sync,
+{
+    BeforeRequest(hook)
+}
+
+/// Eine `Middleware`, die nur `after_response` mit `hook` umsetzt.
+pub fn after_response<F>(hook: F) -> AfterResponse<F>
+where
+    F: Fn(&RequestInfo, &ResponseInfo) + Send + Sync,
+{
+    AfterResponse(hook)
+}
+
+/// Siehe `before_request`.
+pub struct BeforeRequest<F>(F);
+
+impl<F> Middleware for BeforeRequest<F>
+where
+    F: Fn(&RequestInfo) + Send + Sync,
+{
+    fn before_request(&self, request: &RequestInfo) {
+        (self.0)(request)
+    }
+}
+
+impl<F> fmt::Debug for BeforeRequest<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("BeforeRequest").finish()
+    }
+}
+
+/// Siehe `after_response`.
+pub struct AfterResponse<F>(F);
+
+impl<F> Middleware for AfterResponse<F>
+where
+    F: Fn(&RequestInfo, &ResponseInfo) + Send + Sync,
+{
+    fn after_response(&self, request: &RequestInfo, response: &ResponseInfo) {
+        (self.0)(request, response)
+    }
+}
+
+impl<F> fmt::Debug for AfterResponse<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AfterResponse").finish()
+    }
+}
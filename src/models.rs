@@ -1,17 +1,23 @@
 use std::fmt;
 
+use lenient;
+
 #[derive(Deserialize, Debug, Clone, PartialEq)]
 pub struct Response<T> {
+    #[serde(with = "lenient")]
     pub error: u8,
     pub message: String,
+    #[serde(default, with = "lenient")]
     pub code: Option<u16>,
     pub data: Option<T>,
 }
 
 #[derive(Deserialize, Debug, Clone, PartialEq)]
 pub struct EmptyResponse {
+    #[serde(with = "lenient")]
     pub error: u8,
     pub message: String,
+    #[serde(default, with = "lenient")]
     pub code: Option<u16>,
 }
 
@@ -22,8 +28,9 @@ pub struct EmptyResponse {
 /// * `Anime` - Ist ein Anime
 /// * `Manga` - Ist ein Managa
 #[derive(Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "lowercase")]
 pub enum Kategorie {
-    #[serde(rename = "anime")] Anime,
+    Anime,
     Manga,
 }
 
@@ -50,6 +57,7 @@ impl fmt::Display for Kategorie {
 /// * `Doujin` - Das Medium ist ein Doujin
 /// * `Hmanga` - Das Medium ist ein Hmanaga
 #[derive(Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "lowercase")]
 pub enum Medium {
     Animeseries,
     Movie,
@@ -77,7 +85,7 @@ impl fmt::Display for Medium {
     }
 }
 
-///	Nach was die angefragte List sortiert werden soll.
+/// Nach was die angefragte List sortiert werden soll.
 ///
 /// # Description
 ///
@@ -349,10 +357,10 @@ impl fmt::Display for TranslationStatus {
 ///
 /// # Description
 ///
-///	* `favour` - Favorisierte Konferenzen.
-///	* `block` - Blockierte Konferenzen.
-///	* `group` - Listet ausschließlich Gruppenkonferenzen auf.
-///	* `default` - Listet unblockierte Konferenzen.
+/// * `favour` - Favorisierte Konferenzen.
+/// * `block` - Blockierte Konferenzen.
+/// * `group` - Listet ausschließlich Gruppenkonferenzen auf.
+/// * `default` - Listet unblockierte Konferenzen.
 #[derive(Deserialize, Clone, Debug, PartialEq)]
 pub enum ConferenceOption {
     Favour,
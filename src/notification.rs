@@ -1,8 +1,8 @@
-use serde_json;
-
 use error::*;
+use lenient;
+use paginator::Paginator;
+use params::Params;
 use Proxer;
-use models::*;
 
 /// Gibt die neuesten News aus.
 /// Der Bildlink einer News setzt sich zusammen aus: cdn.proxer.me/news/[News-ID]_[Image-ID].png
@@ -11,47 +11,87 @@ use models::*;
 #[derive(Deserialize, Debug, Clone, PartialEq)]
 pub struct News {
     /// Die ID der News
+    #[serde(with = "lenient")]
     pub nid: u64,
     /// Der Zeitpunkt der publizierung (Unix-Timestamp als Sekunden gespeichert)
+    #[serde(with = "lenient")]
     pub time: i64,
     /// Die ID des entsprechenden Forumsbeitrags
+    #[serde(with = "lenient")]
     pub mid: u64,
     /// Die Beschreibung der News
     pub description: String,
     /// ID zum Bild.
+    #[serde(with = "lenient")]
     pub image_id: String,
     /// CSS-Konforme Style-Elemente um die Positionierung des Bildes zu bestimmen.
     pub image_style: String,
     /// Der Titel des entsprechenden Forumsbeitrags
     pub subject: String,
     /// Anzahl der Zugriffe auf den entsprechenden Forumsbeitrag
+    #[serde(with = "lenient")]
     pub hits: u64,
     /// mid
+    #[serde(with = "lenient")]
     pub thread: u64,
     /// User-ID des Erstellers des Forumsposts
+    #[serde(with = "lenient")]
     pub uid: u64,
     /// Benutzername des Autors
     pub uname: String,
     /// Anzahl der Antworten/Kommentare auf die News
+    #[serde(with = "lenient")]
     pub posts: u64,
     /// Die ID der Kategorie, in der sich eine News befindet.
+    #[serde(with = "lenient")]
     pub catid: u64,
     /// Der Name der Kategorie.
     pub catname: String,
 }
 
+endpoint! {
+    /// Liefert die Anzahl der Notifications je Kategorie. Siehe `Notification::get_count`.
+    pub struct GetCount -> String ["notifications", "count", login] {}
+    |this| Params::new()
+}
+
+endpoint! {
+    /// Liefert die neuesten News. Siehe `Notification::get_news_per_api`.
+    pub struct GetNews -> Vec<News> ["notifications", "news"] {
+        /// Die zu ladende Seite, beginnend ab 0.
+        pub page: Option<u64>,
+        /// Die Anzahl der zu ladenden News pro Seite. Default 15.
+        pub limit: Option<u64>,
+    }
+    |this| Params::new()
+        .add("p", &this.page)
+        .add("limit", &this.limit)
+}
+
+paged!(GetNews => News, 15);
+
+endpoint! {
+    /// Löscht eine Notification. Siehe `Notification::delete_notification`.
+    pub struct DeleteNotification -> () ["notifications", "delete", mutates, login] {
+        /// Die ID der zu löschenden Notification. Ohne ID werden alle gelesenen gelöscht.
+        pub nid: Option<u64>,
+    }
+    |this| Params::new().add("nid", &this.nid)
+}
+
 /// Diese Klasse beinhaltet alle Schnittstellen,
 /// die mit Daten zu tun haben, die normalerweise auf Proxer oben rechts bei den Notifications zu sehen sind,
 /// insbesondere News und Benachrichtigungen.
-#[derive(Debug)]
-pub struct Notification<'a> {
-    proxer: &'a Proxer,
+#[derive(Debug, Clone)]
+pub struct Notification {
+    proxer: Proxer,
 }
 
-impl<'a> Notification<'a> {
-    #[doc(hidden)]
-    pub fn new(proxer: &'a Proxer) -> Notification<'a> {
-        Notification { proxer: proxer }
+impl Notification {
+    pub(crate) fn new(proxer: &Proxer) -> Notification {
+        Notification {
+            proxer: proxer.clone(),
+        }
     }
 
     /// Diese Funktion gibt die Anzahl an verschiedenen Notification-Kategorien zurück (kleine rote Zahlen auf Proxer).
@@ -64,12 +104,7 @@ impl<'a> Notification<'a> {
     /// * 4 = News
     /// * 5 = Benachrichtigungen
     pub fn get_count(&self) -> Result<String> {
-        let url = url!("notifications", "count");
-        let body = String::new();
-        let response = self.proxer.connect(&url, &body)?;
-        let data: Response<String> = serde_json::from_reader(response)?;
-        check_error!(data.error, data.code.unwrap_or_default(), data.message);
-        check_data!(data.data)
+        self.proxer.execute(&GetCount {})
     }
 
     /// Gibt die neuesten News aus.
@@ -80,15 +115,15 @@ impl<'a> Notification<'a> {
     /// # Arguments
     ///
     /// * `page` - Die zu ladende Seite, beginnend ab 0 (Auf Seite 0 befinden sich die neuesten News,
-    /// nach hinten werden die News älter). Wenn nicht gegeben, so wird die erste Seite geladen.
+    ///   nach hinten werden die News älter). Wenn nicht gegeben, so wird die erste Seite geladen.
     /// * `limit` - Die Anzahl der zu ladenden News pro Seite. Default 15.
     pub fn get_news_per_api(&self, page: Option<u64>, limit: Option<u64>) -> Result<Vec<News>> {
-        let url = url!("notifications", "news");
-        let body = param_build!("p" => page, "limit" => limit);
-        let response = self.proxer.connect(&url, &body)?;
-        let data: Response<Vec<News>> = serde_json::from_reader(response)?;
-        check_error!(data.error, data.code.unwrap_or_default(), data.message);
-        check_data!(data.data)
+        self.proxer.execute(&GetNews { page, limit })
+    }
+
+    /// Wie `get_news_per_api`, lädt aber die News aller Seiten nacheinander, beginnend mit den neuesten.
+    pub fn get_news_per_api_all(&self) -> Paginator<GetNews> {
+        self.proxer.paginate(GetNews { page: None, limit: None })
     }
 
     /// Löscht eine gegebene Notification
@@ -96,13 +131,8 @@ impl<'a> Notification<'a> {
     /// # Arguments
     ///
     /// * `nid` - Die ID der zu löschenden Notification.
-    /// Wenn weggelassen oder 0, so werden alle als gelesen markierten Benachrichtigungen gelöscht.
+    ///   Wenn weggelassen oder 0, so werden alle als gelesen markierten Benachrichtigungen gelöscht.
     pub fn delete_notification(&self, nid: Option<u64>) -> Result<()> {
-        let url = url!("notifications", "delete");
-        let body = param_build!("nid" => nid);
-        let response = self.proxer.connect(&url, &body)?;
-        let data: EmptyResponse = serde_json::from_reader(response)?;
-        check_error!(data.error, 0, data.message);
-        Ok(())
+        self.proxer.execute(&DeleteNotification { nid })
     }
 }
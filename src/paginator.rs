@@ -0,0 +1,270 @@
+//! Seitenweises Laden von Listen.
+//!
+//! Viele API-Funktionen liefern ihre Daten in Seiten (`p`/`limit`). Ein `Paginator` lädt
+//! diese Seiten erst bei Bedarf und gibt die Einträge einzeln zurück.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use proxer::Proxer;
+//! use proxer::list::SearchEntry;
+//!
+//! let proxer = Proxer::new("api-key").unwrap();
+//! let query = SearchEntry { name: Some("Naruto".to_owned()), ..SearchEntry::default() };
+//! for entry in proxer.list().entry_search_all(query).max_items(250) {
+//!     println!("{}", entry.unwrap().name);
+//! }
+//! ```
+
+use std::collections::VecDeque;
+use std::fmt;
+
+use endpoint::Endpoint;
+use error::*;
+use Proxer;
+
+/// Eine API-Funktion, deren Daten in Seiten geliefert werden.
+pub trait Paged: Endpoint {
+    /// Der Typ eines einzelnen Eintrags.
+    type Item;
+
+    /// Die Seitengröße, die ohne `Paginator::page_size` verwendet wird.
+    fn default_page_size(&self) -> u64;
+
+    /// Setzt die zu ladende Seite und die gewünschte Seitengröße.
+    /// Geliefert wird die Seitengröße, die der Server tatsächlich verwendet.
+    fn set_page(&mut self, page: u64, limit: u64) -> u64;
+
+    /// Zerlegt die Daten einer Seite in ihre Einträge.
+    fn into_items(output: Self::Output) -> Vec<Self::Item>;
+}
+
+/// Liefert die Einträge einer `Paged`-Funktion über alle Seiten hinweg.
+///
+/// Die nächste Seite wird erst angefragt, wenn alle Einträge der vorherigen zurückgegeben wurden,
+/// jede Seite durchläuft also wie gewohnt Zwischenspeicher, Rate-Limit und Retry-Policy.
+/// Die Iteration endet nach der ersten Seite, die weniger Einträge als die Seitengröße enthält,
+/// nach `max_items` Einträgen oder nach einem Fehler. Nur nach `Error::BudgetExceeded`
+/// kann weiter iteriert werden, dabei wird dieselbe Seite erneut angefragt.
+pub struct Paginator<E: Paged> {
+    proxer: Proxer,
+    endpoint: E,
+    page: u64,
+    page_size: u64,
+    max_items: Option<u64>,
+    returned: u64,
+    buffer: VecDeque<E::Item>,
+    finished: bool,
+}
+
+impl<E: Paged> Paginator<E> {
+    /// Erstellt einen `Paginator`, der bei Seite 0 beginnt. `page` und `limit` von `endpoint` werden überschrieben.
+    pub fn new(proxer: &Proxer, endpoint: E) -> Paginator<E> {
+        Paginator {
+            proxer: proxer.clone(),
+            page_size: endpoint.default_page_size(),
+            endpoint,
+            page: 0,
+            max_items: None,
+            returned: 0,
+            buffer: VecDeque::new(),
+            finished: false,
+        }
+    }
+
+    /// Die Anzahl der Einträge pro Seite. Default: der Default der jeweiligen API-Funktion.
+    pub fn page_size(mut self, page_size: u64) -> Self {
+        self.page_size = page_size.max(1);
+        self
+    }
+
+    /// Gibt höchstens `max_items` Einträge zurück. Default: alle.
+    pub fn max_items(mut self, max_items: u64) -> Self {
+        self.max_items = Some(max_items);
+        self
+    }
+
+    /// Beginnt bei der Seite `page` (Start bei 0).
+    pub fn start_page(mut self, page: u64) -> Self {
+        self.page = page;
+        self
+    }
+
+    /// Die als Nächstes zu ladende Seite.
+    pub fn current_page(&self) -> u64 {
+        self.page
+    }
+
+    fn fetch(&mut self) -> Result<()> {
+        let page_size = self.endpoint.set_page(self.page, self.page_size);
+        debug!("Loading page {} of {}/{}", self.page, self.endpoint.api_class(), self.endpoint.api_function());
+        match self.proxer.execute(&self.endpoint) {
+            Ok(output) => {
+                let items = E::into_items(output);
+                self.finished = (items.len() as u64) < page_size;
+                self.buffer.extend(items);
+                self.page += 1;
+                Ok(())
+            }
+            Err(err) => {
+                self.finished = !matches!(err, Error::BudgetExceeded { .. });
+                Err(err)
+            }
+        }
+    }
+}
+
+impl<E: Paged> Iterator for Paginator<E> {
+    type Item = Result<E::Item>;
+
+    fn next(&mut self) -> Option<Result<E::Item>> {
+        loop {
+            if self.max_items.is_some_and(|max| self.returned >= max) {
+                return None;
+            }
+            if let Some(item) = self.buffer.pop_front() {
+                self.returned += 1;
+                return Some(Ok(item));
+            }
+            if self.finished {
+                return None;
+            }
+            if let Err(err) = self.fetch() {
+                return Some(Err(err));
+            }
+        }
+    }
+}
+
+impl<E: Paged + fmt::Debug> fmt::Debug for Paginator<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Paginator")
+            .field("endpoint", &self.endpoint)
+            .field("page", &self.page)
+            .field("page_size", &self.page_size)
+            .field("max_items", &self.max_items)
+            .field("returned", &self.returned)
+            .field("finished", &self.finished)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+    use std::time::Duration;
+
+    use super::*;
+    use params::Params;
+    use ratelimit::{RateLimit, RateLimitMode, RateLimiter};
+    use testing::{self, ScriptedTransport};
+
+    endpoint! {
+        pub struct GetNumbers -> Vec<u64> ["test", "numbers"] {
+            pub page: Option<u64>,
+            pub limit: Option<u64>,
+        }
+        |this| Params::new().add("p", &this.page).add("limit", &this.limit)
+    }
+
+    paged!(GetNumbers => u64, 3);
+
+    fn numbers() -> GetNumbers {
+        GetNumbers { page: None, limit: None }
+    }
+
+    fn pages(transport: &ScriptedTransport) -> Vec<(String, String)> {
+        transport
+            .requests()
+            .iter()
+            .map(|request| (testing::param(request, "p").unwrap(), testing::param(request, "limit").unwrap()))
+            .collect()
+    }
+
+    fn page(page: u64, limit: u64) -> (String, String) {
+        (page.to_string(), limit.to_string())
+    }
+
+    #[test]
+    fn stops_after_short_page() {
+        let transport = ScriptedTransport::new().json(&testing::ok("[1,2,3]")).json(&testing::ok("[4,5]"));
+        let items: Vec<u64> = transport.proxer().paginate(numbers()).map(Result::unwrap).collect();
+
+        assert_eq!(items, vec![1, 2, 3, 4, 5]);
+        assert_eq!(pages(&transport), vec![page(0, 3), page(1, 3)]);
+    }
+
+    #[test]
+    fn stops_after_empty_page() {
+        let transport = ScriptedTransport::new()
+            .json(&testing::ok("[1,2]"))
+            .json(&testing::ok("[3,4]"))
+            .json(&testing::ok("[]"));
+        let paginator = transport.proxer().paginate(numbers()).page_size(2).start_page(4);
+        let items: Vec<u64> = paginator.map(Result::unwrap).collect();
+
+        assert_eq!(items, vec![1, 2, 3, 4]);
+        assert_eq!(pages(&transport), vec![page(4, 2), page(5, 2), page(6, 2)]);
+    }
+
+    #[test]
+    fn stops_at_max_items_without_loading_more() {
+        let transport = ScriptedTransport::new().json(&testing::ok("[1,2,3]")).json(&testing::ok("[4,5,6]"));
+        let items: Vec<u64> = transport
+            .proxer()
+            .paginate(numbers())
+            .max_items(4)
+            .map(Result::unwrap)
+            .collect();
+
+        assert_eq!(items, vec![1, 2, 3, 4]);
+        assert_eq!(transport.count(), 2);
+
+        let transport = ScriptedTransport::new().json(&testing::ok("[1,2,3]"));
+        let items: Vec<u64> = transport
+            .proxer()
+            .paginate(numbers())
+            .max_items(3)
+            .map(Result::unwrap)
+            .collect();
+        assert_eq!(items, vec![1, 2, 3]);
+        assert_eq!(transport.count(), 1);
+    }
+
+    #[test]
+    fn stops_after_error() {
+        let transport = ScriptedTransport::new()
+            .json(&testing::ok("[1,2,3]"))
+            .json(&testing::api_error(3004))
+            .json(&testing::ok("[4,5,6]"));
+        let mut paginator = transport.proxer().paginate(numbers());
+
+        assert_eq!(paginator.by_ref().take(3).map(Result::unwrap).collect::<Vec<_>>(), vec![1, 2, 3]);
+        match paginator.next() {
+            Some(Err(Error::ProxerError(ProxerErrorCode::UcpNotLoggedIn, _))) => {}
+            other => panic!("unexpected result: {:?}", other),
+        }
+        assert!(paginator.next().is_none());
+        assert_eq!(transport.count(), 2);
+    }
+
+    #[test]
+    fn continues_after_budget_exceeded() {
+        let transport = ScriptedTransport::new().json(&testing::ok("[1,2,3]")).json(&testing::ok("[4]"));
+        let limiter = RateLimiter::new(RateLimit::new(1, Duration::from_millis(50))).mode(RateLimitMode::Error);
+        let proxer = transport.builder().rate_limit(limiter).build().unwrap();
+        let mut paginator = proxer.paginate(numbers());
+
+        assert_eq!(paginator.by_ref().take(3).map(Result::unwrap).collect::<Vec<_>>(), vec![1, 2, 3]);
+        match paginator.next() {
+            Some(Err(Error::BudgetExceeded { .. })) => {}
+            other => panic!("unexpected result: {:?}", other),
+        }
+        assert_eq!(paginator.current_page(), 1);
+
+        thread::sleep(Duration::from_millis(60));
+        assert_eq!(paginator.next().unwrap().unwrap(), 4);
+        assert!(paginator.next().is_none());
+        assert_eq!(pages(&transport), vec![page(0, 3), page(1, 3)]);
+    }
+}
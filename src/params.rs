@@ -0,0 +1,249 @@
+//! Die Parameter einer Anfrage.
+//!
+//! Alle Parameter werden als `application/x-www-form-urlencoded` gesendet. `Params` kodiert
+//! dabei reservierte Zeichen (`&`, `=`, `+`, ...) und Umlaute, sodass Nachrichten, Suchbegriffe
+//! oder Passwörter unverändert beim Server ankommen.
+
+use std::fmt;
+
+use url::form_urlencoded;
+
+use models::*;
+
+/// Parameter, deren Werte in Logs und an eine `Middleware` nur als "***" weitergegeben werden:
+/// Passwörter, Einmal-Codes und Nachrichtentexte.
+const SECRET_PARAMS: &[&str] = &["password", "secretkey", "text"];
+
+/// Die Parameter einer Anfrage in der Reihenfolge, in der sie hinzugefügt wurden.
+///
+/// # Example
+///
+/// ```
+/// use proxer::params::Params;
+///
+/// let params = Params::new()
+///     .add("text", "Tom & Jerry")
+///     .add("p", &Some(2))
+///     .add("limit", &None::<u64>)
+///     .add("users", &vec!["a", "b"]);
+/// assert_eq!(params.encode(), "text=Tom+%26+Jerry&p=2&users%5B%5D=a&users%5B%5D=b");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Params {
+    pairs: Vec<(String, String)>,
+}
+
+impl Params {
+    /// Erstellt eine leere Parameterliste.
+    pub fn new() -> Params {
+        Params::default()
+    }
+
+    /// Fügt `value` unter dem Namen `name` hinzu. Siehe `ToParam`.
+    pub fn add<T: ToParam + ?Sized>(mut self, name: &str, value: &T) -> Self {
+        value.append_to(name, &mut self);
+        self
+    }
+
+    /// Fügt ein bereits formatiertes Paar hinzu. Ein Name darf mehrfach vorkommen.
+    pub fn push(&mut self, name: &str, value: &str) {
+        self.pairs.push((name.to_owned(), value.to_owned()));
+    }
+
+    /// Ob keine Parameter gesetzt sind.
+    pub fn is_empty(&self) -> bool {
+        self.pairs.is_empty()
+    }
+
+    /// Die Paare in der Reihenfolge, in der sie hinzugefügt wurden.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.pairs.iter().map(|(name, value)| (name.as_str(), value.as_str()))
+    }
+
+    /// Liest bereits kodierte Parameter, z.B. den Body einer `transport::Request`.
+    pub fn parse(encoded: &str) -> Params {
+        let pairs = form_urlencoded::parse(encoded.as_bytes())
+            .map(|(name, value)| (name.into_owned(), value.into_owned()))
+            .collect();
+        Params { pairs }
+    }
+
+    /// Eine Kopie, in der Passwörter, Einmal-Codes und Nachrichtentexte durch "***" ersetzt sind.
+    pub fn redacted(&self) -> Params {
+        let pairs = self
+            .pairs
+            .iter()
+            .map(|(name, value)| {
+                let secret = SECRET_PARAMS.iter().any(|secret| name.eq_ignore_ascii_case(secret));
+                (name.clone(), if secret { "***".to_owned() } else { value.clone() })
+            })
+            .collect();
+        Params { pairs }
+    }
+
+    /// Kodiert die Parameter als `application/x-www-form-urlencoded`.
+    pub fn encode(&self) -> String {
+        form_urlencoded::Serializer::new(String::new())
+            .extend_pairs(self.iter())
+            .finish()
+    }
+}
+
+impl fmt::Display for Params {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.encode())
+    }
+}
+
+impl<'a> From<&'a [(&'a str, &'a str)]> for Params {
+    fn from(pairs: &'a [(&'a str, &'a str)]) -> Params {
+        let mut params = Params::new();
+        for &(name, value) in pairs {
+            params.push(name, value);
+        }
+        params
+    }
+}
+
+/// Ein Wert, der als Parameter gesendet werden kann.
+///
+/// * `None` wird ausgelassen.
+/// * `bool` wird als `true`/`false` gesendet.
+/// * Listen werden als wiederholter Schlüssel `name[]` gesendet.
+pub trait ToParam {
+    /// Fügt den Wert unter dem Namen `name` zu `params` hinzu.
+    fn append_to(&self, name: &str, params: &mut Params);
+}
+
+impl<T: ToParam + ?Sized> ToParam for &T {
+    fn append_to(&self, name: &str, params: &mut Params) {
+        (**self).append_to(name, params)
+    }
+}
+
+impl<T: ToParam> ToParam for Option<T> {
+    fn append_to(&self, name: &str, params: &mut Params) {
+        if let Some(ref value) = *self {
+            value.append_to(name, params)
+        }
+    }
+}
+
+impl<T: ToParam> ToParam for [T] {
+    fn append_to(&self, name: &str, params: &mut Params) {
+        let name = format!("{}[]", name);
+        for value in self {
+            value.append_to(&name, params)
+        }
+    }
+}
+
+impl<T: ToParam> ToParam for Vec<T> {
+    fn append_to(&self, name: &str, params: &mut Params) {
+        self.as_slice().append_to(name, params)
+    }
+}
+
+impl ToParam for bool {
+    fn append_to(&self, name: &str, params: &mut Params) {
+        params.push(name, if *self { "true" } else { "false" })
+    }
+}
+
+macro_rules! display_param
+{
+    ( $( $ty:ty ),* ) =>
+    (
+        $(
+            impl ToParam for $ty {
+                fn append_to(&self, name: &str, params: &mut Params) {
+                    params.push(name, &self.to_string())
+                }
+            }
+        )*
+    );
+}
+
+display_param!(str, String, u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+display_param!(Kategorie, Medium, Sort, Firma, Language, WatchType, SearchSort, LengthLimit, SubType,
+    TranslationStatus, ConferenceOption);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encodes_reserved_characters() {
+        let params = Params::new().add("text", "a&b=c+d e/f?g%h#i");
+        assert_eq!(params.encode(), "text=a%26b%3Dc%2Bd+e%2Ff%3Fg%25h%23i");
+    }
+
+    #[test]
+    fn encodes_unicode() {
+        let params = Params::new().add("name", "Grüße, 進撃の巨人 🎉");
+        assert_eq!(
+            params.encode(),
+            "name=Gr%C3%BC%C3%9Fe%2C+%E9%80%B2%E6%92%83%E3%81%AE%E5%B7%A8%E4%BA%BA+%F0%9F%8E%89"
+        );
+    }
+
+    #[test]
+    fn encodes_names() {
+        let params = Params::new().add("length-limit", "up").add("a b&c", "1");
+        assert_eq!(params.encode(), "length-limit=up&a+b%26c=1");
+    }
+
+    #[test]
+    fn skips_none_and_keeps_order() {
+        let params = Params::new()
+            .add("uid", &3u64)
+            .add("kat", &None::<Kategorie>)
+            .add("p", &Some(0u64))
+            .add("search", &Some("x".to_owned()));
+        assert_eq!(params.encode(), "uid=3&p=0&search=x");
+        assert_eq!(Params::new().add("kat", &None::<Kategorie>).encode(), "");
+        assert!(Params::new().add("kat", &None::<Kategorie>).is_empty());
+    }
+
+    #[test]
+    fn encodes_booleans() {
+        let params = Params::new().add("isH", &true).add("read", &Some(false));
+        assert_eq!(params.encode(), "isH=true&read=false");
+    }
+
+    #[test]
+    fn repeats_list_keys() {
+        let params = Params::new().add("users", &vec!["Üser", "a&b"]);
+        assert_eq!(params.encode(), "users%5B%5D=%C3%9Cser&users%5B%5D=a%26b");
+        assert_eq!(Params::new().add("users", &Vec::<String>::new()).encode(), "");
+    }
+
+    #[test]
+    fn allows_repeated_pairs() {
+        let mut params = Params::from(&[("id", "1"), ("id", "2")][..]);
+        params.push("id", "&");
+        assert_eq!(params.encode(), "id=1&id=2&id=%26");
+    }
+
+    #[test]
+    fn redacts_secrets() {
+        let params = Params::new()
+            .add("username", "user")
+            .add("password", "p&ss")
+            .add("secretkey", "123456")
+            .add("text", "Hallo");
+        assert_eq!(params.redacted().encode(), "username=user&password=***&secretkey=***&text=***");
+    }
+
+    #[test]
+    fn parses_encoded_params() {
+        let params = Params::new().add("name", "Tom & Jerry").add("users", &vec!["ä", "="]);
+        assert_eq!(Params::parse(&params.encode()), params);
+    }
+
+    #[test]
+    fn formats_models() {
+        let params = Params::new().add("kat", &Kategorie::Manga).add("medium", &Medium::Animeseries);
+        assert_eq!(params.encode(), "kat=manga&medium=animeseries");
+    }
+}
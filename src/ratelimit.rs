@@ -0,0 +1,236 @@
+//! Begrenzung der Anfragen an den Proxer-Server.
+//!
+//! Die Nutzungsbedingungen der API verlangen, die Anzahl der Anfragen so gering wie möglich zu halten.
+//! Ein `RateLimiter` verteilt dafür pro API-Klasse ein Budget (Token-Bucket) und wird mit
+//! `ProxerBuilder::rate_limit` gesetzt.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use error::*;
+
+/// Das Budget einer API-Klasse: höchstens `requests` Anfragen innerhalb von `per`.
+///
+/// Verbrauchte Anfragen werden gleichmäßig über den Zeitraum wieder aufgefüllt,
+/// es können also bis zu `requests` Anfragen direkt hintereinander gesendet werden.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimit {
+    requests: u32,
+    per: Duration,
+}
+
+impl RateLimit {
+    /// Höchstens `requests` Anfragen innerhalb von `per`.
+    ///
+    /// Ungültige Werte werden auf das Minimum angehoben: `requests` auf 1 und `per` auf 1ms.
+    pub fn new(requests: u32, per: Duration) -> RateLimit {
+        RateLimit {
+            requests: requests.max(1),
+            per: per.max(Duration::from_millis(1)),
+        }
+    }
+
+    /// Höchstens `requests` Anfragen pro Minute.
+    pub fn per_minute(requests: u32) -> RateLimit {
+        RateLimit::new(requests, Duration::from_secs(60))
+    }
+
+    /// Höchstens `requests` Anfragen pro Stunde.
+    pub fn per_hour(requests: u32) -> RateLimit {
+        RateLimit::new(requests, Duration::from_secs(60 * 60))
+    }
+
+    /// Die Zeit, bis eine verbrauchte Anfrage wieder verfügbar ist.
+    fn interval(&self) -> Duration {
+        self.per / self.requests
+    }
+}
+
+/// Was geschieht, wenn das Budget einer API-Klasse aufgebraucht ist.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RateLimitMode {
+    /// Wartet, bis wieder eine Anfrage verfügbar ist.
+    Block,
+    /// Gibt sofort `Error::BudgetExceeded` zurück.
+    Error,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    updated: Instant,
+}
+
+/// Verwaltet die Budgets aller API-Klassen.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use proxer::Proxer;
+/// use proxer::ratelimit::{RateLimit, RateLimitMode, RateLimiter};
+///
+/// let limiter = RateLimiter::new(RateLimit::per_minute(30))
+///     .class("notifications", RateLimit::new(1, Duration::from_secs(30 * 60)))
+///     .mode(RateLimitMode::Error);
+/// let proxer = Proxer::builder("api-key").rate_limit(limiter).build().unwrap();
+/// ```
+#[derive(Debug)]
+pub struct RateLimiter {
+    default: RateLimit,
+    classes: HashMap<String, RateLimit>,
+    mode: RateLimitMode,
+    buckets: Mutex<HashMap<String, Bucket>>,
+}
+
+impl Default for RateLimiter {
+    /// 30 Anfragen pro Minute für jede API-Klasse, blockierend.
+    fn default() -> RateLimiter {
+        RateLimiter::new(RateLimit::per_minute(30))
+    }
+}
+
+impl RateLimiter {
+    /// Erstellt einen blockierenden `RateLimiter`, der `default` für jede API-Klasse verwendet.
+    pub fn new(default: RateLimit) -> RateLimiter {
+        RateLimiter {
+            default,
+            classes: HashMap::new(),
+            mode: RateLimitMode::Block,
+            buckets: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Setzt ein eigenes Budget für die API-Klasse `api_class` (z.B. "info", "list", "messenger").
+    pub fn class(mut self, api_class: &str, limit: RateLimit) -> Self {
+        self.classes.insert(api_class.to_owned(), limit);
+        self
+    }
+
+    /// Legt fest, was bei aufgebrauchtem Budget geschieht. Default: `RateLimitMode::Block`.
+    pub fn mode(mut self, mode: RateLimitMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Das Budget der API-Klasse `api_class`.
+    pub fn limit(&self, api_class: &str) -> RateLimit {
+        self.classes.get(api_class).cloned().unwrap_or(self.default)
+    }
+
+    /// Verbraucht eine Anfrage aus dem Budget von `api_class`.
+    /// Je nach `RateLimitMode` wird gewartet oder `Error::BudgetExceeded` zurückgegeben.
+    pub fn acquire(&self, api_class: &str) -> Result<()> {
+        loop {
+            let wait = match self.try_acquire(api_class) {
+                None => return Ok(()),
+                Some(wait) => wait,
+            };
+            if self.mode == RateLimitMode::Error {
+                warn!("Request budget for {:?} exceeded, next request in {:?}", api_class, wait);
+                return Err(Error::BudgetExceeded {
+                    api_class: api_class.to_owned(),
+                    retry_after: wait,
+                });
+            }
+            debug!("Request budget for {:?} exceeded, waiting {:?}", api_class, wait);
+            thread::sleep(wait);
+        }
+    }
+
+    /// Verbraucht eine Anfrage, falls verfügbar. Sonst wird die Wartezeit bis zur nächsten Anfrage geliefert.
+    fn try_acquire(&self, api_class: &str) -> Option<Duration> {
+        let limit = self.limit(api_class);
+        let now = Instant::now();
+        let mut buckets = self.buckets.lock().unwrap_or_else(|e| e.into_inner());
+        let bucket = buckets.entry(api_class.to_owned()).or_insert_with(|| Bucket {
+            tokens: f64::from(limit.requests),
+            updated: now,
+        });
+
+        let interval = limit.interval().as_secs_f64();
+        let refilled = now.duration_since(bucket.updated).as_secs_f64() / interval;
+        bucket.tokens = (bucket.tokens + refilled).min(f64::from(limit.requests));
+        bucket.updated = now;
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            None
+        } else {
+            Some(Duration::from_secs_f64((1.0 - bucket.tokens) * interval))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn budget_exceeded(result: Result<()>) -> Duration {
+        match result {
+            Err(Error::BudgetExceeded { retry_after, .. }) => retry_after,
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn clamps_empty_limits() {
+        let minimum = RateLimit::new(1, Duration::from_millis(1));
+        assert_eq!(RateLimit::new(0, Duration::from_millis(1)), minimum);
+        assert_eq!(RateLimit::new(1, Duration::from_secs(0)), minimum);
+        assert_eq!(RateLimit::new(0, Duration::from_secs(0)), minimum);
+    }
+
+    #[test]
+    fn allows_burst_up_to_requests() {
+        let limiter = RateLimiter::new(RateLimit::per_hour(3)).mode(RateLimitMode::Error);
+        for _ in 0..3 {
+            limiter.acquire("info").unwrap();
+        }
+
+        let wait = budget_exceeded(limiter.acquire("info"));
+        assert!(wait > Duration::from_secs(19 * 60) && wait <= Duration::from_secs(20 * 60), "{:?}", wait);
+    }
+
+    #[test]
+    fn refills_over_time() {
+        let limiter = RateLimiter::new(RateLimit::new(2, Duration::from_millis(100))).mode(RateLimitMode::Error);
+        limiter.acquire("info").unwrap();
+        limiter.acquire("info").unwrap();
+        assert!(budget_exceeded(limiter.acquire("info")) <= Duration::from_millis(50));
+
+        thread::sleep(Duration::from_millis(60));
+        limiter.acquire("info").unwrap();
+        budget_exceeded(limiter.acquire("info"));
+    }
+
+    #[test]
+    fn block_mode_waits() {
+        let limiter = RateLimiter::new(RateLimit::new(1, Duration::from_millis(50)));
+        let start = Instant::now();
+        limiter.acquire("info").unwrap();
+        limiter.acquire("info").unwrap();
+        limiter.acquire("info").unwrap();
+
+        assert!(start.elapsed() >= Duration::from_millis(90), "{:?}", start.elapsed());
+    }
+
+    #[test]
+    fn classes_have_separate_budgets() {
+        let limiter = RateLimiter::new(RateLimit::per_hour(2))
+            .class("notifications", RateLimit::per_hour(1))
+            .mode(RateLimitMode::Error);
+        limiter.acquire("notifications").unwrap();
+        budget_exceeded(limiter.acquire("notifications"));
+
+        limiter.acquire("info").unwrap();
+        limiter.acquire("list").unwrap();
+        limiter.acquire("info").unwrap();
+        budget_exceeded(limiter.acquire("info"));
+        limiter.acquire("list").unwrap();
+        assert_eq!(limiter.limit("notifications"), RateLimit::per_hour(1));
+        assert_eq!(limiter.limit("ucp"), RateLimit::per_hour(2));
+    }
+}
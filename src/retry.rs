@@ -0,0 +1,408 @@
+//! Wiederholung fehlgeschlagener Anfragen mit exponentiellem Backoff.
+//!
+//! Standardmäßig sendet `Proxer` jede Anfrage genau einmal. Mit `ProxerBuilder::retry`
+//! kann eine `RetryPolicy` gesetzt werden, die vorübergehende Fehler abfängt.
+
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+use serde_json;
+
+use error::*;
+use models::EmptyResponse;
+
+/// Legt fest, wie oft und in welchen Abständen fehlgeschlagene Anfragen wiederholt werden.
+///
+/// Nur lesende Anfragen werden wiederholt. Verändernde Anfragen
+/// (z.B. `Messenger::set_message` oder `Ucp::delete_reminder`) werden nur mit
+/// `retry_mutating(true)` erneut gesendet, da sie sonst doppelt ausgeführt werden könnten.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    jitter: bool,
+    connection_errors: bool,
+    server_errors: bool,
+    rate_limited: bool,
+    maintenance: bool,
+    firewall: bool,
+    mutating: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> RetryPolicy {
+        RetryPolicy::new()
+    }
+}
+
+impl RetryPolicy {
+    /// Drei Versuche, beginnend mit 500ms Wartezeit und höchstens 30s, mit Jitter.
+    /// Wiederholt werden Verbindungsfehler, Serverfehler, HTTP 429 und Wartungsarbeiten.
+    pub fn new() -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            jitter: true,
+            connection_errors: true,
+            server_errors: true,
+            rate_limited: true,
+            maintenance: true,
+            firewall: false,
+            mutating: false,
+        }
+    }
+
+    /// Die Anzahl der Versuche insgesamt, einschließlich des ersten. Default: 3.
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Die Wartezeit vor der ersten Wiederholung. Sie verdoppelt sich mit jedem Versuch. Default: 500ms.
+    pub fn initial_backoff(mut self, backoff: Duration) -> Self {
+        self.initial_backoff = backoff;
+        self
+    }
+
+    /// Die höchste Wartezeit zwischen zwei Versuchen. Default: 30s.
+    pub fn max_backoff(mut self, backoff: Duration) -> Self {
+        self.max_backoff = backoff;
+        self
+    }
+
+    /// Ob die Wartezeit zufällig zwischen der Hälfte und dem vollen Wert gewählt wird. Default: `true`.
+    pub fn jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Ob Verbindungs- und E/A-Fehler wiederholt werden. Default: `true`.
+    pub fn retry_connection_errors(mut self, retry: bool) -> Self {
+        self.connection_errors = retry;
+        self
+    }
+
+    /// Ob HTTP 5xx ohne API-Antwort wiederholt wird. Default: `true`.
+    pub fn retry_server_errors(mut self, retry: bool) -> Self {
+        self.server_errors = retry;
+        self
+    }
+
+    /// Ob HTTP 429 wiederholt wird. Ein `Retry-After` des Servers wird dabei beachtet,
+    /// gewartet wird jedoch höchstens `max_backoff`. Default: `true`.
+    pub fn retry_rate_limited(mut self, retry: bool) -> Self {
+        self.rate_limited = retry;
+        self
+    }
+
+    /// Ob Wartungsarbeiten wiederholt werden. Default: `true`.
+    ///
+    /// Dazu zählen Wartungsseiten (`Error::Maintenance`) und die Fehler der API, für die
+    /// `ProxerErrorCode::is_retryable` gilt (1007 und 1008). Schlägt auch der letzte Versuch
+    /// mit einem solchen Fehler der API fehl, wird er wie gewohnt als `Error::ProxerError` geliefert.
+    pub fn retry_maintenance(mut self, retry: bool) -> Self {
+        self.maintenance = retry;
+        self
+    }
+
+    /// Ob Firewall- bzw. Cloudflare-Challenge-Seiten wiederholt werden. Default: `false`.
+    pub fn retry_firewall(mut self, retry: bool) -> Self {
+        self.firewall = retry;
+        self
+    }
+
+    /// Ob auch verändernde Anfragen wiederholt werden. Default: `false`.
+    pub fn retry_mutating(mut self, retry: bool) -> Self {
+        self.mutating = retry;
+        self
+    }
+
+    /// Führt `send` aus und wiederholt es gemäß dieser Policy.
+    ///
+    /// Neben den Fehlern von `send` werden auch Antworten mit einem wiederholbaren Fehler der API
+    /// (siehe `retry_maintenance`) erneut angefragt.
+    pub(crate) fn run<F>(&self, url: &str, mutating: bool, mut send: F) -> Result<Vec<u8>>
+    where
+        F: FnMut() -> Result<Vec<u8>>,
+    {
+        let mut attempt = 1;
+        loop {
+            debug!("Sending {:?}, attempt {}/{}", url, attempt, self.max_attempts);
+            let result = send();
+            let retryable = match result {
+                Ok(ref response) => self.retryable_api_error(response).is_some(),
+                Err(ref err) => self.is_retryable(err),
+            };
+            if attempt >= self.max_attempts || (mutating && !self.mutating) || !retryable {
+                return result;
+            }
+
+            let (delay, reason) = match result {
+                Ok(ref response) => (self.backoff(attempt), format!("{:?}", self.retryable_api_error(response))),
+                Err(ref err) => (self.delay(attempt, err), err.to_string()),
+            };
+            warn!(
+                "Attempt {}/{} for {:?} failed, retrying in {:?}: {}",
+                attempt, self.max_attempts, url, delay, reason
+            );
+            ::std::thread::sleep(delay);
+            attempt += 1;
+        }
+    }
+
+    /// Der Fehlercode der Antwort, wenn der Server einen wiederholbaren Fehler gemeldet hat.
+    fn retryable_api_error(&self, response: &[u8]) -> Option<ProxerErrorCode> {
+        if !self.maintenance {
+            return None;
+        }
+        match serde_json::from_slice::<EmptyResponse>(response) {
+            Ok(ref data) if data.error == 1 => {
+                Some(ProxerErrorCode::from(data.code.unwrap_or_default())).filter(ProxerErrorCode::is_retryable)
+            }
+            _ => None,
+        }
+    }
+
+    fn is_retryable(&self, err: &Error) -> bool {
+        match *err {
+            Error::Hyper(_) | Error::Io(_) => self.connection_errors,
+            Error::ServerError { .. } => self.server_errors,
+            Error::RateLimited { .. } => self.rate_limited,
+            Error::Maintenance { .. } => self.maintenance,
+            Error::Firewall { .. } => self.firewall,
+            _ => false,
+        }
+    }
+
+    /// Die Wartezeit nach dem fehlgeschlagenen Versuch `attempt`.
+    fn delay(&self, attempt: u32, err: &Error) -> Duration {
+        if let Error::RateLimited {
+            retry_after: Some(seconds),
+            ..
+        } = *err
+        {
+            return Duration::from_secs(seconds).min(self.max_backoff);
+        }
+        self.backoff(attempt)
+    }
+
+    /// Die exponentielle Wartezeit nach dem fehlgeschlagenen Versuch `attempt`, ggf. mit Jitter.
+    fn backoff(&self, attempt: u32) -> Duration {
+        let factor = 1u32.checked_shl(attempt - 1).unwrap_or(u32::MAX);
+        let backoff = self
+            .initial_backoff
+            .checked_mul(factor)
+            .map_or(self.max_backoff, |backoff| backoff.min(self.max_backoff));
+        if !self.jitter {
+            return backoff;
+        }
+
+        // `RandomState` wird bei jedem Aufruf zufällig initialisiert und liefert so ohne weitere Abhängigkeit Zufall.
+        let random = RandomState::new().build_hasher().finish();
+        let millis = backoff.as_millis() as u64;
+        Duration::from_millis(millis / 2 + random % (millis / 2 + 1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Instant;
+
+    use super::*;
+    use info::GetEntry;
+    use testing::{self, ScriptedTransport};
+    use transport::Response;
+    use user::LogoutUser;
+
+    fn server_error() -> Error {
+        Error::ServerError {
+            status: 502,
+            snippet: String::new(),
+        }
+    }
+
+    fn rate_limited(retry_after: Option<u64>) -> Error {
+        Error::RateLimited {
+            retry_after,
+            snippet: String::new(),
+        }
+    }
+
+    fn fast() -> RetryPolicy {
+        RetryPolicy::new().initial_backoff(Duration::from_millis(1)).jitter(false)
+    }
+
+    #[test]
+    fn doubles_backoff_up_to_max() {
+        let policy = RetryPolicy::new()
+            .initial_backoff(Duration::from_millis(100))
+            .max_backoff(Duration::from_millis(300))
+            .jitter(false);
+        let delays: Vec<_> = (1..6).map(|attempt| policy.delay(attempt, &server_error())).collect();
+
+        assert_eq!(
+            delays,
+            [100, 200, 300, 300, 300].iter().map(|&millis| Duration::from_millis(millis)).collect::<Vec<_>>()
+        );
+        assert_eq!(policy.delay(200, &server_error()), Duration::from_millis(300));
+    }
+
+    #[test]
+    fn jitter_stays_between_half_and_full_backoff() {
+        let policy = RetryPolicy::new().initial_backoff(Duration::from_millis(100));
+        for attempt in 1..4 {
+            let backoff = Duration::from_millis(100 << (attempt - 1));
+            for _ in 0..200 {
+                let delay = policy.delay(attempt, &server_error());
+                assert!(delay >= backoff / 2 && delay <= backoff, "{:?} for {:?}", delay, backoff);
+            }
+        }
+    }
+
+    #[test]
+    fn retry_after_is_capped_by_max_backoff() {
+        let policy = RetryPolicy::new().max_backoff(Duration::from_secs(10));
+
+        assert_eq!(policy.delay(1, &rate_limited(Some(3))), Duration::from_secs(3));
+        assert_eq!(policy.delay(1, &rate_limited(Some(86_400))), Duration::from_secs(10));
+        assert!(policy.delay(1, &rate_limited(None)) <= Duration::from_millis(500));
+    }
+
+    #[test]
+    fn retries_only_retryable_errors() {
+        let policy = RetryPolicy::new();
+
+        assert!(policy.is_retryable(&server_error()));
+        assert!(policy.is_retryable(&rate_limited(None)));
+        assert!(policy.is_retryable(&Error::Maintenance {
+            status: 503,
+            snippet: String::new(),
+        }));
+        assert!(!policy.is_retryable(&Error::Firewall {
+            status: 403,
+            snippet: String::new(),
+        }));
+        assert!(!policy.is_retryable(&Error::ProxerError(ProxerErrorCode::InfoInvalidId, String::new())));
+        assert!(!policy.clone().retry_server_errors(false).is_retryable(&server_error()));
+        assert!(!policy.retry_maintenance(false).is_retryable(&Error::Maintenance {
+            status: 503,
+            snippet: String::new(),
+        }));
+    }
+
+    #[test]
+    fn retries_until_success() {
+        let transport = ScriptedTransport::new()
+            .status(502, "Bad Gateway")
+            .status(503, "Service Unavailable")
+            .json(&testing::ok(&testing::entry(53)));
+        let proxer = transport.builder().retry(fast()).build().unwrap();
+
+        assert_eq!(proxer.execute(&GetEntry { id: 53 }).unwrap().id, 53);
+        assert_eq!(transport.count(), 3);
+    }
+
+    #[test]
+    fn stops_after_max_attempts() {
+        let transport = ScriptedTransport::new().status(502, "Bad Gateway");
+        let proxer = transport.builder().retry(fast().max_attempts(2)).build().unwrap();
+
+        match proxer.execute(&GetEntry { id: 53 }) {
+            Err(Error::ServerError { status: 502, .. }) => {}
+            other => panic!("unexpected result: {:?}", other),
+        }
+        assert_eq!(transport.count(), 2);
+    }
+
+    #[test]
+    fn does_not_retry_api_errors() {
+        let transport = ScriptedTransport::new().json(&testing::api_error(3007));
+        let proxer = transport.builder().retry(fast()).build().unwrap();
+
+        assert!(proxer.execute(&GetEntry { id: 53 }).is_err());
+        assert_eq!(transport.count(), 1);
+    }
+
+    #[test]
+    fn retries_mutating_requests_only_if_allowed() {
+        let transport = ScriptedTransport::new().status(502, "Bad Gateway");
+        let proxer = transport.builder().retry(fast()).build().unwrap();
+        assert!(proxer.execute(&LogoutUser {}).is_err());
+        assert_eq!(transport.count(), 1);
+
+        let transport = ScriptedTransport::new()
+            .status(502, "Bad Gateway")
+            .json(&testing::ok("null"));
+        let proxer = transport.builder().retry(fast().retry_mutating(true)).build().unwrap();
+        assert!(proxer.execute(&LogoutUser {}).is_ok());
+        assert_eq!(transport.count(), 2);
+    }
+
+    #[test]
+    fn waits_at_most_max_backoff_for_retry_after() {
+        let transport = ScriptedTransport::new()
+            .response(Ok(Response {
+                status: 429,
+                headers: vec![("Retry-After".to_owned(), "3600".to_owned())],
+                body: b"Too Many Requests".to_vec(),
+            }))
+            .json(&testing::ok(&testing::entry(53)));
+        let proxer = transport
+            .builder()
+            .retry(fast().max_backoff(Duration::from_millis(20)))
+            .build()
+            .unwrap();
+
+        let start = Instant::now();
+        assert_eq!(proxer.execute(&GetEntry { id: 53 }).unwrap().id, 53);
+        assert!(start.elapsed() < Duration::from_secs(5));
+        assert_eq!(transport.count(), 2);
+    }
+
+    #[test]
+    fn retries_api_maintenance() {
+        let transport = ScriptedTransport::new()
+            .json(&testing::api_error(1007))
+            .json(&testing::ok(&testing::entry(53)));
+        let proxer = transport.builder().retry(fast()).build().unwrap();
+
+        assert_eq!(proxer.execute(&GetEntry { id: 53 }).unwrap().id, 53);
+        assert_eq!(transport.count(), 2);
+    }
+
+    #[test]
+    fn reports_api_maintenance_after_last_attempt() {
+        let transport = ScriptedTransport::new().json(&testing::api_error(1008));
+        let proxer = transport.builder().retry(fast().max_attempts(2)).build().unwrap();
+
+        match proxer.execute(&GetEntry { id: 53 }) {
+            Err(Error::ProxerError(ProxerErrorCode::ApiMaintenance, _)) => {}
+            other => panic!("unexpected result: {:?}", other),
+        }
+        assert_eq!(transport.count(), 2);
+
+        let transport = ScriptedTransport::new().json(&testing::api_error(1007));
+        let proxer = transport.builder().retry(fast().retry_maintenance(false)).build().unwrap();
+        assert!(proxer.execute(&GetEntry { id: 53 }).is_err());
+        assert_eq!(transport.count(), 1);
+    }
+
+    #[test]
+    fn retryable_errors_agree_with_error_codes() {
+        let policy = RetryPolicy::new();
+        let maintenance = Error::Maintenance {
+            status: 503,
+            snippet: String::new(),
+        };
+        let firewall = Error::Firewall {
+            status: 403,
+            snippet: String::new(),
+        };
+        for err in &[maintenance, firewall] {
+            assert_eq!(policy.is_retryable(err), err.proxer_code().unwrap().is_retryable(), "{:?}", err);
+        }
+    }
+}
@@ -0,0 +1,150 @@
+//! Hilfen für die Unit-Tests.
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use error::*;
+use params::Params;
+use transport::{Request, Response, Transport};
+use {Proxer, ProxerBuilder};
+
+/// Ein `Transport`, der vorgegebene Antworten der Reihe nach liefert und alle Anfragen aufzeichnet.
+///
+/// Klone teilen sich Antworten und Aufzeichnung, sodass ein Klon an `Proxer` übergeben
+/// und der andere danach geprüft werden kann. Sind alle Antworten verbraucht, wird die
+/// letzte wiederholt, sofern kein `handler` gesetzt ist.
+#[derive(Clone, Default)]
+pub(crate) struct ScriptedTransport {
+    script: Arc<Script>,
+}
+
+#[derive(Default)]
+struct Script {
+    responses: Mutex<VecDeque<Result<Response>>>,
+    last: Mutex<Option<Response>>,
+    requests: Mutex<Vec<Request>>,
+    delay: Mutex<Option<Duration>>,
+    handler: Mutex<Option<Arc<Handler>>>,
+}
+
+type Handler = dyn Fn(&Request) -> String + Send + Sync;
+
+impl ScriptedTransport {
+    pub(crate) fn new() -> ScriptedTransport {
+        ScriptedTransport::default()
+    }
+
+    /// Fügt eine JSON-Antwort mit Status 200 hinzu.
+    pub(crate) fn json(self, body: &str) -> Self {
+        self.status(200, body)
+    }
+
+    /// Fügt eine Antwort mit dem Statuscode `status` hinzu.
+    pub(crate) fn status(self, status: u16, body: &str) -> Self {
+        self.response(Ok(Response {
+            status,
+            headers: Vec::new(),
+            body: body.as_bytes().to_vec(),
+        }))
+    }
+
+    /// Fügt eine beliebige Antwort oder einen Fehler des Transports hinzu.
+    pub(crate) fn response(self, response: Result<Response>) -> Self {
+        self.script.responses.lock().unwrap().push_back(response);
+        self
+    }
+
+    /// Beantwortet alle Anfragen, für die keine Antwort vorgegeben ist, mit dem Body `handler(request)`.
+    pub(crate) fn handler<F>(self, handler: F) -> Self
+    where
+        F: Fn(&Request) -> String + Send + Sync + 'static,
+    {
+        *self.script.handler.lock().unwrap() = Some(Arc::new(handler));
+        self
+    }
+
+    /// Wartet bei jeder Anfrage `delay`, bevor geantwortet wird.
+    pub(crate) fn delay(self, delay: Duration) -> Self {
+        *self.script.delay.lock().unwrap() = Some(delay);
+        self
+    }
+
+    /// Alle bisher gesendeten Anfragen.
+    pub(crate) fn requests(&self) -> Vec<Request> {
+        self.script.requests.lock().unwrap().clone()
+    }
+
+    /// Die Anzahl der bisher gesendeten Anfragen.
+    pub(crate) fn count(&self) -> usize {
+        self.script.requests.lock().unwrap().len()
+    }
+
+    /// Ein `ProxerBuilder`, der über diesen Transport sendet.
+    pub(crate) fn builder(&self) -> ProxerBuilder {
+        Proxer::builder("test-key").disclaimer(|_| ()).transport(self.clone())
+    }
+
+    /// Ein `Proxer`, der über diesen Transport sendet.
+    pub(crate) fn proxer(&self) -> Proxer {
+        self.builder().build().unwrap()
+    }
+}
+
+impl Transport for ScriptedTransport {
+    fn send(&self, request: &Request) -> Result<Response> {
+        self.script.requests.lock().unwrap().push(request.clone());
+        let delay = *self.script.delay.lock().unwrap();
+        if let Some(delay) = delay {
+            thread::sleep(delay);
+        }
+
+        let next = self.script.responses.lock().unwrap().pop_front();
+        let handler = self.script.handler.lock().unwrap().clone();
+        if let (None, Some(handler)) = (next.as_ref(), handler) {
+            return Ok(Response {
+                status: 200,
+                headers: Vec::new(),
+                body: handler(request).into_bytes(),
+            });
+        }
+        let mut last = self.script.last.lock().unwrap();
+        match next {
+            Some(Ok(response)) => {
+                *last = Some(response.clone());
+                Ok(response)
+            }
+            Some(Err(err)) => Err(err),
+            None => last
+                .clone()
+                .ok_or_else(|| Error::Other("No scripted response left.".to_owned())),
+        }
+    }
+}
+
+/// Eine erfolgreiche Antwort der API mit den Daten `data`.
+pub(crate) fn ok(data: &str) -> String {
+    format!(r#"{{"error":0,"message":"ok","data":{}}}"#, data)
+}
+
+/// Eine Fehlerantwort der API mit dem Fehlercode `code`.
+pub(crate) fn api_error(code: u16) -> String {
+    format!(r#"{{"error":1,"message":"Fehler","code":{}}}"#, code)
+}
+
+/// Die Daten eines Entrys mit der ID `id`, passend zu `info::GetEntry`.
+pub(crate) fn entry(id: u64) -> String {
+    format!(
+        r#"{{"id":"{}","name":"Entry {}","genre":"","fsk":"","description":"","medium":"animeseries","count":"12","state":"1","rate_sum":"0","rate_count":"0","clicks":"0","kat":"anime","license":"0"}}"#,
+        id, id
+    )
+}
+
+/// Der Wert des Parameters `name` im Body von `request`.
+pub(crate) fn param(request: &Request, name: &str) -> Option<String> {
+    Params::parse(&request.body)
+        .iter()
+        .find(|&(key, _)| key == name)
+        .map(|(_, value)| value.to_owned())
+}
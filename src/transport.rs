@@ -0,0 +1,270 @@
+//! Austauschbare HTTP-Schicht von `Proxer`.
+//!
+//! Standardmäßig wird `HyperTransport` verwendet. Über `Proxer::with_transport` kann eine
+//! eigene Implementierung (z.B. ein Fake für Tests oder ein instrumentierter Client) gesetzt werden.
+
+use std::borrow::Cow;
+use std::fmt;
+use std::io::{self, Read};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::time::Duration;
+
+use hyper;
+use hyper::client::{Client, ProxyConfig};
+use hyper::header::Headers;
+use hyper::net::{HttpStream, HttpsConnector, NetworkConnector};
+use hyper_native_tls::NativeTlsClient;
+
+use error::*;
+use params::Params;
+use {API_KEY_HEADER, LOGIN_TOKEN_HEADER};
+
+/// Eine HTTP-Anfrage an die Proxer-API.
+///
+/// Die `Debug`-Ausgabe verbirgt den API-Key, das Login-Token, Passwörter und Nachrichtentexte.
+#[derive(Clone, PartialEq)]
+pub struct Request {
+    /// Die vollständige URL der Anfrage.
+    pub url: String,
+    /// Die zu sendenden Header als Name-Wert-Paare.
+    pub headers: Vec<(String, String)>,
+    /// Der bereits kodierte Body der Anfrage.
+    pub body: String,
+}
+
+impl fmt::Debug for Request {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Request")
+            .field("url", &self.url)
+            .field("headers", &redact_headers(&self.headers))
+            .field("body", &Params::parse(&self.body).redacted().encode())
+            .finish()
+    }
+}
+
+/// Header, deren Werte nie ausgegeben werden.
+static SECRET_HEADERS: [&str; 2] = [API_KEY_HEADER, LOGIN_TOKEN_HEADER];
+
+/// Ersetzt die Werte des API-Keys und des Login-Tokens durch "***".
+pub(crate) fn redact_headers(headers: &[(String, String)]) -> Vec<(&str, &str)> {
+    headers
+        .iter()
+        .map(|(name, value)| {
+            if SECRET_HEADERS.iter().any(|secret| name.eq_ignore_ascii_case(secret)) {
+                (name.as_str(), "***")
+            } else {
+                (name.as_str(), value.as_str())
+            }
+        })
+        .collect()
+}
+
+/// Die Antwort auf eine `Request`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Response {
+    /// Der HTTP-Statuscode.
+    pub status: u16,
+    /// Die empfangenen Header als Name-Wert-Paare.
+    pub headers: Vec<(String, String)>,
+    /// Der unveränderte Body der Antwort.
+    pub body: Vec<u8>,
+}
+
+/// So viele Zeichen einer fehlerhaften Antwort werden in Fehlern aufbewahrt.
+const SNIPPET_LENGTH: usize = 200;
+
+/// Merkmale einer Cloudflare-Challenge- bzw. Firewall-Seite.
+static FIREWALL_MARKERS: [&str; 6] = [
+    "cf-browser-verification",
+    "cf-challenge",
+    "challenge-platform",
+    "attention required! | cloudflare",
+    "just a moment...",
+    "access denied | proxer.me used cloudflare",
+];
+
+/// Merkmale einer Wartungsseite.
+static MAINTENANCE_MARKERS: [&str; 3] = ["wartung", "maintenance", "wartungsarbeiten"];
+
+impl Response {
+    /// Liefert den Wert des ersten Headers mit dem Namen `name` (ohne Beachtung der Groß-/Kleinschreibung).
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Liefert den Body, wenn er eine JSON-Antwort der API enthält.
+    ///
+    /// Andernfalls wird anhand von Statuscode, Headern und Inhalt ein passender Fehler
+    /// (`Firewall`, `Maintenance`, `RateLimited`, `ServerError` oder `InvalidResponse`) erzeugt.
+    pub fn into_json(self) -> Result<Vec<u8>> {
+        if self.status == 429 {
+            let retry_after = self.header("Retry-After").and_then(|value| value.trim().parse().ok());
+            return Err(Error::RateLimited {
+                retry_after,
+                snippet: self.snippet(),
+            });
+        }
+        if self.is_json() {
+            return Ok(self.body);
+        }
+
+        let status = self.status;
+        let page = String::from_utf8_lossy(&self.body).to_lowercase();
+        let from_cloudflare = self.header("cf-ray").is_some()
+            || self.header("Server").is_some_and(|server| server.eq_ignore_ascii_case("cloudflare"));
+        let error = if FIREWALL_MARKERS.iter().any(|marker| page.contains(marker))
+            || (from_cloudflare && status == 403)
+        {
+            Error::Firewall {
+                status,
+                snippet: self.snippet(),
+            }
+        } else if MAINTENANCE_MARKERS.iter().any(|marker| page.contains(marker)) {
+            Error::Maintenance {
+                status,
+                snippet: self.snippet(),
+            }
+        } else if status >= 500 {
+            Error::ServerError {
+                status,
+                snippet: self.snippet(),
+            }
+        } else {
+            Error::InvalidResponse {
+                status,
+                content_type: self.header("Content-Type").map(str::to_owned),
+                snippet: self.snippet(),
+            }
+        };
+        warn!("Received no JSON response: {:?}", error);
+        Err(error)
+    }
+
+    /// Ob der Body wie JSON aussieht. Der Content-Type allein ist nicht verlässlich.
+    fn is_json(&self) -> bool {
+        match self.body.iter().find(|byte| !byte.is_ascii_whitespace()) {
+            Some(&b'{') | Some(&b'[') => true,
+            Some(_) => false,
+            None => self.header("Content-Type").is_some_and(|value| value.contains("json")),
+        }
+    }
+
+    /// Der gekürzte Anfang des Bodys mit zusammengefassten Leerzeichen.
+    fn snippet(&self) -> String {
+        String::from_utf8_lossy(&self.body)
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+            .chars()
+            .take(SNIPPET_LENGTH)
+            .collect()
+    }
+}
+
+/// Sendet eine `Request` und liefert die zugehörige `Response`.
+///
+/// Fehler der Proxer-API (`error: 1`) sind keine Fehler des Transports,
+/// sie werden wie jede andere Antwort zurückgegeben.
+pub trait Transport: Send + Sync {
+    fn send(&self, request: &Request) -> Result<Response>;
+}
+
+/// Einstellungen für den `HyperTransport`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct HyperConfig {
+    /// Wie lange höchstens auf den Verbindungsaufbau gewartet wird. Default: Kein Limit.
+    pub connect_timeout: Option<Duration>,
+    /// Wie lange höchstens auf Daten des Servers gewartet wird. Default: Kein Limit.
+    pub read_timeout: Option<Duration>,
+    /// Ein HTTP-Proxy (Host und Port), über den alle Anfragen gesendet werden.
+    pub proxy: Option<(String, u16)>,
+}
+
+/// Baut TCP-Verbindungen mit optionalem Timeout auf.
+#[derive(Debug, Clone, Copy)]
+struct TimeoutConnector {
+    timeout: Option<Duration>,
+}
+
+impl NetworkConnector for TimeoutConnector {
+    type Stream = HttpStream;
+
+    fn connect(&self, host: &str, port: u16, scheme: &str) -> hyper::Result<HttpStream> {
+        if scheme != "http" {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "Invalid scheme for Http").into());
+        }
+        let timeout = match self.timeout {
+            Some(timeout) => timeout,
+            None => return Ok(HttpStream(TcpStream::connect((host, port))?)),
+        };
+
+        let mut last_error = None;
+        for addr in (host, port).to_socket_addrs()? {
+            match TcpStream::connect_timeout(&addr, timeout) {
+                Ok(stream) => return Ok(HttpStream(stream)),
+                Err(err) => last_error = Some(err),
+            }
+        }
+        Err(last_error
+            .unwrap_or_else(|| io::Error::new(io::ErrorKind::NotFound, "Host could not be resolved"))
+            .into())
+    }
+}
+
+/// Die Standard-Implementierung auf Basis von hyper und native-tls.
+pub struct HyperTransport {
+    client: Client,
+}
+
+impl HyperTransport {
+    /// Erstellt einen neuen `HyperTransport` mit TLS-Unterstützung.
+    pub fn new() -> Result<Self> {
+        HyperTransport::with_config(&HyperConfig::default())
+    }
+
+    /// Erstellt einen neuen `HyperTransport` mit den angegebenen Einstellungen.
+    pub fn with_config(config: &HyperConfig) -> Result<Self> {
+        let ssl = NativeTlsClient::new()?;
+        let connector = TimeoutConnector {
+            timeout: config.connect_timeout,
+        };
+        let mut client = match config.proxy {
+            Some((ref host, port)) => {
+                Client::with_proxy_config(ProxyConfig::new("http", host.clone(), port, connector, ssl))
+            }
+            None => Client::with_connector(HttpsConnector::with_connector(ssl, connector)),
+        };
+        client.set_read_timeout(config.read_timeout);
+        Ok(HyperTransport { client })
+    }
+}
+
+impl Transport for HyperTransport {
+    fn send(&self, request: &Request) -> Result<Response> {
+        let mut headers = Headers::new();
+        for (name, value) in &request.headers {
+            headers.set_raw(Cow::Owned(name.clone()), vec![value.clone().into_bytes()]);
+        }
+
+        let mut response = self.client
+            .post(&request.url)
+            .headers(headers)
+            .body(request.body.as_str())
+            .send()?;
+
+        let mut body = Vec::new();
+        response.read_to_end(&mut body)?;
+        Ok(Response {
+            status: response.status.to_u16(),
+            headers: response
+                .headers
+                .iter()
+                .map(|header| (header.name().to_owned(), header.value_string()))
+                .collect(),
+            body,
+        })
+    }
+}
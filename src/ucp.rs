@@ -1,6 +1,7 @@
-use serde_json;
-
 use error::*;
+use lenient;
+use paginator::Paginator;
+use params::Params;
 use Proxer;
 use models::*;
 
@@ -8,26 +9,33 @@ use models::*;
 #[derive(Deserialize, Debug, Clone, PartialEq)]
 pub struct List {
     /// Die ID des Entrys
+    #[serde(with = "lenient")]
     pub id: u64,
     /// Der Name des Entrys
     pub name: String,
     /// Anzahl der Folgen/Kapitel (müssen nicht hochgeladen sein!)
+    #[serde(with = "lenient")]
     pub count: u64,
     /// Das Medium des Entrys (animeseries,movie,ova,hentai,mangaseries,oneshot,doujin,hmanga)
     pub medium: Medium,
     /// Der Status des Entrys
-    pub estate: String,
+    #[serde(with = "lenient")]
+    pub estate: u64,
     /// Die ID des zugehörigen Kommentars
+    #[serde(with = "lenient")]
     pub cid: u64,
     /// Der Text des zugehörigen Kommentars
     pub comment: String,
     /// Der Status des zugehörigen Kommentars
-    pub state: String,
+    #[serde(with = "lenient")]
+    pub state: u64,
     /// Die Episodenzahl des zugehörigen Kommentars (bis wohin der Entry gesehen/gelesen wurde)
+    #[serde(with = "lenient")]
     pub episode: u64,
     /// Die Daten des zugehörigen Kommentars
     pub data: String,
     /// Die Bewertung des Entrys durch den User (0 bis 10)
+    #[serde(with = "lenient")]
     pub rating: f32,
 }
 
@@ -35,6 +43,7 @@ pub struct List {
 #[derive(Deserialize, Debug, Clone, PartialEq)]
 pub struct History {
     /// Die ID des Entrys
+    #[serde(with = "lenient")]
     pub eid: u64,
     /// Der Name des Entrys
     pub name: String,
@@ -45,8 +54,10 @@ pub struct History {
     /// Die Kategorie des Entrys (anime,manga)
     pub kat: Kategorie,
     /// Die Episoden/Kapitelnummer
+    #[serde(with = "lenient")]
     pub episode: u64,
     /// Der Zeitpunkt des Aufrufs (Format: 'YYYY-MM-DD hh:mm:ss')
+    #[serde(with = "lenient")]
     pub timestamp: i64,
 }
 
@@ -54,19 +65,23 @@ pub struct History {
 #[derive(Deserialize, Debug, Clone, PartialEq)]
 pub struct Vote {
     /// Die ID des Kommentarvotes
+    #[serde(with = "lenient")]
     pub id: u64,
     /// Der Name des gevoteten Entrys
     pub name: String,
     /// Die User-ID des Erstellers des Kommentars
+    #[serde(with = "lenient")]
     pub uid: u64,
     /// Der Username des Erstellers des Kommentars
     pub username: String,
     /// Die ID des Kommentars
+    #[serde(with = "lenient")]
     pub kid: u64,
     /// Der Inhalt des Kommentars
     pub comment: String,
     /// Die Bewertung des Kommentars
-    pub rating: String,
+    #[serde(with = "lenient")]
+    pub rating: u64,
     /// Der Typ des Votes
     #[serde(rename = "type")]
     pub vote_type: String,
@@ -76,34 +91,173 @@ pub struct Vote {
 #[derive(Deserialize, Debug, Clone, PartialEq)]
 pub struct Reminder {
     /// Die ID des Entrys
+    #[serde(with = "lenient")]
     pub eid: u64,
     /// Die Kategorie des Entrys (anime,manga)
     pub kat: Kategorie,
     /// Der Name des Entrys
     pub name: String,
     /// Die Folgen/Kapitelnummer des Lesezeichens
+    #[serde(with = "lenient")]
     pub episode: u64,
     /// Die Sprache des Lesezeichens
     pub language: String,
     /// Das Medium des Entrys (animeseries,movie,ova,hentai,mangaseries,oneshot,doujin,hmanga)
     pub medium: Medium,
     /// Die ID des Lesezeichens
+    #[serde(with = "lenient")]
     pub id: u64,
     /// Der Status des Entrys
-    pub state: String,
+    #[serde(with = "lenient")]
+    pub state: u64,
+}
+
+endpoint! {
+    /// Liefert die Liste des Users. Siehe `Ucp::get_list`.
+    pub struct GetList -> Vec<List> ["ucp", "list", login] {
+        /// Die Kategorie, die geladen werden soll. Default: Anime.
+        pub kat: Option<Kategorie>,
+        /// Die zu ladende Seite, Start bei 0. Default 0.
+        pub page: Option<u64>,
+        /// Die Einträge pro Seite. Default 100.
+        pub limit: Option<u64>,
+        /// Nur Entrys, deren Name diesen Wert enthält.
+        pub search: Option<String>,
+        /// Nur Entrys, deren Name mit diesem Wert beginnt.
+        pub search_start: Option<String>,
+        /// Die Sortierung der Liste.
+        pub sort: Option<Sort>,
+    }
+    |this| Params::new()
+        .add("kat", &this.kat)
+        .add("p", &this.page)
+        .add("limit", &this.limit)
+        .add("search", &this.search)
+        .add("search_start", &this.search_start)
+        .add("sort", &this.sort)
+}
+
+paged!(GetList => List, 100);
+
+endpoint! {
+    /// Liefert die Summe der gesehenen Episoden/Kapitel. Siehe `Ucp::get_listsum`.
+    pub struct GetListSum -> String ["ucp", "listsum", login] {
+        /// Die Kategorie, die geladen werden soll. Default: Anime.
+        pub kat: Option<Kategorie>,
+    }
+    |this| Params::new().add("kat", &this.kat)
+}
+
+endpoint! {
+    /// Liefert die Top-Ten des Users. Siehe `Ucp::get_topten`.
+    pub struct GetTopTen -> String ["ucp", "topten", login] {}
+    |this| Params::new()
+}
+
+endpoint! {
+    /// Liefert die Chronik des Users. Siehe `Ucp::get_history`.
+    pub struct GetHistory -> Vec<History> ["ucp", "history", login] {
+        /// Die Einträge pro Seite. Default 50.
+        pub limit: Option<u64>,
+        /// Die zu ladende Seite, Start bei 0. Default 0.
+        pub page: Option<u64>,
+    }
+    |this| Params::new()
+        .add("limit", &this.limit)
+        .add("p", &this.page)
+}
+
+paged!(GetHistory => History, 50);
+
+endpoint! {
+    /// Liefert die Kommentarvotes des Users. Siehe `Ucp::get_votes`.
+    pub struct GetVotes -> Vec<Vote> ["ucp", "votes", login] {}
+    |this| Params::new()
+}
+
+endpoint! {
+    /// Liefert die Lesezeichen des Users. Siehe `Ucp::get_reminder`.
+    pub struct GetReminder -> Vec<Reminder> ["ucp", "reminder", login] {
+        /// Die Kategorie, die geladen werden soll. Default: Beide.
+        pub kat: Option<Kategorie>,
+        /// Die zu ladende Seite, Start bei 0. Default 0.
+        pub page: Option<u64>,
+        /// Die Einträge pro Seite. Default 100.
+        pub limit: Option<u64>,
+    }
+    |this| Params::new()
+        .add("kat", &this.kat)
+        .add("p", &this.page)
+        .add("limit", &this.limit)
+}
+
+paged!(GetReminder => Reminder, 100);
+
+endpoint! {
+    /// Löscht ein Lesezeichen. Siehe `Ucp::delete_reminder`.
+    pub struct DeleteReminder -> () ["ucp", "deletereminder", mutates, login] {
+        /// Die ID des Eintrags.
+        pub id: u64,
+    }
+    |this| Params::new().add("id", &this.id)
+}
+
+endpoint! {
+    /// Löscht einen Eintrag der Top-Ten. Siehe `Ucp::delte_favorite`.
+    pub struct DeleteFavorite -> () ["ucp", "deletefavorite", mutates, login] {
+        /// Die ID des Eintrags.
+        pub id: u64,
+    }
+    |this| Params::new().add("id", &this.id)
+}
+
+endpoint! {
+    /// Löscht einen Kommentarvote. Siehe `Ucp::delte_vote`.
+    pub struct DeleteVote -> () ["ucp", "deletevote", mutates, login] {
+        /// Die ID des Eintrags.
+        pub id: u64,
+    }
+    |this| Params::new().add("id", &this.id)
+}
+
+endpoint! {
+    /// Setzt die Zahl der gesehenen/gelesenen Folgen/Kapitel. Siehe `Ucp::set_commentstate`.
+    pub struct SetCommentState -> () ["ucp", "setcommentstate", mutates, login] {
+        /// Die ID des Eintrags.
+        pub id: u64,
+        /// Der zu setzende Wert.
+        pub value: u64,
+    }
+    |this| Params::new()
+        .add("id", &this.id)
+        .add("value", &this.value)
+}
+
+endpoint! {
+    /// Setzt ein Lesezeichen. Siehe `Ucp::set_reminder`.
+    pub struct SetReminder -> () ["ucp", "setreminder", mutates, login] {
+        /// Die ID des Eintrags.
+        pub id: u64,
+        /// Der zu setzende Wert.
+        pub value: u64,
+    }
+    |this| Params::new()
+        .add("id", &this.id)
+        .add("value", &this.value)
 }
 
 /// Diese Klasse dient der Verwaltung sämtlicher Daten, die normalerweise über das UCP Abrufbar/Veränderbar sind.
 /// Logischerweise erfordern alle diese Funktionen, dass der User eingeloggt ist.
-#[derive(Debug)]
-pub struct Ucp<'a> {
-    proxer: &'a Proxer,
+#[derive(Debug, Clone)]
+pub struct Ucp {
+    proxer: Proxer,
 }
 
-impl<'a> Ucp<'a> {
-    #[doc(hidden)]
-    pub fn new(proxer: &'a Proxer) -> Ucp<'a> {
-        Ucp { proxer: proxer }
+impl Ucp {
+    pub(crate) fn new(proxer: &Proxer) -> Ucp {
+        Ucp {
+            proxer: proxer.clone(),
+        }
     }
 
     /// Diese Funktion liefert die Liste aller Animes/Mangas, zu denen der User einen Eintrag im UCP hat.
@@ -114,11 +268,11 @@ impl<'a> Ucp<'a> {
     /// * `page` - Dieser Parameter gibt an, welche Seite geladen werden soll. Default Wert 0. Start bei 0.
     /// * `limt` - Dieser Parameter gibt an, wie viele Einträge eine Seite enthalten soll. Default Wert 100.
     /// * `search` - Durch die Angabe dieses Parameters werden nur Entrys angezeigt,
-    /// die den angegeben Wert als Substring ihres Namens haben. Dabei ist die Position im Namen egal.
+    ///   die den angegeben Wert als Substring ihres Namens haben. Dabei ist die Position im Namen egal.
     /// * `search_start` - Durch die Angabe dieses Parameters werden nur Entrys angezeigt,
-    /// die den angegeben Wert als Substring zu Beginn ihres Namens haben.
+    ///   die den angegeben Wert als Substring zu Beginn ihres Namens haben.
     /// * `sort` - Dieser Parameter gibt an, wie die Liste sortiert werden soll,
-    /// erlaubte Eingaben (Fehlerhafte Eingaben werden auf den Default-Wert gezwungen)
+    ///   erlaubte Eingaben (Fehlerhafte Eingaben werden auf den Default-Wert gezwungen)
     pub fn get_list(
         &self,
         kat: Option<Kategorie>,
@@ -128,40 +282,46 @@ impl<'a> Ucp<'a> {
         search_start: Option<String>,
         sort: Option<Sort>,
     ) -> Result<Vec<List>> {
-        let url = url!("ucp", "list");
-        let body = param_build!("kat" => kat,
-            "p" => page,
-            "limit" => limit,
-            "search" => search,
-            "search_start" => search_start,
-            "sort" => sort);
-        let response = self.proxer.connect(&url, &body)?;
-        let data: Response<Vec<List>> = serde_json::from_reader(response)?;
-        check_error!(data.error, data.code.unwrap_or_default(), data.message);
-        check_data!(data.data)
+        self.proxer.execute(&GetList {
+            kat,
+            page,
+            limit,
+            search,
+            search_start,
+            sort,
+        })
     }
+
+    /// Wie `get_list`, lädt aber die Einträge aller Seiten nacheinander.
+    pub fn get_list_all(
+        &self,
+        kat: Option<Kategorie>,
+        search: Option<String>,
+        search_start: Option<String>,
+        sort: Option<Sort>,
+    ) -> Paginator<GetList> {
+        self.proxer.paginate(GetList {
+            kat,
+            page: None,
+            limit: None,
+            search,
+            search_start,
+            sort,
+        })
+    }
+
     /// Diese Funktion liefert die Summe der Episoden/Kapitel, die der User bisher gesehen hat.
     ///
     /// # Arguments
     ///
     /// * `kat` - Die Kategorie, die geladen werden soll. Mögliche Werte: anime, manga. Default: anime.
     pub fn get_listsum(&self, kat: Option<Kategorie>) -> Result<String> {
-        let url = url!("ucp", "listsum");
-        let body = param_build!("kat" => kat);
-        let response = self.proxer.connect(&url, &body)?;
-        let data: Response<String> = serde_json::from_reader(response)?;
-        check_error!(data.error, data.code.unwrap_or_default(), data.message);
-        check_data!(data.data)
+        self.proxer.execute(&GetListSum { kat })
     }
 
     /// Diese Funktion liefert die Top-Ten des Users. (Anime und Manga)
     pub fn get_topten(&self) -> Result<String> {
-        let url = url!("ucp", "topten");
-        let body = String::new();
-        let response = self.proxer.connect(&url, &body)?;
-        let data: Response<String> = serde_json::from_reader(response)?;
-        check_error!(data.error, data.code.unwrap_or_default(), data.message);
-        check_data!(data.data)
+        self.proxer.execute(&GetTopTen {})
     }
 
     /// Diese Funktion liefert die Chronik des Users.
@@ -171,22 +331,17 @@ impl<'a> Ucp<'a> {
     /// * `limit` - Dieser Parameter gibt an, wie viele Einträge eine Seite der Chronik haben soll. Default Wert 50.
     /// * `page` - Dieser Parameter gibt an, welche Seite der Chronik geladen werden soll. Start bei 0, Default Wert 0.
     pub fn get_history(&self, limit: Option<u64>, page: Option<u64>) -> Result<Vec<History>> {
-        let url = url!("ucp", "history");
-        let body = param_build!("limit" => limit, "p" => page);
-        let response = self.proxer.connect(&url, &body)?;
-        let data: Response<Vec<History>> = serde_json::from_reader(response)?;
-        check_error!(data.error, data.code.unwrap_or_default(), data.message);
-        check_data!(data.data)
+        self.proxer.execute(&GetHistory { limit, page })
+    }
+
+    /// Wie `get_history`, lädt aber die Chronik aller Seiten nacheinander.
+    pub fn get_history_all(&self) -> Paginator<GetHistory> {
+        self.proxer.paginate(GetHistory { limit: None, page: None })
     }
 
     /// Diese Funktion liefert die Kommentarvotes des Users.
     pub fn get_votes(&self) -> Result<Vec<Vote>> {
-        let url = url!("ucp", "votes");
-        let body = String::new();
-        let response = self.proxer.connect(&url, &body)?;
-        let data: Response<Vec<Vote>> = serde_json::from_reader(response)?;
-        check_error!(data.error, data.code.unwrap_or_default(), data.message);
-        check_data!(data.data)
+        self.proxer.execute(&GetVotes {})
     }
 
     /// Diese Funktion liefert eine Liste aller Lesezeichen des Users.
@@ -194,7 +349,7 @@ impl<'a> Ucp<'a> {
     /// # Arguments
     ///
     /// * `kat` - Dieser Parameter gibt an, welche Kategorie geladen werden soll.
-    /// Wenn weggelassen werden beide Kategorien geladen. Erlaubte Werte: "anime","manga"
+    ///   Wenn weggelassen werden beide Kategorien geladen. Erlaubte Werte: "anime","manga"
     /// * `page` - Die zu ladende Seite, Start bei 0. Default 0
     /// * `limit` - Die zu ladenden Einträge pro Seite. Default 100
     pub fn get_reminder(
@@ -203,12 +358,16 @@ impl<'a> Ucp<'a> {
         page: Option<u64>,
         limit: Option<u64>,
     ) -> Result<Vec<Reminder>> {
-        let url = url!("ucp", "reminder");
-        let body = param_build!("kat" => kat, "p" => page, "limit" => limit);
-        let response = self.proxer.connect(&url, &body)?;
-        let data: Response<Vec<Reminder>> = serde_json::from_reader(response)?;
-        check_error!(data.error, data.code.unwrap_or_default(), data.message);
-        check_data!(data.data)
+        self.proxer.execute(&GetReminder { kat, page, limit })
+    }
+
+    /// Wie `get_reminder`, lädt aber die Lesezeichen aller Seiten nacheinander.
+    pub fn get_reminder_all(&self, kat: Option<Kategorie>) -> Paginator<GetReminder> {
+        self.proxer.paginate(GetReminder {
+            kat,
+            page: None,
+            limit: None,
+        })
     }
 
     /// Diese Funktion löscht ein Lesezeichen.
@@ -217,12 +376,7 @@ impl<'a> Ucp<'a> {
     ///
     /// * `id` - Die ID des zu löschenden Lesezeichens (erhältlich über die "Reminder" Funktion)
     pub fn delete_reminder(&self, id: u64) -> Result<()> {
-        let url = url!("ucp", "deletereminder");
-        let body = param_build!("id" => Some(id));
-        let response = self.proxer.connect(&url, &body)?;
-        let data: EmptyResponse = serde_json::from_reader(response)?;
-        check_error!(data.error, data.code.unwrap_or_default(), data.message);
-        Ok(())
+        self.proxer.execute(&DeleteReminder { id })
     }
 
     /// Diese Funktion löscht einen Eintrag der Top-ten.
@@ -231,12 +385,7 @@ impl<'a> Ucp<'a> {
     ///
     /// * `id` - Die ID des zu löschenden Eintrags (erhältlich über die "Favorite" Funktion)
     pub fn delte_favorite(&self, id: u64) -> Result<()> {
-        let url = url!("ucp", "deletefavorite");
-        let body = param_build!("id" => Some(id));
-        let response = self.proxer.connect(&url, &body)?;
-        let data: EmptyResponse = serde_json::from_reader(response)?;
-        check_error!(data.error, data.code.unwrap_or_default(), data.message);
-        Ok(())
+        self.proxer.execute(&DeleteFavorite { id })
     }
 
     /// Diese Funktion löscht einen Kommentarvote.
@@ -245,12 +394,7 @@ impl<'a> Ucp<'a> {
     ///
     /// * `id` - Die ID des zu löschenden Eintrags (erhältlich über die "Vote" Funktion)
     pub fn delte_vote(&self, id: u64) -> Result<()> {
-        let url = url!("ucp", "deletevote");
-        let body = param_build!("id" => Some(id));
-        let response = self.proxer.connect(&url, &body)?;
-        let data: EmptyResponse = serde_json::from_reader(response)?;
-        check_error!(data.error, data.code.unwrap_or_default(), data.message);
-        Ok(())
+        self.proxer.execute(&DeleteVote { id })
     }
 
     /// Diese Funktion setzt die Zahl der bereits gesehenen/gelesenen Folgen/Kapitel
@@ -263,12 +407,7 @@ impl<'a> Ucp<'a> {
     /// * `id` - Die ID des zu bearbeitenden Eintrags (erhältlich über die "List" Funktion)
     /// * `value` - Der zu setzende Wert
     pub fn set_commentstate(&self, id: u64, value: u64) -> Result<()> {
-        let url = url!("ucp", "setcommentstate");
-        let body = param_build!("id" => Some(id), "value" => Some(value));
-        let response = self.proxer.connect(&url, &body)?;
-        let data: EmptyResponse = serde_json::from_reader(response)?;
-        check_error!(data.error, data.code.unwrap_or_default(), data.message);
-        Ok(())
+        self.proxer.execute(&SetCommentState { id, value })
     }
 
     /// Diese Funktion setzt eine Episode auf die Watch/Readlist eines eingeloggten Users.
@@ -280,11 +419,6 @@ impl<'a> Ucp<'a> {
     /// * `language` - Die zu ladende Sprache. (Für Animes: gersub,gerdub,engsub,engdub; Für Mangas: de,en)
     /// * `kat` - Die Kategorie des Entrys (manga oder anime)
     pub fn set_reminder(&self, id: u64, value: u64) -> Result<()> {
-        let url = url!("ucp", "setreminder");
-        let body = param_build!("id" => Some(id), "value" => Some(value));
-        let response = self.proxer.connect(&url, &body)?;
-        let data: EmptyResponse = serde_json::from_reader(response)?;
-        check_error!(data.error, data.code.unwrap_or_default(), data.message);
-        Ok(())
+        self.proxer.execute(&SetReminder { id, value })
     }
 }
@@ -1,13 +1,25 @@
+use std::fmt;
+use std::fs::{File, OpenOptions};
+use std::io::{Read, Write};
+use std::path::Path;
+
 use serde_json;
 
+use endpoint::{parse_response, Endpoint};
 use error::*;
+use lenient;
+use paginator::Paginator;
+use params::Params;
 use Proxer;
 use models::*;
 
 /// Mit dieser Schnittstelle kann ein User mithilfe eines Passwortes und eines Usernamen eingeloggt werden
-#[derive(Deserialize, Debug, Clone, PartialEq)]
+///
+/// Das Token wird in der `Debug`-Ausgabe nicht angezeigt.
+#[derive(Deserialize, Clone, PartialEq)]
 pub struct Login {
     /// Die ID des eingeloggten Users.
+    #[serde(with = "lenient")]
     pub uid: u64,
     /// Der Avatar des eingeloggten Users.
     pub avatar: String,
@@ -15,11 +27,148 @@ pub struct Login {
     pub token: String,
 }
 
-/// Mit dieser Schnittstelle kann ein User ausgeloggt werden
-#[derive(Deserialize, Debug, Clone, PartialEq)]
-pub struct Logout {
-    pub error: u8,
-    pub message: String,
+/// Die Daten einer Login-Sitzung, die gespeichert und später mit `User::resume` fortgesetzt werden können,
+/// ohne das Passwort erneut zu senden.
+///
+/// Das Token erlaubt den vollen Zugriff auf den Account und sollte entsprechend geschützt abgelegt werden.
+/// In der `Debug`-Ausgabe wird es daher nicht angezeigt.
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+pub struct Session {
+    /// Die ID des eingeloggten Users.
+    #[serde(with = "lenient")]
+    pub uid: u64,
+    /// Der Avatar des eingeloggten Users.
+    pub avatar: String,
+    /// Das Login-Token der Sitzung.
+    pub token: String,
+}
+
+impl Session {
+    /// Liest eine Sitzung aus einem mit `Session::to_json` erzeugten String.
+    pub fn from_json(json: &str) -> Result<Session> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    /// Wandelt die Sitzung in einen kurzen JSON-String um.
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string(self)?)
+    }
+
+    /// Liest eine Sitzung aus einer mit `Session::save` erzeugten Datei.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Session> {
+        let mut json = String::new();
+        File::open(path)?.read_to_string(&mut json)?;
+        Session::from_json(&json)
+    }
+
+    /// Speichert die Sitzung in der angegebenen Datei.
+    ///
+    /// Da die Datei das Login-Token enthält, ist sie unter Unix nur für den Besitzer
+    /// lesbar (Modus 0600), auch wenn sie bereits existierte.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let mut options = OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+        let mut file = options.open(path)?;
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            file.set_permissions(::std::fs::Permissions::from_mode(0o600))?;
+        }
+        file.write_all(self.to_json()?.as_bytes())?;
+        Ok(())
+    }
+}
+
+impl From<Login> for Session {
+    fn from(login: Login) -> Session {
+        Session {
+            uid: login.uid,
+            avatar: login.avatar,
+            token: login.token,
+        }
+    }
+}
+
+impl fmt::Debug for Login {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Login")
+            .field("uid", &self.uid)
+            .field("avatar", &self.avatar)
+            .field("token", &"***")
+            .finish()
+    }
+}
+
+impl fmt::Debug for Session {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Session")
+            .field("uid", &self.uid)
+            .field("avatar", &self.avatar)
+            .field("token", &"***")
+            .finish()
+    }
+}
+
+/// Zugangsdaten, mit denen sich `Proxer` nach einem abgelaufenen Login-Token automatisch neu einloggen kann.
+/// Siehe `ProxerBuilder::relogin`.
+#[derive(Clone, PartialEq)]
+pub struct Credentials {
+    /// Der Benutzername.
+    pub username: String,
+    /// Das Passwort.
+    pub password: String,
+    /// Der Einmal-Code der Zwei-Faktor-Authentifizierung, falls benötigt.
+    pub secret_key: Option<String>,
+}
+
+impl Credentials {
+    pub fn new(username: &str, password: &str) -> Credentials {
+        Credentials {
+            username: username.to_owned(),
+            password: password.to_owned(),
+            secret_key: None,
+        }
+    }
+}
+
+impl fmt::Debug for Credentials {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Credentials")
+            .field("username", &self.username)
+            .field("password", &"***")
+            .field("secret_key", &self.secret_key.as_ref().map(|_| "***"))
+            .finish()
+    }
+}
+
+/// Liefert die Zugangsdaten für einen erneuten Login.
+///
+/// Wird nur aufgerufen, wenn der Server das aktuelle Login-Token ablehnt.
+/// Gibt der Provider `None` zurück, schlägt die Anfrage mit `Error::ReloginFailed` fehl.
+/// Neben `Credentials` selbst kann auch ein Closure `Fn() -> Option<Credentials>` verwendet werden,
+/// etwa um das Passwort erst bei Bedarf aus einem geschützten Speicher zu lesen.
+pub trait CredentialProvider: Send + Sync {
+    fn credentials(&self) -> Option<Credentials>;
+}
+
+impl CredentialProvider for Credentials {
+    fn credentials(&self) -> Option<Credentials> {
+        Some(self.clone())
+    }
+}
+
+impl<F> CredentialProvider for F
+where
+    F: Fn() -> Option<Credentials> + Send + Sync,
+{
+    fn credentials(&self) -> Option<Credentials> {
+        self()
+    }
 }
 
 /// Mit dieser Schnittstelle können die öffentlichen Daten jedes Users per ID oder Username abgefragt werden.
@@ -29,6 +178,7 @@ pub struct Logout {
 #[derive(Deserialize, Debug, Clone, PartialEq)]
 pub struct UserInfo {
     ///  Die ID des abgefragten Users
+    #[serde(with = "lenient")]
     pub uid: u64,
     /// Der Username des abgefragten Users
     pub username: String,
@@ -37,18 +187,25 @@ pub struct UserInfo {
     /// Der momentane Status des abgefragten Users
     pub status: String,
     /// Der Zeitpunkt der letzten Änderung des Status des abgefragten Users (Als Unix-Timestamp in Sekunden, false wenn nicht gesetzt)
+    #[serde(with = "lenient")]
     pub status_time: i64,
     /// Die Uploadpunkte des abgefragten Users
+    #[serde(with = "lenient")]
     pub points_uploads: u64,
     /// Die Animepunkte des abgefragten Users
+    #[serde(with = "lenient")]
     pub points_anime: u64,
     /// Die Mangapunkte des abgefragten Users
+    #[serde(with = "lenient")]
     pub points_manga: u64,
     /// Die Infopunkte des abgefragten Users
+    #[serde(with = "lenient")]
     pub points_info: u64,
     /// Die Forums-Punkte des abgefragten Users
+    #[serde(with = "lenient")]
     pub points_forum: u64,
     /// Die sonstigen Punkte des abgefragten Users
+    #[serde(with = "lenient")]
     pub points_misc: u64,
 }
 
@@ -62,6 +219,7 @@ pub struct UserInfo {
 #[derive(Deserialize, Debug, Clone, PartialEq)]
 pub struct TopTen {
     ///  Die ID des Entrys
+    #[serde(with = "lenient")]
     pub eid: u64,
     ///  Der Name des Entrys
     pub name: String,
@@ -81,28 +239,36 @@ pub struct TopTen {
 #[derive(Deserialize, Debug, Clone, PartialEq)]
 pub struct List {
     /// Die ID des Entrys
+    #[serde(with = "lenient")]
     pub id: u64,
     /// Der Name des Entrys
     pub name: String,
     /// Anzahl der Folgen/Kapitel (müssen nicht hochgeladen sein!)
+    #[serde(with = "lenient")]
     pub count: u64,
     /// Das Medium des Entrys (animeseries,movie,ova,hentai,mangaseries,oneshot,doujin,hmanga)
     pub medium: String,
     /// Der Status des Entrys
+    #[serde(with = "lenient")]
     pub estate: u64,
     /// Die ID des zugehörigen Kommentars
+    #[serde(with = "lenient")]
     pub cid: u64,
     /// Der Text des zugehörigen Kommentars
     pub comment: String,
     /// Der Status des zugehörigen Kommentars
-    pub state: String,
+    #[serde(with = "lenient")]
+    pub state: u64,
     /// Die Episodenzahl des zugehörigen Kommentars (bis wohin der Entry gesehen/gelesen wurde)
+    #[serde(with = "lenient")]
     pub episode: u64,
     /// Die Daten des zugehörigen Kommentars
     pub data: String,
     /// Die Bewertung des Entrys durch den User (0 bis 10)
+    #[serde(with = "lenient")]
     pub rating: i8,
     /// Der Änderungszeitpunkt des Kommentars (Unix-Timestamp als Integer)
+    #[serde(with = "lenient")]
     pub timestamp: i64,
 }
 
@@ -116,36 +282,181 @@ pub struct List {
 #[derive(Deserialize, Debug, Clone, PartialEq)]
 pub struct LatestComment {
     /// Die ID des Kommentars
+    #[serde(with = "lenient")]
     pub id: u64,
     /// Die ID des Entrys
+    #[serde(with = "lenient")]
     pub tid: u64,
     /// Der beim Kommentar angegebene Status (0 = geschaut, 1 = am schauen, 2 = wird geschaut, 3 = abgebrochen)
+    #[serde(with = "lenient")]
     pub state: i8,
     /// Zusätzliche informationen, die als json-String abgespeichert werden. Es kann sich hierbei beispielsweise um die "Genre"-Bewertung handeln.
     pub data: String,
     /// Der Kommentar-Text
     pub comment: String,
     /// Die Bewertung des Entrys
+    #[serde(with = "lenient")]
     pub rating: i8,
     /// Die Episode, bis zu der der Kommentarschreiber geschaut hat
+    #[serde(with = "lenient")]
     pub episode: u64,
     /// Wie viele Personen den Kommentar hilfreich finden.
+    #[serde(with = "lenient")]
     pub positive: u64,
     /// Der Zeitpunkt der Erstellung des Kommentars (Unix-Timestamp in Sekunden)
+    #[serde(with = "lenient")]
     pub timestamp: i64,
     /// Der Username des Erstellers des Kommentars
     pub username: String,
     /// Die User-ID des Erstellers des Kommentars
+    #[serde(with = "lenient")]
     pub uid: u64,
     /// Das Profilbild des Erstellers des Kommentars
     pub avatar: String,
 }
 
+/// Loggt einen User ein. Siehe `User::login`.
+///
+/// Das Passwort und der Secret-Key werden in der `Debug`-Ausgabe nicht angezeigt.
+#[derive(Clone, PartialEq)]
+pub struct LoginUser {
+    /// Der Benutzername.
+    pub username: String,
+    /// Das Passwort.
+    pub password: String,
+    /// Der Einmal-Code der Zwei-Faktor-Authentifizierung.
+    pub secret_key: Option<String>,
+}
+
+impl fmt::Debug for LoginUser {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("LoginUser")
+            .field("username", &self.username)
+            .field("password", &"***")
+            .field("secret_key", &self.secret_key.as_ref().map(|_| "***"))
+            .finish()
+    }
+}
+
+impl Endpoint for LoginUser {
+    type Output = Login;
+
+    fn api_class(&self) -> &'static str {
+        "user"
+    }
+
+    fn api_function(&self) -> &'static str {
+        "login"
+    }
+
+    fn params(&self) -> Params {
+        Params::new()
+            .add("username", &self.username)
+            .add("password", &self.password)
+            .add("secretkey", &self.secret_key)
+    }
+
+    fn mutates(&self) -> bool {
+        true
+    }
+
+    /// Liefert `Error::TwoFactorRequired`, wenn der Server den Einmal-Code verlangt.
+    fn parse(&self, response: &[u8]) -> Result<Login> {
+        let data: EmptyResponse = serde_json::from_slice(response)?;
+        if data.error == 1 && data.code.map(ProxerErrorCode::from) == Some(ProxerErrorCode::LoginTwoFactorRequired) {
+            return Err(Error::TwoFactorRequired);
+        }
+        parse_response(response)
+    }
+}
+
+endpoint! {
+    /// Loggt den aktuellen User aus. Siehe `User::logout`.
+    pub struct LogoutUser -> () ["user", "logout", mutates] {}
+    |this| Params::new()
+}
+
+endpoint! {
+    /// Liefert die öffentlichen Daten eines Users. Siehe `User::get_userinfo`.
+    pub struct GetUserInfo -> UserInfo ["user", "userinfo"] {
+        /// Die User-ID. Ohne ID werden die Daten des eingeloggten Users geliefert.
+        pub uid: Option<u64>,
+    }
+    |this| Params::new().add("uid", &this.uid)
+}
+
+endpoint! {
+    /// Liefert die Topten eines Users. Siehe `User::get_topten`.
+    pub struct GetTopTen -> Vec<TopTen> ["user", "topten"] {
+        /// Die User-ID.
+        pub uid: u64,
+        /// Die Kategorie, die geladen werden soll. Default: Anime.
+        pub kat: Option<Kategorie>,
+    }
+    |this| Params::new()
+        .add("uid", &this.uid)
+        .add("kat", &this.kat)
+}
+
+endpoint! {
+    /// Liefert die Entryliste eines Users. Siehe `User::get_list`.
+    pub struct GetList -> Vec<List> ["user", "list"] {
+        /// Die User-ID.
+        pub uid: u64,
+        /// Die Kategorie, die geladen werden soll. Default: Anime.
+        pub kat: Option<Kategorie>,
+        /// Die zu ladende Seite, Start bei 0. Default 0.
+        pub page: Option<u64>,
+        /// Die Einträge pro Seite. Default 100.
+        pub limit: Option<u64>,
+        /// Nur Entrys, deren Name diesen Wert enthält.
+        pub search: Option<String>,
+        /// Nur Entrys, deren Name mit diesem Wert beginnt.
+        pub search_start: Option<String>,
+        /// Die Sortierung der Liste.
+        pub sort: Option<Sort>,
+    }
+    |this| Params::new()
+        .add("uid", &this.uid)
+        .add("kat", &this.kat)
+        .add("p", &this.page)
+        .add("limit", &this.limit)
+        .add("search", &this.search)
+        .add("search_start", &this.search_start)
+        .add("sort", &this.sort)
+}
+
+paged!(GetList => List, 100);
+
+endpoint! {
+    /// Liefert die letzten Kommentare eines Users. Siehe `User::get_latestcomments`.
+    pub struct GetLatestComments -> Vec<LatestComment> ["user", "comments"] {
+        /// Die User-ID.
+        pub uid: u64,
+        /// Die Kategorie, die geladen werden soll. Default: Anime.
+        pub kat: Option<Kategorie>,
+        /// Die zu ladende Seite, Start bei 0. Default 0.
+        pub page: Option<u64>,
+        /// Die Einträge pro Seite. Default 25.
+        pub limit: Option<u64>,
+        /// Die minimale Anzahl an Zeichen eines Kommentars. Default 300.
+        pub length: Option<u64>,
+    }
+    |this| Params::new()
+        .add("uid", &this.uid)
+        .add("kat", &this.kat)
+        .add("p", &this.page)
+        .add("limit", &this.limit)
+        .add("length", &this.length)
+}
+
+paged!(GetLatestComments => LatestComment, 25);
+
 /// Diese Klasse beinhaltet die grundlegenden Schnittstellen zur Verwaltung von Userdaten,
 /// vornehmlich Login und Logout, aber auch die Möglichkeit,
 /// einen neuen User zu registrieren sowie die öffentlichen Daten eines jeden Users per ID oder Username abzufragen.
-#[derive(Debug)]
-pub struct User<'a> {
+#[derive(Clone)]
+pub struct User {
     /// Die ID des eingeloggten Users.
     pub uid: u64,
     /// Der Avatar des eingeloggten Users.
@@ -155,44 +466,99 @@ pub struct User<'a> {
     /// so kann man stattdessen bei jeder Anfrage die einen Login erfordert dieses Token senden.
     pub token: String,
 
-    proxer: &'a Proxer,
+    proxer: Proxer,
 }
 
-impl<'a> User<'a> {
-    #[doc(hidden)]
-    pub fn new(proxer: &'a Proxer, username: &str, password: &str) -> Result<User<'a>> {
-        let user: Login = User::login(&proxer, username, password)?;
+impl fmt::Debug for User {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("User")
+            .field("uid", &self.uid)
+            .field("avatar", &self.avatar)
+            .field("token", &"***")
+            .field("proxer", &self.proxer)
+            .finish()
+    }
+}
+
+impl User {
+    pub(crate) fn from_login(proxer: &Proxer, login: Login) -> User {
+        User {
+            uid: login.uid,
+            avatar: login.avatar,
+            token: login.token,
+            proxer: proxer.clone(),
+        }
+    }
+
+    /// Setzt eine gespeicherte Sitzung fort, ohne das Passwort erneut zu senden.
+    /// Das Token wird dabei über "user/userinfo" geprüft.
+    ///
+    /// Erst wenn das Token gültig ist, wird es in `proxer` hinterlegt. Ist es nicht mehr gültig
+    /// (Fehler 1005), wird `Error::SessionExpired` zurückgegeben, ohne neu einzuloggen
+    /// (auch nicht mit `ProxerBuilder::relogin`). Bei jedem Fehler bleibt das bisherige Token von `proxer` erhalten.
+    ///
+    /// # Arguments
+    ///
+    /// * `token` - Das Login-Token einer vorherigen Sitzung (siehe `Session`).
+    pub fn resume(proxer: &Proxer, token: &str) -> Result<User> {
+        let info = proxer
+            .execute_with_token(&GetUserInfo { uid: None }, token)
+            .map_err(|err| match err {
+                Error::ProxerError(ProxerErrorCode::InvalidLoginToken, _) => Error::SessionExpired,
+                err => err,
+            })?;
+        proxer.set_login_token(Some(token.to_owned()));
         Ok(User {
-            uid: user.uid,
-            avatar: user.avatar,
-            token: user.token,
-            proxer: proxer,
+            uid: info.uid,
+            avatar: info.avatar,
+            token: token.to_owned(),
+            proxer: proxer.clone(),
         })
     }
 
+    /// Die Daten der aktuellen Sitzung, z.B. um sie mit `Session::save` zu speichern.
+    pub fn session(&self) -> Session {
+        Session {
+            uid: self.uid,
+            avatar: self.avatar.clone(),
+            token: self.token.clone(),
+        }
+    }
+
     /// Mit dieser Schnittstelle kann ein User mithilfe eines Passwortes und eines Usernamen eingeloggt werden
     ///
     /// # Arguments
     ///
     /// * `username` - Der Benutzername des zu einloggenden Benutzers.
     /// * `password` - Das Passwort des zu einloggenden Benutzers
-    /// * `api_key` - API_KEY.
-    pub fn login(proxer: &Proxer, username: &str, password: &str) -> Result<Login> {
-        let url = url!("user", "login");
-        let body = param_build!("username" => Some(username),
-            "password" => Some(password));
-        let response = proxer.connect(&url, &body)?;
-        let data: Response<Login> = serde_json::from_reader(response)?;
-        check_data!(data.data)
+    /// * `secret_key` - Der Einmal-Code der Zwei-Faktor-Authentifizierung,
+    ///   falls diese für den Account aktiviert ist.
+    ///
+    /// Bei Erfolg wird das Login-Token in `proxer` hinterlegt und ab sofort bei jeder Anfrage mitgesendet.
+    ///
+    /// Ist die Zwei-Faktor-Authentifizierung aktiviert, aber kein `secret_key` angegeben,
+    /// wird `Error::TwoFactorRequired` zurückgegeben. Der Login kann dann mit dem Code wiederholt werden.
+    pub fn login(
+        proxer: &Proxer,
+        username: &str,
+        password: &str,
+        secret_key: Option<&str>,
+    ) -> Result<Login> {
+        let login = proxer.execute(&LoginUser {
+            username: username.to_owned(),
+            password: password.to_owned(),
+            secret_key: secret_key.map(str::to_owned),
+        })?;
+        proxer.set_login_token(Some(login.token.clone()));
+        Ok(login)
     }
 
-    /// Mit dieser Schnittstelle kann ein User ausgeloggt werden
+    /// Mit dieser Schnittstelle kann ein User ausgeloggt werden.
+    /// Das Login-Token wird dabei in jedem Fall aus der Proxer-Sitzung entfernt.
     pub fn logout(self) -> Result<()> {
-        let url = url!("user", "logout");
-        let response = self.proxer.connect(&url, "")?;
-        let data: Logout = serde_json::from_reader(response)?;
-        check_error!(data.error, 0, data.message);
-        Ok(())
+        let result = self.proxer.execute(&LogoutUser {});
+        self.proxer.set_login_token(None);
+        result
     }
 
     /// Mit dieser Schnittstelle können die öffentlichen Daten jedes Users per ID oder Username abgefragt werden.
@@ -204,12 +570,7 @@ impl<'a> User<'a> {
     ///
     /// * `&self` - User-ID, deren Daten abgefragt werden sollen
     pub fn get_userinfo(&self) -> Result<UserInfo> {
-        let url = url!("user", "userinfo");
-        let body = param_build!("uid" => Some(self.uid));
-        let response = self.proxer.connect(&url, &body)?;
-        let data: Response<UserInfo> = serde_json::from_reader(response)?;
-        check_error!(data.error, data.code.unwrap_or_default(), data.message);
-        check_data!(data.data)
+        self.proxer.execute(&GetUserInfo { uid: Some(self.uid) })
     }
 
     /// Mit dieser Schnittstelle können die Topten jedes Users per ID oder Username abgefragt werden.
@@ -225,12 +586,7 @@ impl<'a> User<'a> {
     /// * `uid` - User-ID, deren Daten abgefragt werden sollen
     /// * `kat` - Die Kategorie, die geladen werden soll. Mögliche Werte: anime, manga. Default: anime.
     pub fn get_topten(&self, uid: u64, kat: Option<Kategorie>) -> Result<Vec<TopTen>> {
-        let url = url!("user", "topten");
-        let body = param_build!("uid" => Some(uid), "kat" => kat);
-        let response = self.proxer.connect(&url, &body)?;
-        let data: Response<Vec<TopTen>> = serde_json::from_reader(response)?;
-        check_error!(data.error, data.code.unwrap_or_default(), data.message);
-        check_data!(data.data)
+        self.proxer.execute(&GetTopTen { uid, kat })
     }
 
     /// Mit dieser Schnittstelle können die Entrylisten jedes Users per ID oder Username abgefragt werden.
@@ -250,6 +606,7 @@ impl<'a> User<'a> {
     /// * `search` - Durch die Angabe dieses Parameters werden nur Entrys angezeigt, die den angegeben Wert als Substring ihres Namens haben. Dabei ist die Position im Namen egal.
     /// * `search_start` - Durch die Angabe dieses Parameters werden nur Entrys angezeigt, die den angegeben Wert als Substring zu Beginn ihres Namens haben.
     /// * `sort` - Dieser Parameter gibt an, wie die Liste sortiert werden soll, erlaubte Eingaben (Fehlerhafte Eingaben werden auf den Default-Wert gezwungen)
+    #[allow(clippy::too_many_arguments)]
     pub fn get_list(
         &self,
         uid: u64,
@@ -260,18 +617,35 @@ impl<'a> User<'a> {
         search_start: Option<String>,
         sort: Option<Sort>,
     ) -> Result<Vec<List>> {
-        let url = url!("user", "list");
-        let body = param_build!("uid" => Some(uid),
-            "kat" => kat,
-            "p" => page,
-            "limit" => limit,
-            "search" => search,
-            "search_start" => search_start,
-            "sort" => sort);
-        let response = self.proxer.connect(&url, &body)?;
-        let data: Response<Vec<List>> = serde_json::from_reader(response)?;
-        check_error!(data.error, data.code.unwrap_or_default(), data.message);
-        check_data!(data.data)
+        self.proxer.execute(&GetList {
+            uid,
+            kat,
+            page,
+            limit,
+            search,
+            search_start,
+            sort,
+        })
+    }
+
+    /// Wie `get_list`, lädt aber die Einträge aller Seiten nacheinander.
+    pub fn get_list_all(
+        &self,
+        uid: u64,
+        kat: Option<Kategorie>,
+        search: Option<String>,
+        search_start: Option<String>,
+        sort: Option<Sort>,
+    ) -> Paginator<GetList> {
+        self.proxer.paginate(GetList {
+            uid,
+            kat,
+            page: None,
+            limit: None,
+            search,
+            search_start,
+            sort,
+        })
     }
 
     /// Mit dieser Schnittstelle können die letzten Kommentare jedes Users per ID oder Username abgefragt werden.
@@ -294,15 +668,23 @@ impl<'a> User<'a> {
         limit: Option<u64>,
         length: Option<u64>,
     ) -> Result<Vec<LatestComment>> {
-        let url = url!("user", "comments");
-        let body = param_build!("uid" => Some(self.uid),
-            "kat" => kat,
-            "p" => page,
-            "limit" => limit,
-            "length" => length);
-        let response = self.proxer.connect(&url, &body)?;
-        let data: Response<Vec<LatestComment>> = serde_json::from_reader(response)?;
-        check_error!(data.error, data.code.unwrap_or_default(), data.message);
-        check_data!(data.data)
+        self.proxer.execute(&GetLatestComments {
+            uid: self.uid,
+            kat,
+            page,
+            limit,
+            length,
+        })
+    }
+
+    /// Wie `get_latestcomments`, lädt aber die Kommentare aller Seiten nacheinander.
+    pub fn get_latestcomments_all(&self, kat: Option<Kategorie>, length: Option<u64>) -> Paginator<GetLatestComments> {
+        self.proxer.paginate(GetLatestComments {
+            uid: self.uid,
+            kat,
+            page: None,
+            limit: None,
+            length,
+        })
     }
 }